@@ -20,10 +20,12 @@ use weaver_common::error::{format_errors, handle_errors, WeaverError};
 use crate::Error::CompoundError;
 
 mod finding;
+mod report;
 
 // Import finding so we don't need to expose deeper into the crate.
 pub use crate::finding::FindingLevel;
 pub use crate::finding::PolicyFinding;
+pub use crate::report::PolicyReport;
 
 /// Default semconv rules/functions for the semantic convention registry.
 pub const SEMCONV_REGO: &str = include_str!("../../../defaults/rego/semconv.rego");
@@ -112,6 +114,26 @@ pub enum Error {
         violation: Box<PolicyFinding>,
     },
 
+    /// A policy warning, i.e. a finding reported by a `warn` rule.
+    #[error("Policy warning: {violation}, provenance: {provenance}")]
+    #[diagnostic(severity(Warning))]
+    PolicyWarning {
+        /// The provenance of the warning (URL or path).
+        provenance: String,
+        /// The warning.
+        violation: Box<PolicyFinding>,
+    },
+
+    /// A policy information, i.e. a finding reported by an `info` rule.
+    #[error("Policy information: {violation}, provenance: {provenance}")]
+    #[diagnostic(severity(Advice))]
+    PolicyInformation {
+        /// The provenance of the information (URL or path).
+        provenance: String,
+        /// The information.
+        violation: Box<PolicyFinding>,
+    },
+
     /// A container for multiple errors.
     #[error("{:?}", format_errors(.0))]
     #[diagnostic()]
@@ -148,6 +170,7 @@ impl From<Error> for DiagnosticMessages {
 }
 
 /// A list of supported policy stages.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PolicyStage {
     /// Policies that are evaluated before resolution.
     BeforeResolution,
@@ -157,6 +180,10 @@ pub enum PolicyStage {
     ComparisonAfterResolution,
     /// Policies that are evaluated to provide advice on samples.
     LiveCheckAdvice,
+    /// Policies that are evaluated after resolution on each resolved group of
+    /// the given type (e.g. `span` or `metric`), the group (including its
+    /// inherited attributes) being the input.
+    GroupAfterResolution(String),
 }
 
 impl Display for PolicyStage {
@@ -175,6 +202,9 @@ impl Display for PolicyStage {
             PolicyStage::LiveCheckAdvice => {
                 write!(f, "live_check_advice")
             }
+            PolicyStage::GroupAfterResolution(group_type) => {
+                write!(f, "after_resolution.{group_type}")
+            }
         }
     }
 }
@@ -421,8 +451,9 @@ impl Engine {
     }
 
     /// Returns a list of violations based on the policies, the data, the
-    /// input, and the given policy stage.
-    #[allow(clippy::print_stdout)] // Used to display the coverage (debugging purposes only)
+    /// input, and the given policy stage. Only the findings of the `deny`
+    /// rules are returned, see [`Engine::check_report`] to also get the
+    /// findings of the `warn` and `info` rules.
     pub fn check(&mut self, stage: PolicyStage) -> Result<Vec<PolicyFinding>, Error> {
         // If we don't have any policy package that matches the stage,
         // return an empty list of violations.
@@ -430,15 +461,77 @@ impl Engine {
             return Ok(vec![]);
         }
 
-        let value = self
-            .engine
-            .eval_rule(format!("data.{stage}.deny"))
-            .map_err(|e| Error::ViolationEvaluationError {
-                error: e.to_string(),
-            })?;
+        let violations = self.eval_findings(&stage, "deny", false)?;
+        self.print_coverage_report()?;
+        Ok(violations)
+    }
+
+    /// Returns a report of the findings of the `deny`, `warn`, and `info`
+    /// rules based on the policies, the data, the input, and the given policy
+    /// stage. Each of these rules is optional, e.g. a policy package may only
+    /// define `warn` rules.
+    pub fn check_report(&mut self, stage: PolicyStage) -> Result<PolicyReport, Error> {
+        // If we don't have any policy package that matches the stage,
+        // return an empty report.
+        if !self.policy_packages.contains(&format!("data.{stage}")) {
+            return Ok(PolicyReport::default());
+        }
+
+        let report = PolicyReport {
+            deny: self.eval_findings(&stage, "deny", true)?,
+            warn: self.eval_findings(&stage, "warn", true)?,
+            info: self.eval_findings(&stage, "info", true)?,
+        };
+        self.print_coverage_report()?;
+        Ok(report)
+    }
+
+    /// Evaluates the given rule of a policy stage and returns the findings it
+    /// reported. An optional rule that is not defined by the policies doesn't
+    /// report any finding.
+    fn eval_findings(
+        &mut self,
+        stage: &PolicyStage,
+        rule: &str,
+        optional: bool,
+    ) -> Result<Vec<PolicyFinding>, Error> {
+        let rule_path = format!("data.{stage}.{rule}");
+        let value = if optional {
+            // Contrary to `eval_rule`, querying an undefined rule is not an
+            // error, the query just doesn't return any result.
+            self.engine.eval_query(rule_path, false).map(|results| {
+                results
+                    .result
+                    .into_iter()
+                    .next()
+                    .and_then(|result| result.expressions.into_iter().next())
+                    .map_or(regorus::Value::Undefined, |expression| expression.value)
+            })
+        } else {
+            self.engine.eval_rule(rule_path)
+        }
+        .map_err(|e| Error::ViolationEvaluationError {
+            error: e.to_string(),
+        })?;
+        if value == regorus::Value::Undefined {
+            return Ok(vec![]);
+        }
+
+        // convert `regorus` value to `serde_json` value
+        let json_value = to_value(&value).map_err(|e| Error::ViolationEvaluationError {
+            error: e.to_string(),
+        })?;
+
+        // convert json value into a vector of findings
+        serde_json::from_value(json_value).map_err(|e| Error::ViolationEvaluationError {
+            error: e.to_string(),
+        })
+    }
 
-        // Print the coverage report if enabled
-        // This is useful for debugging purposes
+    /// Prints the coverage report if enabled.
+    /// This is useful for debugging purposes.
+    #[allow(clippy::print_stdout)] // Used to display the coverage (debugging purposes only)
+    fn print_coverage_report(&self) -> Result<(), Error> {
         if self.coverage_enabled {
             let report =
                 self.engine
@@ -454,19 +547,7 @@ impl Engine {
                     })?;
             println!("{pretty_report}");
         }
-
-        // convert `regorus` value to `serde_json` value
-        let json_value = to_value(&value).map_err(|e| Error::ViolationEvaluationError {
-            error: e.to_string(),
-        })?;
-
-        // convert json value into a vector of violations
-        let violations: Vec<PolicyFinding> =
-            serde_json::from_value(json_value).map_err(|e| Error::ViolationEvaluationError {
-                error: e.to_string(),
-            })?;
-
-        Ok(violations)
+        Ok(())
     }
 }
 
@@ -481,6 +562,81 @@ mod tests {
     use crate::finding::PolicyFinding;
     use crate::{Engine, Error, PolicyStage};
 
+    #[test]
+    fn test_group_policy_stage() -> Result<(), Box<dyn std::error::Error>> {
+        let mut engine = Engine::new();
+        let policy_package = engine.add_policy(
+            "span_policies.rego",
+            r#"
+package after_resolution.span
+
+import rego.v1
+
+deny contains {"id": "missing_span_kind", "type": "semconv_attribute", "category": "span", "group": input.id, "attr": ""} if {
+    not input.span_kind
+}
+"#,
+        )?;
+        assert_eq!(policy_package, "data.after_resolution.span");
+        let span_stage = PolicyStage::GroupAfterResolution("span".to_owned());
+        assert!(engine.has_stage(span_stage.clone()));
+        assert!(!engine.has_stage(PolicyStage::GroupAfterResolution("metric".to_owned())));
+        assert!(!engine.has_stage(PolicyStage::AfterResolution));
+
+        engine.set_input(&serde_json::json!({"id": "span.http.client", "span_kind": "client"}))?;
+        assert!(engine.check(span_stage.clone())?.is_empty());
+
+        engine.set_input(&serde_json::json!({"id": "span.http.server"}))?;
+        let violations = engine.check(span_stage)?;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].context["group"], "span.http.server");
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_report() -> Result<(), Box<dyn std::error::Error>> {
+        let mut engine = Engine::new();
+        _ = engine.add_policy(
+            "levels.rego",
+            r#"
+package after_resolution
+
+import rego.v1
+
+deny contains {"id": "missing_brief", "context": {"group": g.id}, "message": "Missing brief", "level": "violation"} if {
+    some g in input.groups
+    not g.brief
+}
+
+warn contains {"id": "missing_note", "context": {"group": g.id}, "message": "Missing note", "level": "improvement"} if {
+    some g in input.groups
+    not g.note
+}
+"#,
+        )?;
+
+        engine.set_input(&serde_json::json!({"groups": [{"id": "a", "brief": "A"}]}))?;
+        let report = engine.check_report(PolicyStage::AfterResolution)?;
+        assert!(!report.has_denials());
+        assert_eq!(report.warn.len(), 1);
+        assert_eq!(report.warn[0].id, "missing_note");
+        // The `info` rule is not defined.
+        assert!(report.info.is_empty());
+        // `check` only returns the findings of the `deny` rules.
+        assert!(engine.check(PolicyStage::AfterResolution)?.is_empty());
+
+        engine.set_input(&serde_json::json!({"groups": [{"id": "b", "note": "B"}]}))?;
+        let report = engine.check_report(PolicyStage::AfterResolution)?;
+        assert!(report.has_denials());
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.deny[0].context["group"], "b");
+
+        assert!(engine
+            .check_report(PolicyStage::BeforeResolution)?
+            .is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_policy() -> Result<(), Box<dyn std::error::Error>> {
         let mut engine = Engine::new();
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Aggregation of the findings reported by the `deny`, `warn`, and `info`
+//! rules of a policy stage.
+
+use serde::Serialize;
+
+use crate::PolicyFinding;
+
+/// The findings of a policy stage, grouped by the rule that reported them.
+///
+/// Only the `deny` findings are considered as failures, the `warn` and `info`
+/// findings are reported without failing the check, which allows a gradual
+/// rollout of new policies.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PolicyReport {
+    /// The findings reported by the `deny` rules.
+    pub deny: Vec<PolicyFinding>,
+    /// The findings reported by the `warn` rules.
+    pub warn: Vec<PolicyFinding>,
+    /// The findings reported by the `info` rules.
+    pub info: Vec<PolicyFinding>,
+}
+
+impl PolicyReport {
+    /// Returns true if the report doesn't contain any finding.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.deny.is_empty() && self.warn.is_empty() && self.info.is_empty()
+    }
+
+    /// Returns the total number of findings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.deny.len() + self.warn.len() + self.info.len()
+    }
+
+    /// Returns true if at least one `deny` rule reported a finding, i.e. the
+    /// policy check failed.
+    #[must_use]
+    pub fn has_denials(&self) -> bool {
+        !self.deny.is_empty()
+    }
+}
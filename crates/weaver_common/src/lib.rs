@@ -8,6 +8,7 @@ pub mod result;
 #[cfg(test)]
 pub mod test;
 pub mod vdir;
+pub mod watch;
 
 use crate::diagnostic::{DiagnosticMessage, DiagnosticMessages};
 use crate::error::{format_errors, WeaverError};
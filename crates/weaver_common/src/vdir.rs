@@ -17,11 +17,12 @@
 //! # String Format
 //!
 //! The format allows specifying the source, an optional Git refspec, and an optional sub-folder:
-//! `source[@refspec][\[sub_folder]]`
+//! `source[@refspec][\[sub_folder]]` (or `source[\[sub_folder]][@refspec]`)
 //!
 //! - `source`: Can be a local path (`/path/to/dir`, `./archive.zip`) or a URL (`https://...`).
-//! - `@refspec`: (Optional) For Git repositories, specifies a tag, branch, or commit hash.
-//!   *(Note: Currently, fetching specific refspecs is not fully implemented)*.
+//! - `@refspec`: (Optional) For Git repositories, specifies the tag or branch to check out.
+//!   Repositories pinned to a refspec are cached in `~/.weaver/git_cache`, see
+//!   [`LoadOptions::refresh`].
 //! - `[sub_folder]`: (Optional) Specifies a directory *within* the source (archive or Git repo)
 //!   that should become the root of the virtual directory.
 //!
@@ -32,6 +33,7 @@
 //! - Local archive with sub-folder: `data.zip[specific_dir]`
 //! - Git repo (default branch): `https://github.com/user/repo.git`
 //! - Git repo (tag `v1.0`, sub-folder `schemas`): `https://github.com/user/repo.git@v1.0[schemas]`
+//!   or `https://github.com/user/repo.git[schemas]@v1.0`
 //! - Remote archive: `https://example.com/archive.tar.gz`
 //! - Remote archive with sub-folder: `https://example.com/archive.zip[data/files]`
 
@@ -47,13 +49,15 @@ use regex::Regex;
 use rouille::url::Url;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Write};
 use std::fs::{create_dir_all, File};
 use std::io;
 use std::num::NonZeroU32;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::atomic::AtomicBool;
+use std::time::Duration;
 use tempfile::TempDir;
 
 /// The extension for a tar gz archive.
@@ -65,7 +69,7 @@ const ZIP_EXT: &str = ".zip";
 ///
 /// Supports the following general format: `source[@refspec][\[sub_folder]]`
 /// - `source`: The main path or URL.
-/// - `refspec`: Optional Git refspec (tag, branch, commit).
+/// - `refspec`: Optional Git refspec (tag, branch).
 /// - `sub_folder`: Optional path within the source (for archives/repos).
 ///
 /// Examples:
@@ -73,9 +77,12 @@ const ZIP_EXT: &str = ".zip";
 /// - `source@tag`
 /// - `source\[sub_folder]`
 /// - `source@tag\[sub_folder]`
+/// - `source\[sub_folder]@tag`
 static REGISTRY_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^(?P<source>.+?)(?:@(?P<refspec>.+?))?(?:\[(?P<sub_folder>.+?)])?$")
-        .expect("Invalid regex")
+    Regex::new(
+        r"^(?P<source>.+?)(?:@(?P<refspec>[^\[\]]+?))?(?:\[(?P<sub_folder>.+?)])?(?:@(?P<trailing_refspec>[^\[\]@]+))?$",
+    )
+    .expect("Invalid regex")
 });
 
 /// Represents a virtual path pointing to a directory-like resource.
@@ -88,7 +95,7 @@ static REGISTRY_REGEX: Lazy<Regex> = Lazy::new(|| {
 ///
 /// Paths may optionally specify:
 /// - A sub-folder within the archive or repository via `[sub_folder]`
-/// - [Not Yet Implemented] A specific Git refspec (branch, tag, or commit) via `@refspec`
+/// - A specific Git refspec (tag or branch) via `@refspec`
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(try_from = "String")]
 #[serde(into = "String")]
@@ -118,9 +125,8 @@ pub enum VirtualDirectoryPath {
         /// The URL of the Git repository to clone (supports HTTP(S) URLs).
         url: String,
 
-        /// Specific tag, branch, or commit hash to checkout.
-        ///
-        /// **Note:** Specifying this field is currently not implemented and has no effect.
+        /// Specific tag or branch to checkout. If omitted, the default branch
+        /// of the repository is used.
         refspec: Option<String>,
 
         /// Optional sub-folder path within the cloned repository to use as the root directory.
@@ -179,7 +185,10 @@ impl FromStr for VirtualDirectoryPath {
                 error: "Invalid virtual directory path. No local path or URL found".to_owned(),
             })?
             .as_str();
-        let refspec = captures.name("refspec").map(|m| m.as_str().to_owned());
+        let refspec = captures
+            .name("refspec")
+            .or_else(|| captures.name("trailing_refspec"))
+            .map(|m| m.as_str().to_owned());
         let sub_folder = captures.name("sub_folder").map(|m| m.as_str().to_owned());
 
         if source.starts_with("http://") || source.starts_with("https://") {
@@ -243,6 +252,29 @@ impl Display for VirtualDirectoryPath {
     }
 }
 
+/// Options applied when a virtual directory is loaded from a URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// The maximum duration of the whole download.
+    pub timeout: Duration,
+    /// The maximum size of the downloaded content, in bytes.
+    pub max_bytes: usize,
+    /// Fetch again the Git repositories pinned to a refspec instead of reusing
+    /// their cached clone, e.g. after a branch or a tag has moved.
+    pub refresh: bool,
+}
+
+impl Default for LoadOptions {
+    /// Two minutes and 256 MiB, large enough for any known semconv registry.
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(120),
+            max_bytes: 256 * 1024 * 1024,
+            refresh: false,
+        }
+    }
+}
+
 /// Represents a resolved virtual directory, providing access to its content on the local filesystem.
 ///
 /// This struct is created from a [`VirtualDirectoryPath`]. Depending on the source type,
@@ -265,6 +297,12 @@ pub struct VirtualDirectory {
     /// persists for the lifetime of `VirtualDirectory` and is cleaned up afterwards.
     #[allow(dead_code)]
     tmp_dir: Option<TempDir>,
+
+    /// The commit checked out, for a virtual directory cloned from a Git repository.
+    git_commit: Option<String>,
+
+    /// The digest of the archive, for a virtual directory unpacked from an archive.
+    archive_digest: Option<String>,
 }
 
 impl VirtualDirectory {
@@ -277,16 +315,49 @@ impl VirtualDirectory {
     ///
     /// Returns an [`Error`] if any operation fails (e.g. network issues, invalid paths, extraction failures).    
     pub fn try_new(vdir_path: &VirtualDirectoryPath) -> Result<Self, Error> {
+        Self::try_new_with_options(vdir_path, &LoadOptions::default())
+    }
+
+    /// Same as [`VirtualDirectory::try_new`] but with the given limits for the
+    /// content loaded from a URL.
+    ///
+    /// Returns [`Error::InvalidRegistryArchive`] if a remote archive can't be
+    /// downloaded within `options.timeout` or is larger than `options.max_bytes`.
+    pub fn try_new_with_options(
+        vdir_path: &VirtualDirectoryPath,
+        options: &LoadOptions,
+    ) -> Result<Self, Error> {
         let vdir_path_repr = vdir_path.to_string();
         let vdir = match vdir_path {
             LocalFolder { path } => Ok(Self {
                 vdir_path: vdir_path_repr,
                 path: path.into(),
                 tmp_dir: None,
+                git_commit: None,
+                archive_digest: None,
             }),
             GitRepo {
-                url, sub_folder, ..
-            } => Self::try_from_git_url(url, sub_folder, vdir_path_repr),
+                url,
+                refspec: None,
+                sub_folder,
+            } => {
+                // Create a temporary directory for the virtual directory that will be deleted
+                // when the `VirtualDirectory` goes out of scope.
+                let tmp_dir = Self::create_tmp_repo()?;
+                let repo_path = tmp_dir.path().to_path_buf();
+                let git_commit = Self::clone_git_repo(url, None, &repo_path)?;
+                Self::try_from_repo_path(url, sub_folder, repo_path, Some(tmp_dir), vdir_path_repr)
+                    .map(|vdir| vdir.with_git_commit(git_commit))
+            }
+            GitRepo {
+                url,
+                refspec: Some(refspec),
+                sub_folder,
+            } => {
+                let (repo_path, git_commit) = Self::cached_git_repo(url, refspec, options)?;
+                Self::try_from_repo_path(url, sub_folder, repo_path, None, vdir_path_repr)
+                    .map(|vdir| vdir.with_git_commit(git_commit))
+            }
             LocalArchive { path, sub_folder } => {
                 // Create a temporary directory for the virtual directory that will be deleted
                 // when the `VirtualDirectory` goes out of scope.
@@ -297,35 +368,112 @@ impl VirtualDirectory {
                 // Create a temporary directory for the virtual directory that will be deleted
                 // when the `VirtualDirectory` goes out of scope.
                 let tmp_dir = Self::create_tmp_repo()?;
-                Self::try_from_remote_archive(url, sub_folder.as_ref(), tmp_dir, vdir_path_repr)
+                Self::try_from_remote_archive(
+                    url,
+                    sub_folder.as_ref(),
+                    tmp_dir,
+                    vdir_path_repr,
+                    options,
+                )
             }
         };
         vdir
     }
 
-    /// Clones a Git repository from the specified URL into a temporary directory.
+    /// Returns the path and the checked out commit of the clone of a Git
+    /// repository pinned to a refspec, cloning the repository if it isn't
+    /// cached yet (or if `options.refresh` is set).
+    ///
+    /// The clones are cached in `~/.weaver/git_cache`, keyed by the URL and
+    /// the refspec, and are not deleted when the `VirtualDirectory` goes out of
+    /// scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GitError`] if the repository or the refspec can't be
+    /// fetched, and [`Error::CacheDirNotCreated`] if the cache can't be updated.
+    fn cached_git_repo(
+        url: &str,
+        refspec: &str,
+        options: &LoadOptions,
+    ) -> Result<(PathBuf, Option<String>), Error> {
+        let home = dirs::home_dir().ok_or(Error::HomeDirNotFound)?;
+        let cache_path = home.join(".weaver/git_cache");
+        let repo_path = cache_path.join(Self::git_cache_key(url, refspec));
+        if repo_path.exists() && !options.refresh {
+            // The commit is unknown if the cached directory is not a Git
+            // repository (e.g. it was populated by hand).
+            let git_commit = open(&repo_path)
+                .ok()
+                .and_then(|repo| repo.head_id().ok().map(|id| id.to_string()));
+            return Ok((repo_path, git_commit));
+        }
+
+        create_dir_all(cache_path.as_path()).map_err(|e| Error::CacheDirNotCreated {
+            message: e.to_string(),
+        })?;
+        // Clone into a temporary directory first, so an interrupted clone is
+        // never mistaken for a cached one.
+        let tmp_dir = tempfile::Builder::new()
+            .prefix("repo")
+            .tempdir_in(cache_path.as_path())
+            .map_err(|e| Error::CacheDirNotCreated {
+                message: e.to_string(),
+            })?;
+        let git_commit = Self::clone_git_repo(url, Some(refspec), tmp_dir.path())?;
+        if repo_path.exists() {
+            std::fs::remove_dir_all(&repo_path).map_err(|e| Error::CacheDirNotCreated {
+                message: format!("{}: {e}", repo_path.display()),
+            })?;
+        }
+        std::fs::rename(tmp_dir.keep(), &repo_path).map_err(|e| Error::CacheDirNotCreated {
+            message: format!("{}: {e}", repo_path.display()),
+        })?;
+        Ok((repo_path, git_commit))
+    }
+
+    /// Returns the name of the cache directory of a Git repository pinned to
+    /// a refspec, e.g. `github.com_open-telemetry_semantic-conventions.git@v1.26.0`.
+    fn git_cache_key(url: &str, refspec: &str) -> String {
+        let url = url
+            .trim_start_matches("https://")
+            .trim_start_matches("http://");
+        format!("{url}@{refspec}")
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '@') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    /// Clones a Git repository from the specified URL into the given directory,
+    /// checking out the given refspec or the default branch, and returns the
+    /// checked out commit.
     ///
     /// Performs a shallow clone (depth=1) to optimize disk usage and clone speed.
-    /// Optionally selects a sub-folder within the repository as the virtual directory root.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::GitError`] if:
-    /// - The repository URL is invalid or inaccessible.
-    /// - The sub-folder does not exist within the cloned repository.
-    fn try_from_git_url(
+    /// Returns [`Error::GitError`] if the repository URL is invalid or
+    /// inaccessible, or if the refspec doesn't exist.
+    fn clone_git_repo(
         url: &str,
-        sub_folder: &Option<String>,
-        vdir_path: String,
-    ) -> Result<Self, Error> {
-        let tmp_dir = Self::create_tmp_repo()?;
-        let tmp_path = tmp_dir.path().to_path_buf();
+        refspec: Option<&str>,
+        path: &Path,
+    ) -> Result<Option<String>, Error> {
+        let git_error = |message: String| GitError {
+            repo_url: url.to_owned(),
+            message,
+        };
 
-        // Clones the repo into the temporary directory.
         // Use shallow clone to save time and space.
         let mut fetch = PrepareFetch::new(
             url,
-            tmp_path.clone(),
+            path,
             Kind::WithWorktree,
             create::Options {
                 destination_must_be_empty: true,
@@ -333,31 +481,40 @@ impl VirtualDirectory {
             },
             open::Options::isolated(),
         )
-        .map_err(|e| GitError {
-            repo_url: url.to_owned(),
-            message: e.to_string(),
-        })?
+        .map_err(|e| git_error(e.to_string()))?
         .with_shallow(Shallow::DepthAtRemote(
             NonZeroU32::new(1).expect("1 is not zero"),
-        ));
+        ))
+        .with_ref_name(refspec)
+        .map_err(|e| git_error(format!("Invalid refspec `{}`: {e}", refspec.unwrap_or(""))))?;
 
         let (mut prepare, _outcome) = fetch
             .fetch_then_checkout(progress::Discard, &AtomicBool::new(false))
-            .map_err(|e| GitError {
-                repo_url: url.to_owned(),
-                message: e.to_string(),
-            })?;
+            .map_err(|e| git_error(e.to_string()))?;
 
-        let (_repo, _outcome) = prepare
+        let (repo, _outcome) = prepare
             .main_worktree(progress::Discard, &AtomicBool::new(false))
-            .map_err(|e| GitError {
-                repo_url: url.to_owned(),
-                message: e.to_string(),
-            })?;
+            .map_err(|e| git_error(e.to_string()))?;
+        Ok(repo.head_id().ok().map(|id| id.to_string()))
+    }
 
+    /// Creates a new `VirtualDirectory` from a cloned Git repository.
+    ///
+    /// Optionally selects a sub-folder within the repository as the virtual directory root.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::GitError`] if the sub-folder does not exist within the cloned repository.
+    fn try_from_repo_path(
+        url: &str,
+        sub_folder: &Option<String>,
+        repo_path: PathBuf,
+        tmp_dir: Option<TempDir>,
+        vdir_path: String,
+    ) -> Result<Self, Error> {
         // Determines the final path to the repo taking into account the sub_folder.
         let path = if let Some(sub_folder) = sub_folder {
-            let path_to_repo = tmp_path.join(sub_folder);
+            let path_to_repo = repo_path.join(sub_folder);
 
             // Checks the existence of the path in the repo.
             // If the path doesn't exist, returns an error.
@@ -370,16 +527,23 @@ impl VirtualDirectory {
 
             path_to_repo
         } else {
-            tmp_path
+            repo_path
         };
 
         Ok(Self {
             vdir_path,
             path,
-            tmp_dir: Some(tmp_dir),
+            tmp_dir,
+            git_commit: None,
+            archive_digest: None,
         })
     }
 
+    fn with_git_commit(mut self, git_commit: Option<String>) -> Self {
+        self.git_commit = git_commit;
+        self
+    }
+
     /// Create a new `VirtualDirectory` from a local archive.
     /// The archive can be in `.tar.gz` or `.zip` format.
     /// The sub_folder is used to filter the entries inside the archive to unpack.
@@ -404,6 +568,7 @@ impl VirtualDirectory {
                 error: "This archive file doesn't exist".to_owned(),
             });
         }
+        let archive_digest = Self::archive_digest_of(archive_filename)?;
         let archive_file = File::open(archive_path).map_err(|e| InvalidRegistryArchive {
             archive: archive_filename.to_owned(),
             error: e.to_string(),
@@ -425,9 +590,24 @@ impl VirtualDirectory {
             vdir_path,
             path: target_path_buf,
             tmp_dir: Some(target_dir),
+            git_commit: None,
+            archive_digest: Some(archive_digest),
         })
     }
 
+    /// Returns the SHA-256 digest of an archive file, e.g. `sha256:9f86d0...`.
+    fn archive_digest_of(archive_filename: &str) -> Result<String, Error> {
+        let content = std::fs::read(archive_filename).map_err(|e| InvalidRegistryArchive {
+            archive: archive_filename.to_owned(),
+            error: e.to_string(),
+        })?;
+        let mut digest = String::from("sha256:");
+        for byte in Sha256::digest(&content) {
+            _ = write!(digest, "{byte:02x}");
+        }
+        Ok(digest)
+    }
+
     /// Extracts the contents of a `.tar.gz` archive into the specified directory.
     ///
     /// - Skips the top-level directory present in the archive (typically the archive's own folder).
@@ -600,19 +780,36 @@ impl VirtualDirectory {
     /// - `sub_folder`: The sub-folder to unpack inside the archive.
     /// - `target_dir`: The temporary target directory where the archive will be unpacked.
     /// - `vdir_path`: The virtual directory path representation (for debug purposes).
+    /// - `options`: The limits applied to the download.
     fn try_from_remote_archive(
         url: &str,
         sub_folder: Option<&String>,
         target_dir: TempDir,
         vdir_path: String,
+        options: &LoadOptions,
     ) -> Result<Self, Error> {
         let tmp_path = target_dir.path().to_path_buf();
+        let download_error = |e: ureq::Error| InvalidRegistryArchive {
+            archive: url.to_owned(),
+            error: match e {
+                ureq::Error::Timeout(_) => format!(
+                    "The download did not complete within {} seconds",
+                    options.timeout.as_secs_f64()
+                ),
+                ureq::Error::BodyExceedsLimit(_) => format!(
+                    "The archive is larger than the maximum of {} bytes",
+                    options.max_bytes
+                ),
+                e => e.to_string(),
+            },
+        };
 
         // Download the archive from the URL
-        let response = ureq::get(url).call().map_err(|e| InvalidRegistryArchive {
-            archive: url.to_owned(),
-            error: e.to_string(),
-        })?;
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(options.timeout))
+            .build()
+            .into();
+        let response = agent.get(url).call().map_err(download_error)?;
         if response.status() != 200 {
             return Err(InvalidRegistryArchive {
                 archive: url.to_owned(),
@@ -647,12 +844,12 @@ impl VirtualDirectory {
         // Write the response body to the file.
         // The number of bytes written is ignored as the `try_from_local_archive` function
         // will handle the archive extraction and return an error if the archive is invalid.
-        _ = io::copy(&mut response.into_body().into_reader(), &mut file).map_err(|e| {
-            InvalidRegistryArchive {
-                archive: url.to_owned(),
-                error: e.to_string(),
-            }
-        })?;
+        let mut reader = response
+            .into_body()
+            .into_with_config()
+            .limit(options.max_bytes as u64)
+            .reader();
+        _ = io::copy(&mut reader, &mut file).map_err(|e| download_error(e.into()))?;
 
         Self::try_from_local_archive(
             save_path.to_str().unwrap_or_default(),
@@ -674,6 +871,20 @@ impl VirtualDirectory {
         &self.vdir_path
     }
 
+    /// Returns the id of the commit checked out, if this virtual directory was
+    /// cloned from a Git repository.
+    #[must_use]
+    pub fn git_commit(&self) -> Option<&str> {
+        self.git_commit.as_deref()
+    }
+
+    /// Returns the SHA-256 digest of the archive, e.g. `sha256:9f86d0...`, if
+    /// this virtual directory was unpacked from a local or remote archive.
+    #[must_use]
+    pub fn archive_digest(&self) -> Option<&str> {
+        self.archive_digest.as_deref()
+    }
+
     /// Creates and returns a new temporary directory within `.weaver/vdir_cache`.
     ///
     /// The created directory and its contents are automatically deleted when dropped.
@@ -703,8 +914,10 @@ impl VirtualDirectory {
 #[cfg(test)]
 mod tests {
     use crate::test::ServeStaticFiles;
-    use crate::vdir::{VirtualDirectory, VirtualDirectoryPath};
+    use crate::vdir::{LoadOptions, VirtualDirectory, VirtualDirectoryPath};
+    use crate::Error;
     use std::path::Path;
+    use std::time::Duration;
 
     #[test]
     fn test_virtual_directory_path() {
@@ -829,6 +1042,73 @@ mod tests {
             panic!("Expected GitRepo, got something else");
         }
         assert_eq!(registry_path.to_string(), registry_path_str);
+
+        // Git repository with sub-folder and tag
+        let registry_path: VirtualDirectoryPath = "http://example.com/registry.git[model]@v1.0.0"
+            .parse()
+            .unwrap();
+        if let VirtualDirectoryPath::GitRepo {
+            url,
+            refspec,
+            sub_folder,
+        } = &registry_path
+        {
+            assert_eq!(url, "http://example.com/registry.git");
+            assert_eq!(*refspec, Some("v1.0.0".to_owned()));
+            assert_eq!(*sub_folder, Some("model".to_owned()));
+        } else {
+            panic!("Expected GitRepo, got something else");
+        }
+        assert_eq!(
+            registry_path.to_string(),
+            "http://example.com/registry.git@v1.0.0[model]"
+        );
+    }
+
+    #[test]
+    fn test_git_cache_key() {
+        assert_eq!(
+            VirtualDirectory::git_cache_key(
+                "https://github.com/open-telemetry/semantic-conventions.git",
+                "v1.26.0"
+            ),
+            "github.com_open-telemetry_semantic-conventions.git@v1.26.0"
+        );
+        assert_eq!(
+            VirtualDirectory::git_cache_key("http://example.com:8080/registry", "feature/a"),
+            "example.com_8080_registry@feature_a"
+        );
+    }
+
+    #[test]
+    fn test_cached_git_repo() {
+        let url = "https://example.invalid/weaver-test-registry.git";
+        let cached_path = dirs::home_dir()
+            .unwrap()
+            .join(".weaver/git_cache")
+            .join(VirtualDirectory::git_cache_key(url, "v1.0.0"));
+        std::fs::create_dir_all(cached_path.join("model")).unwrap();
+        std::fs::write(cached_path.join("model/registry.yaml"), "groups: []\n").unwrap();
+
+        // The cached clone is reused without fetching the repository.
+        let registry_path: VirtualDirectoryPath = format!("{url}[model]@v1.0.0").parse().unwrap();
+        let repo = VirtualDirectory::try_new(&registry_path).unwrap();
+        assert_eq!(repo.path(), cached_path.join("model"));
+        drop(repo);
+        // The cached clone is not deleted when the virtual directory goes out of scope.
+        assert!(cached_path.exists());
+
+        // A refresh fetches the repository again, this one doesn't exist.
+        let options = LoadOptions {
+            refresh: true,
+            ..LoadOptions::default()
+        };
+        let result = VirtualDirectory::try_new_with_options(&registry_path, &options);
+        assert!(matches!(result, Err(Error::GitError { .. })));
+        // The cached clone is kept if the refresh fails.
+        assert!(cached_path.join("model/registry.yaml").exists());
+
+        std::fs::remove_dir_all(&cached_path).unwrap();
     }
 
     fn count_yaml_files(repo_path: &Path) -> usize {
@@ -928,4 +1208,75 @@ mod tests {
         .unwrap();
         check_archive(registry_path, Some("general.yaml"));
     }
+
+    #[test]
+    fn test_archive_digest() {
+        let server = ServeStaticFiles::from("tests/test_data").unwrap();
+        let remote_path = server
+            .relative_path_to_url("semconv_registry_v1.26.0.zip")
+            .parse::<VirtualDirectoryPath>()
+            .unwrap();
+        let local_path = "tests/test_data/semconv_registry_v1.26.0.zip"
+            .parse::<VirtualDirectoryPath>()
+            .unwrap();
+
+        let remote = VirtualDirectory::try_new(&remote_path).unwrap();
+        let local = VirtualDirectory::try_new(&local_path).unwrap();
+        let digest = remote.archive_digest().unwrap();
+        assert!(digest.starts_with("sha256:"));
+        assert_eq!(digest.len(), "sha256:".len() + 64);
+        assert_eq!(local.archive_digest(), Some(digest));
+
+        let folder = VirtualDirectory::try_new(&VirtualDirectoryPath::LocalFolder {
+            path: "tests/test_data".to_owned(),
+        })
+        .unwrap();
+        assert_eq!(folder.archive_digest(), None);
+    }
+
+    #[test]
+    fn test_remote_archive_exceeding_max_bytes() {
+        let server = ServeStaticFiles::from("tests/test_data").unwrap();
+        let registry_path = server
+            .relative_path_to_url("semconv_registry_v1.26.0.zip")
+            .parse::<VirtualDirectoryPath>()
+            .unwrap();
+        let options = LoadOptions {
+            max_bytes: 100,
+            ..LoadOptions::default()
+        };
+
+        let result = VirtualDirectory::try_new_with_options(&registry_path, &options);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidRegistryArchive { error, .. })
+                if error == "The archive is larger than the maximum of 100 bytes"
+        ));
+    }
+
+    #[test]
+    fn test_remote_archive_timeout() {
+        let server = rouille::Server::new("127.0.0.1:0", |_| {
+            std::thread::sleep(Duration::from_secs(2));
+            rouille::Response::empty_404()
+        })
+        .unwrap();
+        let port = server.server_addr().port();
+        let (_handle, kill_switch) = server.stoppable();
+        let registry_path = format!("http://127.0.0.1:{port}/registry.zip")
+            .parse::<VirtualDirectoryPath>()
+            .unwrap();
+        let options = LoadOptions {
+            timeout: Duration::from_millis(200),
+            ..LoadOptions::default()
+        };
+
+        let result = VirtualDirectory::try_new_with_options(&registry_path, &options);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidRegistryArchive { error, .. })
+                if error == "The download did not complete within 0.2 seconds"
+        ));
+        let _ = kill_switch.send(());
+    }
 }
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Detection of the changes of a set of files and directories, e.g. to
+//! regenerate the artifacts of a registry whenever one of its inputs changes.
+//!
+//! The watched paths are polled: a snapshot of the size and modification time
+//! of every file is compared with the previous one, which works the same way
+//! on every platform and file system (including network file systems).
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// The state of a watched file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileState {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+/// A watcher of files and directories. Directories are watched recursively,
+/// hidden files and directories (starting with `.`) are ignored.
+#[derive(Debug)]
+pub struct FileWatcher {
+    /// The watched files and directories.
+    roots: Vec<PathBuf>,
+    /// The state of the watched files at the last scan.
+    snapshot: BTreeMap<PathBuf, FileState>,
+}
+
+impl FileWatcher {
+    /// Creates a watcher of the given files and directories, the changes are
+    /// reported relative to their current state.
+    #[must_use]
+    pub fn new(roots: Vec<PathBuf>) -> Self {
+        let snapshot = scan(&roots);
+        Self { roots, snapshot }
+    }
+
+    /// Returns the watched files and directories.
+    #[must_use]
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Returns the files created, modified or deleted since the last call (or
+    /// the creation of the watcher), sorted by path.
+    pub fn changed_paths(&mut self) -> Vec<PathBuf> {
+        let snapshot = scan(&self.roots);
+        let mut changed: Vec<PathBuf> = snapshot
+            .iter()
+            .filter(|(path, state)| self.snapshot.get(*path) != Some(state))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.snapshot
+                .keys()
+                .filter(|path| !snapshot.contains_key(*path))
+                .cloned(),
+        );
+        changed.sort();
+        self.snapshot = snapshot;
+        changed
+    }
+
+    /// Blocks until at least one file changes, polling the watched paths at the
+    /// given interval, and returns the changed files (see
+    /// [`FileWatcher::changed_paths`]).
+    ///
+    /// The changes are reported once the files have been stable for one
+    /// interval, so an editor saving several files results in a single batch.
+    pub fn wait_for_changes(&mut self, interval: Duration) -> Vec<PathBuf> {
+        let mut changed = loop {
+            std::thread::sleep(interval);
+            let changed = self.changed_paths();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        loop {
+            std::thread::sleep(interval);
+            let more = self.changed_paths();
+            if more.is_empty() {
+                break;
+            }
+            changed.extend(more);
+        }
+        changed.sort();
+        changed.dedup();
+        changed
+    }
+}
+
+/// Returns the state of all the files of the given roots.
+fn scan(roots: &[PathBuf]) -> BTreeMap<PathBuf, FileState> {
+    let mut snapshot = BTreeMap::new();
+    for root in roots {
+        scan_path(root, &mut snapshot);
+    }
+    snapshot
+}
+
+fn scan_path(path: &Path, snapshot: &mut BTreeMap<PathBuf, FileState>) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        // The path doesn't exist (yet).
+        return;
+    };
+    if metadata.is_dir() {
+        let Ok(entries) = std::fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                scan_path(&entry.path(), snapshot);
+            }
+        }
+    } else {
+        _ = snapshot.insert(
+            path.to_path_buf(),
+            FileState {
+                len: metadata.len(),
+                modified: metadata.modified().ok(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_file_watcher() {
+        let dir = tempfile::tempdir().expect("Failed to create a temp dir");
+        let registry = dir.path().join("registry");
+        fs::create_dir_all(registry.join(".git")).unwrap();
+        fs::write(registry.join("http.yaml"), "groups: []").unwrap();
+        let params = dir.path().join("params.yaml");
+
+        let mut watcher = FileWatcher::new(vec![registry.clone(), params.clone()]);
+        assert!(watcher.changed_paths().is_empty());
+
+        // A modified file (the size changes as the modification time may have
+        // a coarse granularity), a new file and a new hidden file.
+        fs::write(registry.join("http.yaml"), "groups: [] # HTTP").unwrap();
+        fs::write(params.clone(), "params: {}").unwrap();
+        fs::write(registry.join(".git").join("HEAD"), "main").unwrap();
+        assert_eq!(
+            watcher.changed_paths(),
+            vec![params.clone(), registry.join("http.yaml")]
+        );
+        assert!(watcher.changed_paths().is_empty());
+
+        // A deleted file.
+        fs::remove_file(&params).unwrap();
+        assert_eq!(watcher.changed_paths(), vec![params]);
+
+        fs::write(registry.join("db.yaml"), "groups: []").unwrap();
+        assert_eq!(
+            watcher.wait_for_changes(Duration::from_millis(10)),
+            vec![registry.join("db.yaml")]
+        );
+    }
+}
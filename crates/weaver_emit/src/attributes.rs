@@ -214,6 +214,7 @@ mod tests {
     ) -> Attribute {
         Attribute {
             name: name.to_owned(),
+            renamed_from: None,
             r#type: attr_type,
             examples,
             brief: "".to_owned(),
@@ -275,6 +275,7 @@ mod tests {
     #[test]
     fn test_emit_stdout() {
         let registry = ResolvedRegistry {
+            file_format: String::new(),
             registry_url: "TEST".to_owned(),
             groups: vec![
                 ResolvedGroup {
@@ -288,6 +289,7 @@ mod tests {
                     deprecated: None,
                     attributes: vec![Attribute {
                         name: "test.string".to_owned(),
+                        renamed_from: None,
                         r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                         examples: Some(Examples::Strings(vec![
                             "value1".to_owned(),
@@ -508,6 +510,7 @@ mod tests {
                     deprecated: None,
                     attributes: vec![Attribute {
                         name: "session.id".to_owned(),
+                        renamed_from: None,
                         r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                         examples: Some(Examples::Strings(vec![
                             "00112233-4455-6677-8899-aabbccddeeff".to_owned(),
@@ -552,6 +555,7 @@ mod tests {
     #[test]
     fn test_emit_otlp_invalid_endpoint() {
         let registry = ResolvedRegistry {
+            file_format: String::new(),
             registry_url: "TEST_OTLP_INVALID".to_owned(),
             groups: vec![],
         };
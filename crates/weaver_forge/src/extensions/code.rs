@@ -35,9 +35,160 @@ pub(crate) fn add_filters(
     // This filter is deprecated
     env.add_filter("comment_with_prefix", comment_with_prefix);
     env.add_filter("markdown_to_html", markdown_to_html);
+    env.add_filter("rust_ident", rust_ident);
+    env.add_filter("go_ident", go_ident);
+    env.add_filter("java_ident", java_ident);
     Ok(())
 }
 
+/// The strict and reserved keywords of Rust (2021 edition).
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// The Rust keywords that can't be used as raw identifiers.
+const RUST_NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// The keywords of Go.
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// The reserved keywords and literals of Java.
+const JAVA_KEYWORDS: &[&str] = &[
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// Converts the input string into a valid identifier: the characters that are
+/// not alphanumeric are replaced by `_`, and the identifier is prefixed by `_`
+/// if it is empty or starts with a digit.
+fn sanitize_ident(input: &str) -> String {
+    let ident: String = input
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{ident}")
+    } else {
+        ident
+    }
+}
+
+/// Converts the input string into a valid Rust identifier. Keywords are
+/// escaped as raw identifiers (e.g. `r#type`), or suffixed by `_` for the ones
+/// that can't be raw (e.g. `self_`).
+pub(crate) fn rust_ident(input: &str) -> String {
+    let ident = sanitize_ident(input);
+    if RUST_NON_RAW_KEYWORDS.contains(&ident.as_str()) {
+        format!("{ident}_")
+    } else if RUST_KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{ident}")
+    } else {
+        ident
+    }
+}
+
+/// Converts the input string into a valid Go identifier. Keywords are suffixed
+/// by `_` (e.g. `type_`).
+pub(crate) fn go_ident(input: &str) -> String {
+    escape_keyword(sanitize_ident(input), GO_KEYWORDS)
+}
+
+/// Converts the input string into a valid Java identifier. Keywords and
+/// literals are suffixed by `_` (e.g. `class_`).
+pub(crate) fn java_ident(input: &str) -> String {
+    escape_keyword(sanitize_ident(input), JAVA_KEYWORDS)
+}
+
+/// Suffixes the identifier by `_` if it is one of the given keywords.
+fn escape_keyword(ident: String, keywords: &[&str]) -> String {
+    if keywords.contains(&ident.as_str()) {
+        format!("{ident}_")
+    } else {
+        ident
+    }
+}
+
 /// Converts the input string into a string comment with a prefix.
 /// Note: This filter is deprecated, please use the `comment` filter instead.
 #[must_use]
@@ -799,6 +950,25 @@ This also covers UDP network interactions where one side initiates the interacti
         assert_eq!(markdown_to_html(&Value::from(markdown)), expected_html);
     }
 
+    #[test]
+    fn test_ident_filters() {
+        let mut env = Environment::new();
+        let config = WeaverConfig::default();
+        add_filters(&mut env, &config, false).unwrap();
+        let render = |template: &str| env.render_str(template, Value::UNDEFINED).unwrap();
+
+        assert_eq!(render("{{ 'type' | rust_ident }}"), "r#type");
+        assert_eq!(render("{{ 'self' | rust_ident }}"), "self_");
+        assert_eq!(render("{{ 'http.method' | rust_ident }}"), "http_method");
+        assert_eq!(render("{{ '2xx' | rust_ident }}"), "_2xx");
+        assert_eq!(render("{{ '' | rust_ident }}"), "_");
+        assert_eq!(render("{{ 'type' | go_ident }}"), "type_");
+        assert_eq!(render("{{ 'Type' | go_ident }}"), "Type");
+        assert_eq!(render("{{ 'class' | java_ident }}"), "class_");
+        assert_eq!(render("{{ 'null' | java_ident }}"), "null_");
+        assert_eq!(render("{{ 'some-value' | java_ident }}"), "some_value");
+    }
+
     #[test]
     fn test_map_text() {
         let mut env = Environment::new();
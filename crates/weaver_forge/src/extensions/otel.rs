@@ -928,6 +928,7 @@ mod tests {
         let mut env = Environment::new();
         let attr = Attribute {
             name: "attr1".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "A brief description".to_owned(),
             examples: None,
@@ -961,6 +962,7 @@ mod tests {
 
         let attr = Attribute {
             name: "attr1".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "A brief description".to_owned(),
             examples: None,
@@ -1149,6 +1151,7 @@ mod tests {
         let attributes: Vec<Attribute> = vec![
             Attribute {
                 name: "rec.a".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
@@ -1166,6 +1169,7 @@ mod tests {
             },
             Attribute {
                 name: "rec.b".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
@@ -1183,11 +1187,15 @@ mod tests {
             },
             Attribute {
                 name: "crec.a".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
                 tag: None,
-                requirement_level: RequirementLevel::ConditionallyRequired { text: "hi".into() },
+                requirement_level: RequirementLevel::ConditionallyRequired {
+                    text: "hi".into(),
+                    condition: None,
+                },
                 sampling_relevant: None,
                 note: "".into(),
                 stability: None,
@@ -1200,11 +1208,15 @@ mod tests {
             },
             Attribute {
                 name: "crec.b".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
                 tag: None,
-                requirement_level: RequirementLevel::ConditionallyRequired { text: "hi".into() },
+                requirement_level: RequirementLevel::ConditionallyRequired {
+                    text: "hi".into(),
+                    condition: None,
+                },
                 sampling_relevant: None,
                 note: "".into(),
                 stability: None,
@@ -1217,6 +1229,7 @@ mod tests {
             },
             Attribute {
                 name: "rec.c".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
@@ -1234,6 +1247,7 @@ mod tests {
             },
             Attribute {
                 name: "rec.d".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
@@ -1251,6 +1265,7 @@ mod tests {
             },
             Attribute {
                 name: "opt.a".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
@@ -1268,6 +1283,7 @@ mod tests {
             },
             Attribute {
                 name: "opt.b".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
@@ -1285,6 +1301,7 @@ mod tests {
             },
             Attribute {
                 name: "req.a".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
@@ -1302,6 +1319,7 @@ mod tests {
             },
             Attribute {
                 name: "req.b".into(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".into(),
                 examples: None,
@@ -1416,6 +1434,7 @@ mod tests {
         let attrs = vec![
             Attribute {
                 name: "attr1".to_owned(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".to_owned(),
                 examples: None,
@@ -1433,6 +1452,7 @@ mod tests {
             },
             Attribute {
                 name: "attr2".to_owned(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
                 brief: "".to_owned(),
                 examples: None,
@@ -1450,6 +1470,7 @@ mod tests {
             },
             Attribute {
                 name: "attr3".to_owned(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: "".to_owned(),
                 examples: None,
@@ -1742,6 +1763,7 @@ mod tests {
         let mut env = Environment::new();
         let attr = Attribute {
             name: "attr1".to_owned(),
+            renamed_from: None,
             r#type: enum_type(vec!["value1".into(), "value2".into()]),
             brief: "A brief description".to_owned(),
             examples: None,
@@ -1772,6 +1794,7 @@ mod tests {
 
         let attr = Attribute {
             name: "attr1".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "A brief description".to_owned(),
             examples: None,
@@ -50,6 +50,11 @@ pub const WEAVER_YAML: &str = "weaver.yaml";
 /// Default jq filter for the semantic convention registry.
 pub const SEMCONV_JQ: &str = include_str!("../../../defaults/jq/semconv.jq");
 
+/// Official documentation targets embedded in Weaver, e.g. the `markdown`
+/// target rendering a Markdown page per group of the registry.
+pub static DOCS_TEMPLATES: include_dir::Dir<'_> =
+    include_dir::include_dir!("$CARGO_MANIFEST_DIR/../../defaults/docs_templates");
+
 // Definition of the Jinja syntax delimiters
 
 /// Constant defining the start of a Jinja block.
@@ -19,11 +19,25 @@ use weaver_semconv::group::{GroupType, InstrumentSpec, SpanKindSpec};
 use weaver_semconv::stability::Stability;
 use weaver_semconv::YamlValue;
 
+/// The version of the format of the resolved registry documents.
+///
+/// This version is bumped whenever a change to [`ResolvedRegistry`] (or to
+/// the types it contains) is not backward compatible.
+pub const RESOLVED_REGISTRY_FILE_FORMAT: &str = "resolved/1.0";
+
 /// A resolved semantic convention registry used in the context of the template and policy
 /// engines.
+///
+/// The groups are sorted by id and the attributes of each group by name, so
+/// the serialization of a registry is deterministic.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ResolvedRegistry {
+    /// The version of the format of the resolved registry, see
+    /// [`RESOLVED_REGISTRY_FILE_FORMAT`].
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub file_format: String,
     /// The semantic convention registry url.
     #[serde(skip_serializing_if = "String::is_empty")]
     pub registry_url: String,
@@ -212,6 +226,7 @@ impl ResolvedRegistry {
                         }
                         attr
                     })
+                    .sorted_by(|a, b| a.name.cmp(&b.name))
                     .collect();
                 let lineage = group.lineage.clone();
 
@@ -246,6 +261,7 @@ impl ResolvedRegistry {
         }
 
         Ok(Self {
+            file_format: RESOLVED_REGISTRY_FILE_FORMAT.to_owned(),
             registry_url: registry.registry_url.clone(),
             groups,
         })
@@ -278,6 +294,7 @@ mod tests {
             groups: vec![
                 Group {
                     id: "zebra.group".to_owned(),
+                    renamed_from: None,
                     r#type: GroupType::AttributeGroup,
                     brief: "Zebra group".to_owned(),
                     note: String::new(),
@@ -301,6 +318,7 @@ mod tests {
                 },
                 Group {
                     id: "apple.group".to_owned(),
+                    renamed_from: None,
                     r#type: GroupType::AttributeGroup,
                     brief: "Apple group".to_owned(),
                     note: String::new(),
@@ -324,6 +342,7 @@ mod tests {
                 },
                 Group {
                     id: "middle.group".to_owned(),
+                    renamed_from: None,
                     r#type: GroupType::AttributeGroup,
                     brief: "Middle group".to_owned(),
                     note: String::new(),
@@ -6,4 +6,5 @@ pub mod entity;
 pub mod event;
 pub mod metric;
 pub mod registry;
+pub mod runtime_index;
 pub mod span;
@@ -0,0 +1,209 @@
+//! A compact per-signal attribute index used for runtime validation.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use weaver_semconv::attribute::{BasicRequirementLevelSpec, RequirementLevel};
+
+use crate::v2::registry::Registry;
+
+/// A lookup of the attributes allowed on each signal of a registry.
+///
+/// The signals are keyed by their identifier (the metric name, the span type
+/// or the event name).
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RuntimeIndex {
+    /// The attributes of the metric signals.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metrics: BTreeMap<String, SignalAttributes>,
+    /// The attributes of the span signals.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub spans: BTreeMap<String, SignalAttributes>,
+    /// The attributes of the event signals.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub events: BTreeMap<String, SignalAttributes>,
+}
+
+/// The attribute keys of a signal, split by requirement level.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SignalAttributes {
+    /// The attributes that must always be present.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub required: BTreeSet<String>,
+    /// The attributes that are allowed but not unconditionally required
+    /// (i.e. conditionally required, recommended or opt-in).
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub recommended: BTreeSet<String>,
+}
+
+impl SignalAttributes {
+    /// Returns true if the attribute is allowed on the signal.
+    #[must_use]
+    pub fn is_allowed(&self, key: &str) -> bool {
+        self.required.contains(key) || self.recommended.contains(key)
+    }
+
+    fn from_attributes<'a>(
+        attributes: impl Iterator<Item = (&'a str, &'a RequirementLevel)>,
+    ) -> Self {
+        let mut signal_attributes = SignalAttributes::default();
+        for (key, requirement_level) in attributes {
+            match requirement_level {
+                RequirementLevel::Basic(BasicRequirementLevelSpec::Required) => {
+                    _ = signal_attributes.required.insert(key.to_owned());
+                }
+                _ => {
+                    _ = signal_attributes.recommended.insert(key.to_owned());
+                }
+            }
+        }
+        signal_attributes
+    }
+}
+
+/// Builds the per-signal attribute index of a resolved registry.
+#[must_use]
+pub fn build_runtime_index(registry: &Registry) -> RuntimeIndex {
+    RuntimeIndex {
+        metrics: registry
+            .metrics
+            .iter()
+            .map(|metric| {
+                let attributes = metric
+                    .attributes
+                    .iter()
+                    .map(|a| (a.base.key.as_str(), &a.requirement_level));
+                (
+                    metric.name.to_string(),
+                    SignalAttributes::from_attributes(attributes),
+                )
+            })
+            .collect(),
+        spans: registry
+            .spans
+            .iter()
+            .map(|span| {
+                let attributes = span
+                    .attributes
+                    .iter()
+                    .map(|a| (a.base.key.as_str(), &a.requirement_level));
+                (
+                    span.r#type.to_string(),
+                    SignalAttributes::from_attributes(attributes),
+                )
+            })
+            .collect(),
+        events: registry
+            .events
+            .iter()
+            .map(|event| {
+                let attributes = event
+                    .attributes
+                    .iter()
+                    .map(|a| (a.base.key.as_str(), &a.requirement_level));
+                (
+                    event.name.to_string(),
+                    SignalAttributes::from_attributes(attributes),
+                )
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use weaver_semconv::{
+        attribute::{AttributeType, PrimitiveOrArrayTypeSpec},
+        group::InstrumentSpec,
+        v2::{signal_id::SignalId, CommonFields},
+    };
+
+    use crate::v2::{
+        attribute::Attribute,
+        metric::{Metric, MetricAttribute},
+    };
+
+    use super::*;
+
+    fn attribute(key: &str) -> Attribute {
+        Attribute {
+            key: key.to_owned(),
+            r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
+            examples: None,
+            common: CommonFields::default(),
+        }
+    }
+
+    #[test]
+    fn test_build_runtime_index() {
+        let registry = Registry {
+            attributes: vec![],
+            attribute_groups: vec![],
+            metrics: vec![Metric {
+                name: SignalId::from("http.server.request.duration".to_owned()),
+                instrument: InstrumentSpec::Histogram,
+                unit: "s".to_owned(),
+                attributes: vec![
+                    MetricAttribute {
+                        base: attribute("http.request.method"),
+                        requirement_level: RequirementLevel::Basic(
+                            BasicRequirementLevelSpec::Required,
+                        ),
+                    },
+                    MetricAttribute {
+                        base: attribute("error.type"),
+                        requirement_level: RequirementLevel::ConditionallyRequired {
+                            text: "If the request has ended with an error.".to_owned(),
+                            condition: None,
+                        },
+                    },
+                    MetricAttribute {
+                        base: attribute("server.address"),
+                        requirement_level: RequirementLevel::Basic(
+                            BasicRequirementLevelSpec::OptIn,
+                        ),
+                    },
+                ],
+                entity_associations: vec![],
+                common: CommonFields::default(),
+            }],
+            spans: vec![],
+            events: vec![],
+            entities: vec![],
+        };
+
+        let index = build_runtime_index(&registry);
+        let metric = &index.metrics["http.server.request.duration"];
+        assert_eq!(
+            metric.required,
+            BTreeSet::from(["http.request.method".to_owned()])
+        );
+        assert_eq!(
+            metric.recommended,
+            BTreeSet::from(["error.type".to_owned(), "server.address".to_owned()])
+        );
+        assert!(metric.is_allowed("server.address"));
+        assert!(!metric.is_allowed("url.full"));
+        assert!(index.spans.is_empty());
+        assert!(index.events.is_empty());
+
+        let json = serde_json::to_value(&index).expect("Failed to serialize the index");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "metrics": {
+                    "http.server.request.duration": {
+                        "required": ["http.request.method"],
+                        "recommended": ["error.type", "server.address"]
+                    }
+                }
+            })
+        );
+        let parsed: RuntimeIndex =
+            serde_json::from_value(json).expect("Failed to deserialize the index");
+        assert_eq!(parsed, index);
+    }
+}
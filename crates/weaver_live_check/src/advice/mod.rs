@@ -163,6 +163,7 @@ mod tests {
         let mut deprecated_advisor = DeprecatedAdvisor;
         let deprecated_attr = Rc::new(VersionedAttribute::V1(Attribute {
             name: "deprecated.attr".to_owned(),
+            renamed_from: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "deprecated attribute".to_owned(),
@@ -200,6 +201,7 @@ mod tests {
         let mut type_advisor = TypeAdvisor;
         let int_attr = Rc::new(VersionedAttribute::V1(Attribute {
             name: "int.attr".to_owned(),
+            renamed_from: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
             brief: "integer attribute".to_owned(),
@@ -238,6 +240,7 @@ mod tests {
         let mut stability_advisor = StabilityAdvisor;
         let dev_attr = Rc::new(VersionedAttribute::V1(Attribute {
             name: "dev.attr".to_owned(),
+            renamed_from: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "development attribute".to_owned(),
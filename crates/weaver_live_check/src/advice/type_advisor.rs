@@ -395,6 +395,7 @@ mod tests {
     fn create_test_attribute(name: &str, requirement_level: RequirementLevel) -> Attribute {
         Attribute {
             name: name.to_owned(),
+            renamed_from: None,
             requirement_level,
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: "test attribute".to_owned(),
@@ -452,6 +453,7 @@ mod tests {
                 "conditional",
                 RequirementLevel::ConditionallyRequired {
                     text: "Required when X".to_owned(),
+                    condition: None,
                 },
             ),
         ];
@@ -549,6 +551,7 @@ mod tests {
         // Create a template attribute like "weaver.finding.context"
         let template_attribute = Attribute {
             name: "weaver.finding.context".to_owned(),
+            renamed_from: None,
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended),
             r#type: AttributeType::Template(TemplateTypeSpec::Any),
             brief: "Template attribute for context".to_owned(),
@@ -614,6 +617,7 @@ mod tests {
         let mixed_semconv_attributes = vec![
             Attribute {
                 name: "template.attr".to_owned(),
+                renamed_from: None,
                 requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
                 r#type: AttributeType::Template(TemplateTypeSpec::String),
                 brief: "Template attribute".to_owned(),
@@ -106,7 +106,7 @@ impl LiveChecker {
         }
 
         // Sort templates by name length in descending order
-        templates_by_length.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()));
+        templates_by_length.sort_by_key(|(a, _)| std::cmp::Reverse(a.len()));
 
         LiveChecker {
             registry,
@@ -611,6 +611,7 @@ mod tests {
             })
         } else {
             VersionedRegistry::V1(ResolvedRegistry {
+                file_format: String::new(),
                 registry_url: "TEST".to_owned(),
                 groups: vec![ResolvedGroup {
                     id: "test.comprehensive.internal".to_owned(),
@@ -625,6 +626,7 @@ mod tests {
                     attributes: vec![
                         Attribute {
                             name: "test.string".to_owned(),
+                            renamed_from: None,
                             r#type: AttributeType::PrimitiveOrArray(
                                 PrimitiveOrArrayTypeSpec::String,
                             ),
@@ -649,6 +651,7 @@ mod tests {
                         },
                         Attribute {
                             name: "test.enum".to_owned(),
+                            renamed_from: None,
                             r#type: AttributeType::Enum {
                                 members: vec![
                                     EnumEntriesSpec {
@@ -689,6 +692,7 @@ mod tests {
                         },
                         Attribute {
                             name: "test.deprecated".to_owned(),
+                            renamed_from: None,
                             r#type: AttributeType::PrimitiveOrArray(
                                 PrimitiveOrArrayTypeSpec::String,
                             ),
@@ -717,6 +721,7 @@ mod tests {
                         },
                         Attribute {
                             name: "test.template".to_owned(),
+                            renamed_from: None,
                             r#type: AttributeType::Template(TemplateTypeSpec::String),
                             examples: Some(Examples::Strings(vec![
                                 "value1".to_owned(),
@@ -844,6 +849,7 @@ mod tests {
             })
         } else {
             VersionedRegistry::V1(ResolvedRegistry {
+                file_format: String::new(),
                 registry_url: "TEST_METRICS".to_owned(),
                 groups: vec![
                     // Attribute group for system memory
@@ -859,6 +865,7 @@ mod tests {
                         deprecated: None,
                         attributes: vec![Attribute {
                             name: "system.memory.state".to_owned(),
+                            renamed_from: None,
                             r#type: AttributeType::Enum {
                                 members: vec![
                                     EnumEntriesSpec {
@@ -947,6 +954,7 @@ mod tests {
                         deprecated: None,
                         attributes: vec![Attribute {
                             name: "system.memory.state".to_owned(),
+                            renamed_from: None,
                             r#type: AttributeType::PrimitiveOrArray(
                                 PrimitiveOrArrayTypeSpec::String,
                             ),
@@ -1040,6 +1048,7 @@ mod tests {
             })
         } else {
             VersionedRegistry::V1(ResolvedRegistry {
+                file_format: String::new(),
                 registry_url: "TEST".to_owned(),
                 groups: vec![ResolvedGroup {
                     id: "custom.comprehensive.internal".to_owned(),
@@ -1053,6 +1062,7 @@ mod tests {
                     deprecated: None,
                     attributes: vec![Attribute {
                         name: "custom.string".to_owned(),
+                        renamed_from: None,
                         r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                         examples: Some(Examples::Strings(vec![
                             "value1".to_owned(),
@@ -1593,6 +1603,7 @@ mod tests {
             })
         } else {
             VersionedRegistry::V1(ResolvedRegistry {
+                file_format: String::new(),
                 registry_url: "TEST_EVENTS".to_owned(),
                 groups: vec![
                     ResolvedGroup {
@@ -1610,6 +1621,7 @@ mod tests {
                         attributes: vec![
                             Attribute {
                                 name: "session.id".to_owned(),
+                                renamed_from: None,
                                 r#type: AttributeType::PrimitiveOrArray(
                                     PrimitiveOrArrayTypeSpec::String,
                                 ),
@@ -1633,6 +1645,7 @@ mod tests {
                             },
                             Attribute {
                                 name: "session.previous_id".to_owned(),
+                                renamed_from: None,
                                 r#type: AttributeType::PrimitiveOrArray(
                                     PrimitiveOrArrayTypeSpec::String,
                                 ),
@@ -348,6 +348,7 @@ mod tests {
     #[test]
     fn test_disabled_statistics() {
         let registry = ResolvedRegistry {
+            file_format: String::new(),
             groups: vec![],
             registry_url: String::new(),
         };
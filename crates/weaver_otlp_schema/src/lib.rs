@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! The protobuf representation of a resolved telemetry schema, so collectors
+//! and SDKs can consume a resolved registry without a YAML or JSON parser.
+//!
+//! The messages are defined in `proto/weaver/schema/v1/resolved_schema.proto`,
+//! see the README for how to regenerate the Rust code.
+
+use prost::Message;
+use weaver_resolved_schema::attribute::Attribute;
+use weaver_resolved_schema::registry::Group;
+use weaver_resolved_schema::ResolvedTelemetrySchema;
+use weaver_semconv::attribute::{
+    AttributeType, BasicRequirementLevelSpec, EnumEntriesSpec, RequirementLevel, ValueSpec,
+};
+use weaver_semconv::deprecated::Deprecated;
+use weaver_semconv::group::SpanKindSpec;
+use weaver_semconv::stability::Stability;
+
+/// The protobuf messages of the resolved telemetry schema.
+#[allow(unused_qualifications)]
+#[allow(unused_results)]
+#[allow(missing_docs)]
+#[allow(clippy::doc_markdown)]
+#[allow(clippy::must_use_candidate)]
+#[path = "weaver.schema.v1.rs"]
+pub mod v1;
+
+/// Encodes a resolved telemetry schema into a `weaver.schema.v1.ResolvedTelemetrySchema`
+/// protobuf message.
+#[must_use]
+pub fn encode_resolved_schema(schema: &ResolvedTelemetrySchema) -> Vec<u8> {
+    v1::ResolvedTelemetrySchema::from(schema).encode_to_vec()
+}
+
+impl From<&ResolvedTelemetrySchema> for v1::ResolvedTelemetrySchema {
+    fn from(schema: &ResolvedTelemetrySchema) -> Self {
+        Self {
+            file_format: schema.file_format.clone(),
+            schema_url: schema.schema_url.clone(),
+            registry_id: schema.registry_id.clone(),
+            registry_url: schema.registry.registry_url.clone(),
+            attributes: schema.catalog.iter().map(Into::into).collect(),
+            groups: schema.registry.groups.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&Attribute> for v1::Attribute {
+    fn from(attribute: &Attribute) -> Self {
+        Self {
+            name: attribute.name.clone(),
+            r#type: Some((&attribute.r#type).into()),
+            brief: attribute.brief.clone(),
+            note: attribute.note.clone(),
+            tag: attribute.tag.clone(),
+            requirement_level: Some((&attribute.requirement_level).into()),
+            sampling_relevant: attribute.sampling_relevant,
+            stability: stability(attribute.stability.as_ref()).into(),
+            deprecated: attribute.deprecated.as_ref().map(Into::into),
+            prefix: attribute.prefix,
+        }
+    }
+}
+
+impl From<&AttributeType> for v1::AttributeType {
+    fn from(r#type: &AttributeType) -> Self {
+        let r#type = match r#type {
+            AttributeType::Enum { members } => v1::attribute_type::Type::Enum(v1::EnumType {
+                members: members.iter().map(Into::into).collect(),
+            }),
+            r#type => v1::attribute_type::Type::Primitive(r#type.to_string()),
+        };
+        Self {
+            r#type: Some(r#type),
+        }
+    }
+}
+
+impl From<&EnumEntriesSpec> for v1::EnumMember {
+    fn from(member: &EnumEntriesSpec) -> Self {
+        let value = match &member.value {
+            ValueSpec::String(value) => v1::enum_member::Value::StringValue(value.clone()),
+            ValueSpec::Int(value) => v1::enum_member::Value::IntValue(*value),
+            ValueSpec::Double(value) => v1::enum_member::Value::DoubleValue(value.into_inner()),
+            ValueSpec::Bool(value) => v1::enum_member::Value::BoolValue(*value),
+        };
+        Self {
+            id: member.id.clone(),
+            brief: member.brief.clone(),
+            note: member.note.clone(),
+            stability: stability(member.stability.as_ref()).into(),
+            deprecated: member.deprecated.as_ref().map(Into::into),
+            value: Some(value),
+        }
+    }
+}
+
+impl From<&RequirementLevel> for v1::RequirementLevel {
+    fn from(requirement_level: &RequirementLevel) -> Self {
+        use v1::requirement_level::Kind;
+        let kind = match requirement_level {
+            RequirementLevel::Basic(BasicRequirementLevelSpec::Required) => Kind::Required,
+            RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended)
+            | RequirementLevel::Recommended { .. } => Kind::Recommended,
+            RequirementLevel::Basic(BasicRequirementLevelSpec::OptIn)
+            | RequirementLevel::OptIn { .. } => Kind::OptIn,
+            RequirementLevel::ConditionallyRequired { .. } => Kind::ConditionallyRequired,
+        };
+        Self {
+            kind: kind.into(),
+            text: requirement_level.text().map(ToOwned::to_owned),
+        }
+    }
+}
+
+impl From<&Deprecated> for v1::Deprecated {
+    fn from(deprecated: &Deprecated) -> Self {
+        use v1::deprecated::Reason;
+        let (reason, renamed_to, note) = match deprecated {
+            Deprecated::Renamed { renamed_to, note } => (Reason::Renamed, renamed_to.clone(), note),
+            Deprecated::Obsoleted { note } => (Reason::Obsoleted, String::new(), note),
+            Deprecated::Uncategorized { note } => (Reason::Uncategorized, String::new(), note),
+            Deprecated::Unspecified { note } => (Reason::Unspecified, String::new(), note),
+        };
+        Self {
+            reason: reason.into(),
+            renamed_to,
+            note: note.clone(),
+        }
+    }
+}
+
+impl From<&Group> for v1::Group {
+    fn from(group: &Group) -> Self {
+        let span_kind = match group.span_kind {
+            None => v1::SpanKind::Unspecified,
+            Some(SpanKindSpec::Internal) => v1::SpanKind::Internal,
+            Some(SpanKindSpec::Client) => v1::SpanKind::Client,
+            Some(SpanKindSpec::Server) => v1::SpanKind::Server,
+            Some(SpanKindSpec::Producer) => v1::SpanKind::Producer,
+            Some(SpanKindSpec::Consumer) => v1::SpanKind::Consumer,
+        };
+        Self {
+            id: group.id.clone(),
+            r#type: group.r#type.to_string(),
+            brief: group.brief.clone(),
+            note: group.note.clone(),
+            prefix: group.prefix.clone(),
+            extends: group.extends.clone(),
+            stability: stability(group.stability.as_ref()).into(),
+            deprecated: group.deprecated.as_ref().map(Into::into),
+            attributes: group
+                .attributes
+                .iter()
+                .map(|attribute| attribute.0)
+                .collect(),
+            span_kind: span_kind.into(),
+            events: group.events.clone(),
+            metric_name: group.metric_name.clone(),
+            instrument: group.instrument.as_ref().map(ToString::to_string),
+            unit: group.unit.clone(),
+            name: group.name.clone(),
+            display_name: group.display_name.clone(),
+            entity_associations: group.entity_associations.clone(),
+        }
+    }
+}
+
+fn stability(stability: Option<&Stability>) -> v1::Stability {
+    match stability {
+        None => v1::Stability::Unspecified,
+        Some(Stability::Stable) => v1::Stability::Stable,
+        Some(Stability::Development) => v1::Stability::Development,
+        Some(Stability::Alpha) => v1::Stability::Alpha,
+        Some(Stability::Beta) => v1::Stability::Beta,
+        Some(Stability::ReleaseCandidate) => v1::Stability::ReleaseCandidate,
+        #[allow(deprecated)]
+        Some(Stability::Deprecated) => v1::Stability::Deprecated,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use weaver_resolver::SchemaResolver;
+    use weaver_semconv::provenance::Provenance;
+    use weaver_semconv::registry::SemConvRegistry;
+
+    #[test]
+    fn test_encode_resolved_schema() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "registry.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        type:
+          members:
+            - id: get
+              value: GET
+              stability: stable
+            - id: post
+              value: POST
+              stability: stable
+        brief: The HTTP method.
+        stability: stable
+      - id: http.method
+        type: string
+        brief: The HTTP method.
+        stability: development
+        deprecated:
+          reason: renamed
+          renamed_to: http.request.method
+        examples: [GET]
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: http.request.method
+        requirement_level:
+          conditionally_required: If available.
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        let schema = SchemaResolver::resolve_semantic_convention_registry(&mut registry, true)
+            .into_result_failing_non_fatal()
+            .expect("Failed to resolve the registry");
+
+        let encoded = encode_resolved_schema(&schema);
+        let decoded = v1::ResolvedTelemetrySchema::decode(encoded.as_slice())
+            .expect("Failed to decode the resolved schema");
+        assert_eq!(decoded, v1::ResolvedTelemetrySchema::from(&schema));
+        assert_eq!(decoded.registry_id, "test");
+        assert_eq!(decoded.attributes.len(), schema.catalog.count_attributes());
+
+        let span = decoded
+            .groups
+            .iter()
+            .find(|group| group.id == "span.http.client")
+            .expect("The span should be encoded");
+        assert_eq!(span.r#type, "span");
+        assert_eq!(span.span_kind(), v1::SpanKind::Client);
+        assert_eq!(span.stability(), v1::Stability::Stable);
+        assert_eq!(span.attributes.len(), 1);
+
+        let method = &decoded.attributes[span.attributes[0] as usize];
+        assert_eq!(method.name, "http.request.method");
+        let requirement_level = method.requirement_level.as_ref().unwrap();
+        assert_eq!(
+            requirement_level.kind(),
+            v1::requirement_level::Kind::ConditionallyRequired
+        );
+        assert_eq!(requirement_level.text.as_deref(), Some("If available."));
+        let Some(v1::attribute_type::Type::Enum(enum_type)) = method
+            .r#type
+            .as_ref()
+            .and_then(|r#type| r#type.r#type.clone())
+        else {
+            panic!("The method should be an enum");
+        };
+        assert_eq!(
+            enum_type.members[1].value,
+            Some(v1::enum_member::Value::StringValue("POST".to_owned()))
+        );
+
+        let deprecated = decoded
+            .attributes
+            .iter()
+            .find(|attribute| attribute.name == "http.method")
+            .and_then(|attribute| attribute.deprecated.clone())
+            .expect("The attribute should be deprecated");
+        assert_eq!(deprecated.reason(), v1::deprecated::Reason::Renamed);
+        assert_eq!(deprecated.renamed_to, "http.request.method");
+    }
+}
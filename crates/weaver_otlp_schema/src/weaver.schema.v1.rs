@@ -0,0 +1,382 @@
+// This file is @generated by prost-build.
+/// A resolved telemetry schema, i.e. the groups of a resolved semantic
+/// convention registry and the catalog of the attributes they reference.
+///
+/// The examples, annotations and lineage of the telemetry objects are not
+/// included, the YAML or JSON formats of `weaver registry resolve` provide the
+/// complete schema.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResolvedTelemetrySchema {
+    /// The version of the resolved schema file format.
+    #[prost(string, tag = "1")]
+    pub file_format: ::prost::alloc::string::String,
+    /// The schema url.
+    #[prost(string, tag = "2")]
+    pub schema_url: ::prost::alloc::string::String,
+    /// The id of the registry.
+    #[prost(string, tag = "3")]
+    pub registry_id: ::prost::alloc::string::String,
+    /// The url of the registry.
+    #[prost(string, tag = "4")]
+    pub registry_url: ::prost::alloc::string::String,
+    /// The attributes of the registry, referenced by the groups through their
+    /// index in this list.
+    #[prost(message, repeated, tag = "5")]
+    pub attributes: ::prost::alloc::vec::Vec<Attribute>,
+    /// The groups of the registry.
+    #[prost(message, repeated, tag = "6")]
+    pub groups: ::prost::alloc::vec::Vec<Group>,
+}
+/// The deprecation of a telemetry object.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Deprecated {
+    /// The reason of the deprecation.
+    #[prost(enumeration = "deprecated::Reason", tag = "1")]
+    pub reason: i32,
+    /// The id of the replacement, only set when the object has been renamed.
+    #[prost(string, tag = "2")]
+    pub renamed_to: ::prost::alloc::string::String,
+    /// The note providing more context about the deprecation.
+    #[prost(string, tag = "3")]
+    pub note: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `Deprecated`.
+pub mod deprecated {
+    /// The reason of the deprecation.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Reason {
+        /// An unstructured deprecation (legacy deprecated string).
+        Unspecified = 0,
+        /// The object has been renamed to `renamed_to`.
+        Renamed = 1,
+        /// The object has been removed without replacement.
+        Obsoleted = 2,
+        /// The object has been deprecated for another reason.
+        Uncategorized = 3,
+    }
+    impl Reason {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "REASON_UNSPECIFIED",
+                Self::Renamed => "REASON_RENAMED",
+                Self::Obsoleted => "REASON_OBSOLETED",
+                Self::Uncategorized => "REASON_UNCATEGORIZED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "REASON_UNSPECIFIED" => Some(Self::Unspecified),
+                "REASON_RENAMED" => Some(Self::Renamed),
+                "REASON_OBSOLETED" => Some(Self::Obsoleted),
+                "REASON_UNCATEGORIZED" => Some(Self::Uncategorized),
+                _ => None,
+            }
+        }
+    }
+}
+/// An attribute of the catalog.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Attribute {
+    /// The name of the attribute.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The type of the attribute.
+    #[prost(message, optional, tag = "2")]
+    pub r#type: ::core::option::Option<AttributeType>,
+    /// A brief description of the attribute.
+    #[prost(string, tag = "3")]
+    pub brief: ::prost::alloc::string::String,
+    /// A more elaborate description of the attribute.
+    #[prost(string, tag = "4")]
+    pub note: ::prost::alloc::string::String,
+    /// The tag of the attribute (e.g. `tech-specific-rabbitmq`).
+    #[prost(string, optional, tag = "5")]
+    pub tag: ::core::option::Option<::prost::alloc::string::String>,
+    /// The requirement level of the attribute.
+    #[prost(message, optional, tag = "6")]
+    pub requirement_level: ::core::option::Option<RequirementLevel>,
+    /// Whether the attribute is relevant for sampling decisions.
+    #[prost(bool, optional, tag = "7")]
+    pub sampling_relevant: ::core::option::Option<bool>,
+    /// The stability of the attribute.
+    #[prost(enumeration = "Stability", tag = "8")]
+    pub stability: i32,
+    /// The deprecation of the attribute, if deprecated.
+    #[prost(message, optional, tag = "9")]
+    pub deprecated: ::core::option::Option<Deprecated>,
+    /// Whether the attribute is a prefix (template attribute).
+    #[prost(bool, tag = "10")]
+    pub prefix: bool,
+}
+/// The type of an attribute.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AttributeType {
+    #[prost(oneof = "attribute_type::Type", tags = "1, 2")]
+    pub r#type: ::core::option::Option<attribute_type::Type>,
+}
+/// Nested message and enum types in `AttributeType`.
+pub mod attribute_type {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Type {
+        /// A primitive, array or template type, e.g. `string`, `int\[\]` or
+        /// `template\[string\]`.
+        #[prost(string, tag = "1")]
+        Primitive(::prost::alloc::string::String),
+        /// An enum type.
+        #[prost(message, tag = "2")]
+        Enum(super::EnumType),
+    }
+}
+/// An enum type and its members.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnumType {
+    /// The members of the enum.
+    #[prost(message, repeated, tag = "1")]
+    pub members: ::prost::alloc::vec::Vec<EnumMember>,
+}
+/// A member of an enum type.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnumMember {
+    /// The id of the member.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// A brief description of the member.
+    #[prost(string, optional, tag = "6")]
+    pub brief: ::core::option::Option<::prost::alloc::string::String>,
+    /// A more elaborate description of the member.
+    #[prost(string, optional, tag = "7")]
+    pub note: ::core::option::Option<::prost::alloc::string::String>,
+    /// The stability of the member.
+    #[prost(enumeration = "Stability", tag = "8")]
+    pub stability: i32,
+    /// The deprecation of the member, if deprecated.
+    #[prost(message, optional, tag = "9")]
+    pub deprecated: ::core::option::Option<Deprecated>,
+    /// The value of the member.
+    #[prost(oneof = "enum_member::Value", tags = "2, 3, 4, 5")]
+    pub value: ::core::option::Option<enum_member::Value>,
+}
+/// Nested message and enum types in `EnumMember`.
+pub mod enum_member {
+    /// The value of the member.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(string, tag = "2")]
+        StringValue(::prost::alloc::string::String),
+        #[prost(int64, tag = "3")]
+        IntValue(i64),
+        #[prost(double, tag = "4")]
+        DoubleValue(f64),
+        #[prost(bool, tag = "5")]
+        BoolValue(bool),
+    }
+}
+/// The requirement level of an attribute.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequirementLevel {
+    /// The kind of requirement level.
+    #[prost(enumeration = "requirement_level::Kind", tag = "1")]
+    pub kind: i32,
+    /// The condition or the description of the requirement level, if any.
+    #[prost(string, optional, tag = "2")]
+    pub text: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `RequirementLevel`.
+pub mod requirement_level {
+    /// The kind of requirement level.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        /// The requirement level is not specified.
+        Unspecified = 0,
+        /// A required attribute.
+        Required = 1,
+        /// A recommended attribute.
+        Recommended = 2,
+        /// An opt-in attribute.
+        OptIn = 3,
+        /// An attribute required under a condition.
+        ConditionallyRequired = 4,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "KIND_UNSPECIFIED",
+                Self::Required => "KIND_REQUIRED",
+                Self::Recommended => "KIND_RECOMMENDED",
+                Self::OptIn => "KIND_OPT_IN",
+                Self::ConditionallyRequired => "KIND_CONDITIONALLY_REQUIRED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "KIND_UNSPECIFIED" => Some(Self::Unspecified),
+                "KIND_REQUIRED" => Some(Self::Required),
+                "KIND_RECOMMENDED" => Some(Self::Recommended),
+                "KIND_OPT_IN" => Some(Self::OptIn),
+                "KIND_CONDITIONALLY_REQUIRED" => Some(Self::ConditionallyRequired),
+                _ => None,
+            }
+        }
+    }
+}
+/// A group of the registry.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Group {
+    /// The id of the group.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// The type of the group, e.g. `span`, `metric` or `attribute_group`.
+    #[prost(string, tag = "2")]
+    pub r#type: ::prost::alloc::string::String,
+    /// A brief description of the group.
+    #[prost(string, tag = "3")]
+    pub brief: ::prost::alloc::string::String,
+    /// A more elaborate description of the group.
+    #[prost(string, tag = "4")]
+    pub note: ::prost::alloc::string::String,
+    /// The prefix of the attributes of the group.
+    #[prost(string, tag = "5")]
+    pub prefix: ::prost::alloc::string::String,
+    /// The id of the group this group extends.
+    #[prost(string, optional, tag = "6")]
+    pub extends: ::core::option::Option<::prost::alloc::string::String>,
+    /// The stability of the group.
+    #[prost(enumeration = "Stability", tag = "7")]
+    pub stability: i32,
+    /// The deprecation of the group, if deprecated.
+    #[prost(message, optional, tag = "8")]
+    pub deprecated: ::core::option::Option<Deprecated>,
+    /// The attributes of the group, as indexes in the attributes of the schema.
+    #[prost(uint32, repeated, tag = "9")]
+    pub attributes: ::prost::alloc::vec::Vec<u32>,
+    /// The kind of the span (span groups only).
+    #[prost(enumeration = "SpanKind", tag = "10")]
+    pub span_kind: i32,
+    /// The ids of the events associated with the span (span groups only).
+    #[prost(string, repeated, tag = "11")]
+    pub events: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// The name of the metric (metric groups only).
+    #[prost(string, optional, tag = "12")]
+    pub metric_name: ::core::option::Option<::prost::alloc::string::String>,
+    /// The instrument of the metric, e.g. `counter` (metric groups only).
+    #[prost(string, optional, tag = "13")]
+    pub instrument: ::core::option::Option<::prost::alloc::string::String>,
+    /// The unit of the metric (metric groups only).
+    #[prost(string, optional, tag = "14")]
+    pub unit: ::core::option::Option<::prost::alloc::string::String>,
+    /// The name of the event.
+    #[prost(string, optional, tag = "15")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    /// The readable name of the group.
+    #[prost(string, optional, tag = "16")]
+    pub display_name: ::core::option::Option<::prost::alloc::string::String>,
+    /// The types of the entities the group is associated with.
+    #[prost(string, repeated, tag = "17")]
+    pub entity_associations: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// The stability of a telemetry object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Stability {
+    /// The stability is not specified.
+    Unspecified = 0,
+    /// A stable definition.
+    Stable = 1,
+    /// A definition in development, formerly known as experimental.
+    Development = 2,
+    /// An alpha definition.
+    Alpha = 3,
+    /// A beta definition.
+    Beta = 4,
+    /// A release candidate definition.
+    ReleaseCandidate = 5,
+    /// A deprecated definition (legacy stability level).
+    Deprecated = 6,
+}
+impl Stability {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "STABILITY_UNSPECIFIED",
+            Self::Stable => "STABILITY_STABLE",
+            Self::Development => "STABILITY_DEVELOPMENT",
+            Self::Alpha => "STABILITY_ALPHA",
+            Self::Beta => "STABILITY_BETA",
+            Self::ReleaseCandidate => "STABILITY_RELEASE_CANDIDATE",
+            Self::Deprecated => "STABILITY_DEPRECATED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STABILITY_UNSPECIFIED" => Some(Self::Unspecified),
+            "STABILITY_STABLE" => Some(Self::Stable),
+            "STABILITY_DEVELOPMENT" => Some(Self::Development),
+            "STABILITY_ALPHA" => Some(Self::Alpha),
+            "STABILITY_BETA" => Some(Self::Beta),
+            "STABILITY_RELEASE_CANDIDATE" => Some(Self::ReleaseCandidate),
+            "STABILITY_DEPRECATED" => Some(Self::Deprecated),
+            _ => None,
+        }
+    }
+}
+/// The kind of a span.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SpanKind {
+    /// The kind is not specified.
+    Unspecified = 0,
+    /// An internal operation.
+    Internal = 1,
+    /// A request to a remote service.
+    Client = 2,
+    /// The handling of a remote request.
+    Server = 3,
+    /// A message sent to a broker.
+    Producer = 4,
+    /// A message received from a broker.
+    Consumer = 5,
+}
+impl SpanKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SPAN_KIND_UNSPECIFIED",
+            Self::Internal => "SPAN_KIND_INTERNAL",
+            Self::Client => "SPAN_KIND_CLIENT",
+            Self::Server => "SPAN_KIND_SERVER",
+            Self::Producer => "SPAN_KIND_PRODUCER",
+            Self::Consumer => "SPAN_KIND_CONSUMER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SPAN_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "SPAN_KIND_INTERNAL" => Some(Self::Internal),
+            "SPAN_KIND_CLIENT" => Some(Self::Client),
+            "SPAN_KIND_SERVER" => Some(Self::Server),
+            "SPAN_KIND_PRODUCER" => Some(Self::Producer),
+            "SPAN_KIND_CONSUMER" => Some(Self::Consumer),
+            _ => None,
+        }
+    }
+}
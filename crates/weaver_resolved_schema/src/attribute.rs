@@ -17,6 +17,7 @@ use weaver_semconv::attribute::{
     AttributeRole, AttributeSpec, AttributeType, Examples, RequirementLevel,
 };
 use weaver_semconv::deprecated::Deprecated;
+use weaver_semconv::provenance::Provenance;
 use weaver_semconv::stability::Stability;
 use weaver_semconv::YamlValue;
 
@@ -26,6 +27,11 @@ use weaver_semconv::YamlValue;
 pub struct Attribute {
     /// Attribute name.
     pub name: String,
+    /// The name of the attribute in a previous version of the registry, if
+    /// the attribute has been renamed.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renamed_from: Option<String>,
     /// Either a string literal denoting the type as a primitive or an
     /// array type, a template type or an enum definition.
     pub r#type: AttributeType,
@@ -100,6 +106,9 @@ pub struct Attribute {
 pub struct UnresolvedAttribute {
     /// The attribute specification.
     pub spec: AttributeSpec,
+    /// The provenance of the file defining the attribute, i.e. the file of
+    /// the parent group for an inherited attribute.
+    pub provenance: Provenance,
 }
 
 /// An internal reference to an attribute in the catalog.
@@ -121,6 +130,7 @@ impl Attribute {
     pub(crate) fn string<S: AsRef<str>>(name: S, brief: S, note: S) -> Self {
         Self {
             name: name.as_ref().to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: brief.as_ref().to_owned(),
             examples: None,
@@ -144,6 +154,7 @@ impl Attribute {
     pub(crate) fn int<S: AsRef<str>>(name: S, brief: S, note: S) -> Self {
         Self {
             name: name.as_ref().to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
             brief: brief.as_ref().to_owned(),
             examples: None,
@@ -167,6 +178,7 @@ impl Attribute {
     pub(crate) fn double<S: AsRef<str>>(name: S, brief: S, note: S) -> Self {
         Self {
             name: name.as_ref().to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Double),
             brief: brief.as_ref().to_owned(),
             examples: None,
@@ -194,6 +206,7 @@ impl Attribute {
     ) -> Self {
         Self {
             name: name.into().to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Boolean),
             brief: brief.into().to_owned(),
             examples: None,
@@ -38,6 +38,10 @@ pub struct Stats {
     pub stability_breakdown: HashMap<Stability, usize>,
     /// Number of deprecated attributes.
     pub deprecated_count: usize,
+    /// Number of enum attributes.
+    pub enum_count: usize,
+    /// Total number of members of the enum attributes.
+    pub enum_member_count: usize,
 }
 
 impl Catalog {
@@ -137,6 +141,19 @@ impl Catalog {
                 .iter()
                 .filter(|attr| attr.deprecated.is_some())
                 .count(),
+            enum_count: self
+                .attributes
+                .iter()
+                .filter(|attr| matches!(attr.r#type, AttributeType::Enum { .. }))
+                .count(),
+            enum_member_count: self
+                .attributes
+                .iter()
+                .map(|attr| match &attr.r#type {
+                    AttributeType::Enum { members, .. } => members.len(),
+                    _ => 0,
+                })
+                .sum(),
         }
     }
 }
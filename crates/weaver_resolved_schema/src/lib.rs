@@ -12,11 +12,14 @@ use crate::registry::{Group, Registry};
 use crate::resource::Resource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use weaver_semconv::deprecated::Deprecated;
 use weaver_semconv::group::GroupType;
 use weaver_semconv::manifest::RegistryManifest;
-use weaver_version::schema_changes::{SchemaChanges, SchemaItemChange, SchemaItemType};
+use weaver_version::schema_changes::{
+    FieldChange, SchemaChanges, SchemaItemChange, SchemaItemType,
+};
+use weaver_version::telemetry_schema_changes;
 use weaver_version::Versions;
 
 pub mod attribute;
@@ -118,6 +121,7 @@ impl ResolvedTelemetrySchema {
         let attr_refs = self.catalog.add_attributes(attrs);
         self.registry.groups.push(Group {
             id: group_id.to_owned(),
+            renamed_from: None,
             r#type: GroupType::Metric,
             brief: "".to_owned(),
             note: "".to_owned(),
@@ -161,6 +165,7 @@ impl ResolvedTelemetrySchema {
         let attr_refs: Vec<attribute::AttributeRef> = self.catalog.add_attributes(attrs);
         self.registry.groups.push(Group {
             id: group_id.to_owned(),
+            renamed_from: None,
             r#type: GroupType::AttributeGroup,
             brief: "".to_owned(),
             note: "".to_owned(),
@@ -357,14 +362,39 @@ impl ResolvedTelemetrySchema {
         let latest_attributes = self.registry_attribute_map();
         let baseline_attributes = baseline_schema.registry_attribute_map();
 
-        // ToDo for future PR, process differences at the field level (not required for the schema update)
-
         // Collect all the information related to the attributes that have been
-        // deprecated in the latest schema.
+        // updated or deprecated in the latest schema.
         for (attr_name, attr) in latest_attributes.iter() {
             let baseline_attr = baseline_attributes.get(attr_name);
 
             if let Some(baseline_attr) = baseline_attr {
+                let fields = field_changes([
+                    (
+                        "type",
+                        Some(baseline_attr.r#type.to_string()),
+                        Some(attr.r#type.to_string()),
+                    ),
+                    (
+                        "requirement_level",
+                        Some(baseline_attr.requirement_level.to_string()),
+                        Some(attr.requirement_level.to_string()),
+                    ),
+                    (
+                        "stability",
+                        baseline_attr.stability.as_ref().map(ToString::to_string),
+                        attr.stability.as_ref().map(ToString::to_string),
+                    ),
+                ]);
+                if !fields.is_empty() {
+                    changes.add_change(
+                        SchemaItemType::RegistryAttributes,
+                        SchemaItemChange::Updated {
+                            name: attr.name.clone(),
+                            fields,
+                        },
+                    );
+                }
+
                 if let Some(deprecated) = attr.deprecated.as_ref() {
                     // is this a change from the baseline?
                     if let Some(baseline_deprecated) = baseline_attr.deprecated.as_ref() {
@@ -409,6 +439,17 @@ impl ResolvedTelemetrySchema {
                         }
                     }
                 }
+            } else if let Some(old_name) = attr.renamed_from.as_deref().filter(|old_name| {
+                is_rename_target(old_name, &latest_attributes, &baseline_attributes)
+            }) {
+                changes.add_change(
+                    SchemaItemType::RegistryAttributes,
+                    SchemaItemChange::Renamed {
+                        old_name: old_name.to_owned(),
+                        new_name: attr.name.clone(),
+                        note: String::new(),
+                    },
+                );
             } else {
                 changes.add_change(
                     SchemaItemType::RegistryAttributes,
@@ -420,11 +461,15 @@ impl ResolvedTelemetrySchema {
         }
 
         // Any attribute in the baseline schema that is not present in the latest schema
-        // is considered removed.
+        // is considered removed, unless it has been renamed (see `renamed_from`).
         // Note: This should never occur if the registry evolution process is followed.
         // However, detecting this case is useful for identifying a violation of the process.
+        let renamed_from: HashSet<&str> = latest_attributes
+            .values()
+            .filter_map(|attr| attr.renamed_from.as_deref())
+            .collect();
         for (attr_name, attr) in baseline_attributes.iter() {
-            if !latest_attributes.contains_key(attr_name) {
+            if !latest_attributes.contains_key(attr_name) && !renamed_from.contains(attr_name) {
                 changes.add_change(
                     SchemaItemType::RegistryAttributes,
                     SchemaItemChange::Removed {
@@ -443,11 +488,26 @@ impl ResolvedTelemetrySchema {
         changes: &mut SchemaChanges,
     ) {
         // Collect all the information related to the signals that have been
-        // deprecated in the latest schema.
+        // updated or deprecated in the latest schema.
         for (signal_name, group) in latest_signals.iter() {
             let baseline_group = baseline_signals.get(signal_name);
 
             if let Some(baseline_group) = baseline_group {
+                let fields = field_changes([(
+                    "stability",
+                    baseline_group.stability.as_ref().map(ToString::to_string),
+                    group.stability.as_ref().map(ToString::to_string),
+                )]);
+                if !fields.is_empty() {
+                    changes.add_change(
+                        schema_item_type,
+                        SchemaItemChange::Updated {
+                            name: (*signal_name).to_owned(),
+                            fields,
+                        },
+                    );
+                }
+
                 if let Some(deprecated) = group.deprecated.as_ref() {
                     // is this a change from the baseline?
                     if let Some(baseline_deprecated) = baseline_group.deprecated.as_ref() {
@@ -490,6 +550,20 @@ impl ResolvedTelemetrySchema {
                         }
                     }
                 }
+            } else if let Some(old_name) = group
+                .renamed_from
+                .as_deref()
+                .and_then(|group_id| signal_name_of(group_id, baseline_signals))
+                .filter(|old_name| is_rename_target(old_name, latest_signals, baseline_signals))
+            {
+                changes.add_change(
+                    schema_item_type,
+                    SchemaItemChange::Renamed {
+                        old_name: old_name.to_owned(),
+                        new_name: (*signal_name).to_owned(),
+                        note: String::new(),
+                    },
+                );
             } else {
                 changes.add_change(
                     schema_item_type,
@@ -501,11 +575,16 @@ impl ResolvedTelemetrySchema {
         }
 
         // Any signal in the baseline schema that is not present in the latest schema
-        // is considered removed.
+        // is considered removed, unless it has been renamed (see `renamed_from`).
         // Note: This should never occur if the registry evolution process is followed.
         // However, detecting this case is useful for identifying a violation of the process.
+        let renamed_from: HashSet<&str> = latest_signals
+            .values()
+            .filter_map(|group| group.renamed_from.as_deref())
+            .filter_map(|group_id| signal_name_of(group_id, baseline_signals))
+            .collect();
         for (signal_name, _) in baseline_signals.iter() {
-            if !latest_signals.contains_key(signal_name) {
+            if !latest_signals.contains_key(signal_name) && !renamed_from.contains(signal_name) {
                 changes.add_change(
                     schema_item_type,
                     SchemaItemChange::Removed {
@@ -517,14 +596,68 @@ impl ResolvedTelemetrySchema {
     }
 }
 
+/// Returns true if the given baseline object has been renamed, i.e. it exists in
+/// the baseline schema but no longer in the latest schema.
+fn is_rename_target<T>(
+    old_name: &str,
+    latest: &HashMap<&str, &T>,
+    baseline: &HashMap<&str, &T>,
+) -> bool {
+    baseline.contains_key(old_name) && !latest.contains_key(old_name)
+}
+
+/// Returns the signal name of the baseline group with the given id.
+fn signal_name_of<'a>(
+    group_id: &str,
+    baseline_signals: &HashMap<&'a str, &Group>,
+) -> Option<&'a str> {
+    baseline_signals
+        .iter()
+        .find(|(_, group)| group.id == group_id)
+        .map(|(signal_name, _)| *signal_name)
+}
+
+/// Returns the fields whose baseline value (first) differs from the head value
+/// (second).
+fn field_changes<const N: usize>(
+    fields: [(&str, Option<String>, Option<String>); N],
+) -> Vec<FieldChange> {
+    fields
+        .into_iter()
+        .filter(|(_, baseline, head)| baseline != head)
+        .map(|(field, baseline, head)| FieldChange {
+            field: field.to_owned(),
+            baseline,
+            head,
+        })
+        .collect()
+}
+
+/// Generate the changes between a baseline schema and the current schema (must
+/// be the most recent one) in the OpenTelemetry telemetry schema format, i.e. a
+/// `versions` section containing the changes of the given version.
+///
+/// The changes that cannot be expressed in this format, e.g. attributes removed
+/// without a rename target, are reported in `non_representable`.
+#[must_use]
+pub fn diff_to_schema_changes(
+    old: &ResolvedTelemetrySchema,
+    new: &ResolvedTelemetrySchema,
+    version: semver::Version,
+) -> telemetry_schema_changes::SchemaChanges {
+    telemetry_schema_changes::SchemaChanges::from_registry_changes(&new.diff(old), version)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::attribute::Attribute;
-    use crate::ResolvedTelemetrySchema;
+    use crate::{diff_to_schema_changes, ResolvedTelemetrySchema};
     use schemars::schema_for;
     use serde_json::to_string_pretty;
+    use weaver_semconv::attribute::{BasicRequirementLevelSpec, RequirementLevel};
     use weaver_semconv::deprecated::Deprecated;
-    use weaver_version::schema_changes::{SchemaItemChange, SchemaItemType};
+    use weaver_semconv::stability::Stability;
+    use weaver_version::schema_changes::{FieldChange, SchemaItemChange, SchemaItemType};
 
     #[test]
     fn test_json_schema_gen() {
@@ -580,6 +713,47 @@ mod tests {
         assert_eq!(changes.count_added_registry_attributes(), 2);
     }
 
+    #[test]
+    fn detect_updated_registry_attribute() {
+        let mut prior_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
+        prior_schema.add_attribute_group(
+            "registry.group1",
+            [
+                Attribute::boolean("attr1", "brief1", "note1"),
+                Attribute::double("attr2", "brief2", "note2"),
+            ],
+        );
+
+        let mut attr2 = Attribute::int("attr2", "brief2", "note2").brief("new brief2");
+        attr2.requirement_level = RequirementLevel::Basic(BasicRequirementLevelSpec::Required);
+        attr2.stability = Some(Stability::Stable);
+        let mut latest_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
+        latest_schema.add_attribute_group(
+            "registry.group1",
+            [Attribute::boolean("attr1", "brief1", "note1"), attr2],
+        );
+
+        let changes = latest_schema.diff(&prior_schema);
+        assert_eq!(changes.count_changes(), 1);
+        assert_eq!(changes.count_updated_registry_attributes(), 1);
+        let field = |field: &str, baseline: Option<&str>, head: Option<&str>| FieldChange {
+            field: field.to_owned(),
+            baseline: baseline.map(ToOwned::to_owned),
+            head: head.map(ToOwned::to_owned),
+        };
+        assert_eq!(
+            changes.changes_by_type(SchemaItemType::RegistryAttributes),
+            Some(&vec![SchemaItemChange::Updated {
+                name: "attr2".to_owned(),
+                fields: vec![
+                    field("type", Some("double"), Some("int")),
+                    field("requirement_level", Some("recommended"), Some("required")),
+                    field("stability", None, Some("stable")),
+                ],
+            }])
+        );
+    }
+
     #[test]
     fn detect_2_deprecated_registry_attributes() {
         let mut prior_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
@@ -689,6 +863,50 @@ mod tests {
         assert_eq!(changes.count_added_registry_attributes(), 2);
     }
 
+    #[test]
+    fn diff_to_schema_changes_renamed_attribute() {
+        let mut prior_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
+        prior_schema.add_attribute_group(
+            "registry.group1",
+            [
+                Attribute::string("attr1", "brief1", "note1"),
+                Attribute::string("attr2", "brief2", "note2"),
+            ],
+        );
+
+        // attr1 is renamed attr1_bis and attr2 is removed.
+        let mut latest_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
+        latest_schema.add_attribute_group(
+            "registry.group1",
+            [
+                Attribute::string("attr1", "brief1", "note1").deprecated(Deprecated::Renamed {
+                    renamed_to: "attr1_bis".to_owned(),
+                    note: "".to_owned(),
+                }),
+                Attribute::string("attr1_bis", "brief1", "note1"),
+            ],
+        );
+
+        let schema_changes =
+            diff_to_schema_changes(&prior_schema, &latest_schema, semver::Version::new(1, 1, 0));
+        let versions = schema_changes.versions.versions_desc();
+        assert_eq!(versions.len(), 1);
+        let spec = versions[0].1;
+        let rename_attributes = &spec.all.as_ref().expect("Missing `all` changes").changes[0]
+            .rename_attributes
+            .attribute_map;
+        assert_eq!(rename_attributes.len(), 1);
+        assert_eq!(rename_attributes["attr1"], "attr1_bis");
+        assert!(spec.metrics.is_none());
+        assert_eq!(schema_changes.non_representable.len(), 1);
+        assert_eq!(
+            schema_changes.non_representable[0].change,
+            SchemaItemChange::Removed {
+                name: "attr2".to_owned(),
+            }
+        );
+    }
+
     #[test]
     fn detect_2_attributes_renamed_to_the_same_existing_attribute() {
         let mut prior_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
@@ -722,9 +940,11 @@ mod tests {
         latest_schema.add_attribute_group("group2", [Attribute::string("attr5", "brief", "note")]);
 
         let changes = latest_schema.diff(&prior_schema);
-        assert_eq!(changes.count_changes(), 2);
-        assert_eq!(changes.count_registry_attribute_changes(), 2);
+        // The type of `attr3` changed as well.
+        assert_eq!(changes.count_changes(), 3);
+        assert_eq!(changes.count_registry_attribute_changes(), 3);
         assert_eq!(changes.count_renamed_registry_attributes(), 2);
+        assert_eq!(changes.count_updated_registry_attributes(), 1);
         dbg!(&changes);
     }
 
@@ -763,10 +983,12 @@ mod tests {
         );
 
         let changes = latest_schema.diff(&prior_schema);
-        assert_eq!(changes.count_changes(), 3);
-        assert_eq!(changes.count_registry_attribute_changes(), 3);
+        // The type of `attr3` changed as well.
+        assert_eq!(changes.count_changes(), 4);
+        assert_eq!(changes.count_registry_attribute_changes(), 4);
         assert_eq!(changes.count_renamed_registry_attributes(), 2);
         assert_eq!(changes.count_added_registry_attributes(), 1);
+        assert_eq!(changes.count_updated_registry_attributes(), 1);
         dbg!(&changes);
     }
 
@@ -845,4 +1067,74 @@ mod tests {
         };
         assert_eq!(name, "system.cpu.time");
     }
+
+    #[test]
+    fn detect_attribute_renamed_from() {
+        let mut prior_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
+        prior_schema.add_attribute_group(
+            "registry.group1",
+            [
+                Attribute::string("attr1", "brief1", "note1"),
+                Attribute::string("attr2", "brief2", "note2"),
+            ],
+        );
+
+        // attr1 is renamed attr1_bis without keeping a deprecated attr1.
+        let mut latest_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
+        latest_schema.add_attribute_group(
+            "registry.group1",
+            [
+                Attribute {
+                    renamed_from: Some("attr1".to_owned()),
+                    ..Attribute::string("attr1_bis", "brief1", "note1")
+                },
+                Attribute::string("attr2", "brief2", "note2"),
+            ],
+        );
+
+        let changes = latest_schema.diff(&prior_schema);
+        assert_eq!(changes.count_changes(), 1);
+        assert_eq!(changes.count_renamed_registry_attributes(), 1);
+        assert_eq!(changes.count_added_registry_attributes(), 0);
+        assert_eq!(changes.count_removed_registry_attributes(), 0);
+
+        // The generated schema file renames the attribute.
+        let schema_changes =
+            diff_to_schema_changes(&prior_schema, &latest_schema, semver::Version::new(1, 1, 0));
+        let versions = schema_changes.versions.versions_desc();
+        let rename_attributes = &versions[0]
+            .1
+            .all
+            .as_ref()
+            .expect("Missing `all` changes")
+            .changes[0]
+            .rename_attributes
+            .attribute_map;
+        assert_eq!(rename_attributes["attr1"], "attr1_bis");
+        assert!(schema_changes.non_representable.is_empty());
+    }
+
+    #[test]
+    fn detect_metric_renamed_from() {
+        let mut prior_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
+        prior_schema.add_metric_group("metrics.cpu.time", "cpu.time", [], None);
+        let mut latest_schema = ResolvedTelemetrySchema::new("1.0", "", "", "");
+        latest_schema.add_metric_group("metrics.system.cpu.time", "system.cpu.time", [], None);
+        latest_schema.registry.groups[0].renamed_from = Some("metrics.cpu.time".to_owned());
+
+        let changes = latest_schema.diff(&prior_schema);
+        assert_eq!(changes.count_changes(), 1);
+        let Some(
+            [SchemaItemChange::Renamed {
+                old_name, new_name, ..
+            }],
+        ) = changes
+            .changes_by_type(SchemaItemType::Metrics)
+            .map(Vec::as_slice)
+        else {
+            panic!("No rename change found in: {changes:?}");
+        };
+        assert_eq!(old_name, "cpu.time");
+        assert_eq!(new_name, "system.cpu.time");
+    }
 }
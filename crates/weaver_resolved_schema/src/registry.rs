@@ -50,6 +50,11 @@ pub struct Stats {
 pub struct Group {
     /// The id that uniquely identifies the semantic convention.
     pub id: String,
+    /// The id of the group in a previous version of the registry, if the group
+    /// has been renamed.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renamed_from: Option<String>,
     /// The type of the group including the specific fields for each type.
     pub r#type: GroupType,
     /// A brief description of the semantic convention.
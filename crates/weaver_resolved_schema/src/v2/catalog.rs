@@ -136,6 +136,7 @@ mod test {
 
         let result = catalog.convert_ref(&crate::attribute::Attribute {
             name: key.clone(),
+            renamed_from: None,
             r#type: atype.clone(),
             brief: brief.clone(),
             examples: None,
@@ -156,6 +157,7 @@ mod test {
         // Make sure "none" annotations is the same as empty annotations.
         let result2 = catalog.convert_ref(&crate::attribute::Attribute {
             name: key.clone(),
+            renamed_from: None,
             r#type: atype.clone(),
             brief: brief.clone(),
             examples: None,
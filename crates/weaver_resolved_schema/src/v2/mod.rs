@@ -611,6 +611,7 @@ mod tests {
         let test_refs = v1_catalog.add_attributes([
             Attribute {
                 name: "test.key".to_owned(),
+                renamed_from: None,
                 r#type: weaver_semconv::attribute::AttributeType::PrimitiveOrArray(
                     weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
                 ),
@@ -632,6 +633,7 @@ mod tests {
             },
             Attribute {
                 name: "test.key".to_owned(),
+                renamed_from: None,
                 r#type: weaver_semconv::attribute::AttributeType::PrimitiveOrArray(
                     weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
                 ),
@@ -661,6 +663,7 @@ mod tests {
             groups: vec![
                 Group {
                     id: "span.my-span".to_owned(),
+                    renamed_from: None,
                     r#type: GroupType::Span,
                     brief: "".to_owned(),
                     note: "".to_owned(),
@@ -684,6 +687,7 @@ mod tests {
                 },
                 Group {
                     id: "span.custom".to_owned(),
+                    renamed_from: None,
                     r#type: GroupType::Span,
                     brief: "".to_owned(),
                     note: "".to_owned(),
@@ -739,6 +743,7 @@ mod tests {
         let test_refs = v1_catalog.add_attributes([
             Attribute {
                 name: "test.key".to_owned(),
+                renamed_from: None,
                 r#type: weaver_semconv::attribute::AttributeType::PrimitiveOrArray(
                     weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
                 ),
@@ -760,6 +765,7 @@ mod tests {
             },
             Attribute {
                 name: "test.key".to_owned(),
+                renamed_from: None,
                 r#type: weaver_semconv::attribute::AttributeType::PrimitiveOrArray(
                     weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
                 ),
@@ -789,6 +795,7 @@ mod tests {
             groups: vec![
                 Group {
                     id: "metric.http".to_owned(),
+                    renamed_from: None,
                     r#type: GroupType::Metric,
                     brief: "".to_owned(),
                     note: "".to_owned(),
@@ -812,6 +819,7 @@ mod tests {
                 },
                 Group {
                     id: "metric.http.custom".to_owned(),
+                    renamed_from: None,
                     r#type: GroupType::Metric,
                     brief: "".to_owned(),
                     note: "".to_owned(),
@@ -864,6 +872,7 @@ mod tests {
         let mut v1_catalog = crate::catalog::Catalog::from_attributes(vec![]);
         let test_refs = v1_catalog.add_attributes([Attribute {
             name: "test.key".to_owned(),
+            renamed_from: None,
             r#type: weaver_semconv::attribute::AttributeType::PrimitiveOrArray(
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
@@ -887,6 +896,7 @@ mod tests {
             registry_url: "my.schema.url".to_owned(),
             groups: vec![Group {
                 id: "event.my-event".to_owned(),
+                renamed_from: None,
                 r#type: GroupType::Event,
                 brief: "".to_owned(),
                 note: "".to_owned(),
@@ -923,6 +933,7 @@ mod tests {
         let mut v1_catalog = crate::catalog::Catalog::from_attributes(vec![]);
         let test_refs = v1_catalog.add_attributes([Attribute {
             name: "test.key".to_owned(),
+            renamed_from: None,
             r#type: weaver_semconv::attribute::AttributeType::PrimitiveOrArray(
                 weaver_semconv::attribute::PrimitiveOrArrayTypeSpec::String,
             ),
@@ -946,6 +957,7 @@ mod tests {
             registry_url: "my.schema.url".to_owned(),
             groups: vec![Group {
                 id: "entity.my-entity".to_owned(),
+                renamed_from: None,
                 r#type: GroupType::Entity,
                 brief: "".to_owned(),
                 note: "".to_owned(),
@@ -9,7 +9,7 @@ use serde::Deserialize;
 use weaver_resolved_schema::attribute;
 use weaver_resolved_schema::attribute::AttributeRef;
 use weaver_resolved_schema::lineage::{AttributeLineage, GroupLineage};
-use weaver_semconv::attribute::AttributeSpec;
+use weaver_semconv::attribute::{note_with_examples, AttributeSpec};
 
 /// A catalog of deduplicated resolved attributes with their corresponding reference.
 #[derive(Deserialize, Debug, Default, PartialEq)]
@@ -88,6 +88,15 @@ impl AttributeCatalog {
         gc_map
     }
 
+    /// Returns the list of indexed attributes ordered by their references.
+    #[must_use]
+    pub fn attribute_index(&self) -> Vec<&attribute::Attribute> {
+        let mut attributes: Vec<(&attribute::Attribute, &AttributeRef)> =
+            self.attribute_refs.iter().collect();
+        attributes.sort_by_key(|(_, attr_ref)| attr_ref.0);
+        attributes.into_iter().map(|(attr, _)| attr).collect()
+    }
+
     /// Returns a list of indexed attribute names ordered by their references.
     #[must_use]
     pub fn attribute_name_index(&self) -> Vec<String> {
@@ -119,11 +128,13 @@ impl AttributeCatalog {
                 requirement_level,
                 sampling_relevant,
                 note,
+                examples_note,
                 stability,
                 deprecated,
                 prefix,
                 annotations,
                 role,
+                scope: _,
             } => {
                 let name;
                 let root_attr = self.root_attributes.get(r#ref);
@@ -142,6 +153,7 @@ impl AttributeCatalog {
                     // values if they are present.
                     let resolved_attr = attribute::Attribute {
                         name: name.clone(),
+                        renamed_from: root_attr.attribute.renamed_from.clone(),
                         r#type: root_attr.attribute.r#type.clone(),
                         brief: attr_lineage.brief(brief, &root_attr.attribute.brief),
                         examples: attr_lineage.examples(examples, &root_attr.attribute.examples),
@@ -154,7 +166,12 @@ impl AttributeCatalog {
                             sampling_relevant,
                             &root_attr.attribute.sampling_relevant,
                         ),
-                        note: attr_lineage.note(note, &root_attr.attribute.note),
+                        note: attr_lineage.note(
+                            &note
+                                .as_ref()
+                                .map(|note| note_with_examples(note, examples_note.as_deref())),
+                            &root_attr.attribute.note,
+                        ),
                         stability: attr_lineage
                             .stability(stability, &root_attr.attribute.stability),
                         deprecated: attr_lineage
@@ -194,6 +211,7 @@ impl AttributeCatalog {
             }
             AttributeSpec::Id {
                 id,
+                renamed_from,
                 r#type,
                 brief,
                 examples,
@@ -201,10 +219,12 @@ impl AttributeCatalog {
                 requirement_level,
                 sampling_relevant,
                 note,
+                examples_note,
                 stability,
                 deprecated,
                 annotations,
                 role,
+                scope: _,
             } => {
                 // Create a fully resolved attribute from an attribute spec (id),
                 // and check if it already exists in the catalog.
@@ -212,13 +232,14 @@ impl AttributeCatalog {
                 // If it does not, add it to the catalog and return a new reference.
                 let attr = attribute::Attribute {
                     name: id.clone(),
+                    renamed_from: renamed_from.clone(),
                     r#type: r#type.clone(),
                     brief: brief.clone().unwrap_or_default(),
                     examples: examples.clone(),
                     tag: tag.clone(),
                     requirement_level: requirement_level.clone(),
                     sampling_relevant: *sampling_relevant,
-                    note: note.clone(),
+                    note: note_with_examples(note, examples_note.as_deref()),
                     stability: stability.clone(),
                     deprecated: deprecated.clone(),
                     tags: None,
@@ -352,6 +373,7 @@ mod tests {
 
     fn gen_attr_by_name(name: String, requirement_level: RequirementLevel) -> attribute::Attribute {
         attribute::Attribute {
+            renamed_from: None,
             name,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Boolean),
             brief: "brief".to_owned(),
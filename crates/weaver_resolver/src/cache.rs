@@ -0,0 +1,353 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Caches of the resolution of semantic convention registries, in memory
+//! ([`CachedRegistry`]) or on disk across runs ([`ResolutionCache`]).
+
+use miette::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use weaver_common::result::WResult;
+use weaver_resolved_schema::ResolvedTelemetrySchema;
+use weaver_semconv::registry::SemConvRegistry;
+
+use crate::{Error, SchemaResolver};
+
+/// The default version stamp of the resolution cache entries.
+///
+/// Entries are invalidated on every release of this crate as the resolution
+/// process and the format of the resolved schema may change.
+pub const RESOLUTION_CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A semantic convention registry that is resolved lazily and only once.
+///
+/// The resolved telemetry schema is built on the first call to
+/// [`CachedRegistry::resolved`] and reused until the cache is invalidated,
+/// either explicitly with [`CachedRegistry::invalidate`] or implicitly when the
+/// underlying registry is mutated through [`CachedRegistry::registry_mut`].
+pub struct CachedRegistry {
+    /// The semantic convention registry to resolve.
+    registry: SemConvRegistry,
+    /// Whether the unreferenced objects are kept in the resolved registry.
+    include_unreferenced: bool,
+    /// The resolved telemetry schema, if still valid.
+    resolved: Option<ResolvedTelemetrySchema>,
+    /// The warnings reported by the last resolution.
+    warnings: Vec<Error>,
+    /// The number of resolutions performed so far.
+    resolution_count: usize,
+}
+
+impl CachedRegistry {
+    /// Creates a new cached registry.
+    #[must_use]
+    pub fn new(registry: SemConvRegistry, include_unreferenced: bool) -> Self {
+        Self {
+            registry,
+            include_unreferenced,
+            resolved: None,
+            warnings: vec![],
+            resolution_count: 0,
+        }
+    }
+
+    /// Returns the underlying semantic convention registry.
+    pub fn registry(&self) -> &SemConvRegistry {
+        &self.registry
+    }
+
+    /// Returns a mutable reference to the underlying semantic convention
+    /// registry and invalidates the cached resolution.
+    pub fn registry_mut(&mut self) -> &mut SemConvRegistry {
+        self.invalidate();
+        &mut self.registry
+    }
+
+    /// Invalidates the cached resolution, the next call to
+    /// [`CachedRegistry::resolved`] will resolve the registry again.
+    pub fn invalidate(&mut self) {
+        self.resolved = None;
+        self.warnings.clear();
+    }
+
+    /// Returns the resolved telemetry schema, resolving the registry if the
+    /// cache is empty.
+    ///
+    /// The warnings are not considered as errors, they are available through
+    /// [`CachedRegistry::warnings`].
+    pub fn resolved(&mut self) -> Result<&ResolvedTelemetrySchema, Error> {
+        let resolved = if let Some(resolved) = self.resolved.take() {
+            resolved
+        } else {
+            self.resolution_count += 1;
+            let result = SchemaResolver::resolve_semantic_convention_registry(
+                &mut self.registry,
+                self.include_unreferenced,
+            );
+            let result = match result {
+                WResult::OkWithNFEs(resolved, nfes) => {
+                    let (warnings, errors): (Vec<_>, Vec<_>) = nfes
+                        .into_iter()
+                        .partition(|e| matches!(e.severity(), Some(miette::Severity::Warning)));
+                    self.warnings = warnings;
+                    WResult::with_non_fatal_errors(resolved, errors)
+                }
+                result => result,
+            };
+            result.into_result_failing_non_fatal()?
+        };
+        Ok(self.resolved.insert(resolved))
+    }
+
+    /// Returns the warnings reported by the last resolution.
+    pub fn warnings(&self) -> &[Error] {
+        &self.warnings
+    }
+
+    /// Returns the number of resolutions performed so far.
+    #[must_use]
+    pub fn resolution_count(&self) -> usize {
+        self.resolution_count
+    }
+}
+
+/// An entry of the resolution cache.
+#[derive(Serialize, Deserialize)]
+struct ResolutionCacheEntry {
+    /// The version stamp of the cache that wrote this entry.
+    version: String,
+    /// The resolved telemetry schema.
+    schema: ResolvedTelemetrySchema,
+}
+
+/// A cache of resolved telemetry schemas stored in a directory.
+///
+/// The entries are keyed by the content hash of the semantic convention
+/// registry (see [`SemConvRegistry::content_hash`]) and the resolution options,
+/// so a change of any input file resolves the registry again. Only the
+/// resolutions without findings are cached, so the warnings of a registry are
+/// reported on every run.
+#[derive(Debug, Clone)]
+pub struct ResolutionCache {
+    /// The directory containing the cache entries.
+    dir: PathBuf,
+    /// The version stamp of the cache entries.
+    version: String,
+}
+
+impl ResolutionCache {
+    /// Creates a cache storing its entries in the given directory.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self::with_version(dir, RESOLUTION_CACHE_VERSION)
+    }
+
+    /// Creates a cache storing its entries in the given directory with a custom
+    /// version stamp.
+    pub fn with_version<P: Into<PathBuf>, S: Into<String>>(dir: P, version: S) -> Self {
+        ResolutionCache {
+            dir: dir.into(),
+            version: version.into(),
+        }
+    }
+
+    /// Resolves the semantic convention registry, reusing the cached telemetry
+    /// schema when the same registry has already been resolved with the same
+    /// options. See [`SchemaResolver::resolve_semantic_convention_registry`].
+    ///
+    /// A failure to write the cache entry is only logged.
+    pub fn resolve(
+        &self,
+        registry: &mut SemConvRegistry,
+        include_unreferenced: bool,
+    ) -> WResult<ResolvedTelemetrySchema, Error> {
+        let entry_path = self.entry_path(registry, include_unreferenced);
+        if let Some(schema) = self.read_entry(&entry_path) {
+            return WResult::Ok(schema);
+        }
+
+        let result =
+            SchemaResolver::resolve_semantic_convention_registry(registry, include_unreferenced);
+        let clean_schema = match &result {
+            WResult::Ok(schema) => Some(schema),
+            WResult::OkWithNFEs(schema, nfes) if nfes.is_empty() => Some(schema),
+            _ => None,
+        };
+        if let Some(schema) = clean_schema {
+            if let Err(e) = self.write_entry(&entry_path, schema) {
+                log::warn!(
+                    "Failed to write the resolution cache entry `{}`: {e}",
+                    entry_path.display()
+                );
+            }
+        }
+        result
+    }
+
+    /// Returns the path of the cache entry for the given registry and options.
+    fn entry_path(&self, registry: &SemConvRegistry, include_unreferenced: bool) -> PathBuf {
+        let suffix = if include_unreferenced {
+            "-unreferenced"
+        } else {
+            ""
+        };
+        self.dir
+            .join(format!("{}{suffix}.json", registry.content_hash()))
+    }
+
+    /// Reads a cache entry, returning `None` if the entry doesn't exist, can't
+    /// be read or has been written with another version stamp.
+    fn read_entry(&self, entry_path: &Path) -> Option<ResolvedTelemetrySchema> {
+        let content = fs::read_to_string(entry_path).ok()?;
+        let entry: ResolutionCacheEntry = serde_json::from_str(&content).ok()?;
+        (entry.version == self.version).then_some(entry.schema)
+    }
+
+    /// Writes a cache entry.
+    fn write_entry(
+        &self,
+        entry_path: &Path,
+        schema: &ResolvedTelemetrySchema,
+    ) -> std::io::Result<()> {
+        #[derive(Serialize)]
+        struct ResolutionCacheEntryRef<'a> {
+            version: &'a str,
+            schema: &'a ResolvedTelemetrySchema,
+        }
+
+        fs::create_dir_all(&self.dir)?;
+        let content = serde_json::to_string(&ResolutionCacheEntryRef {
+            version: &self.version,
+            schema,
+        })?;
+        fs::write(entry_path, content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use weaver_semconv::group::GroupType;
+    use weaver_semconv::provenance::Provenance;
+
+    use super::*;
+
+    #[test]
+    fn test_cached_registry() {
+        let registry = SemConvRegistry::try_from_path_pattern(
+            "local",
+            "data/registry-test-7-spans/registry/*.yaml",
+        )
+        .into_result_failing_non_fatal()
+        .expect("Failed to load the registry");
+        let mut cached_registry = CachedRegistry::new(registry, false);
+        assert_eq!(cached_registry.resolution_count(), 0);
+
+        let span_count = cached_registry
+            .resolved()
+            .expect("Failed to resolve the registry")
+            .registry
+            .groups(GroupType::Span)
+            .count();
+        assert_eq!(cached_registry.resolution_count(), 1);
+
+        // The second call reuses the cached resolution.
+        _ = cached_registry
+            .resolved()
+            .expect("Failed to resolve the registry");
+        assert_eq!(cached_registry.resolution_count(), 1);
+
+        // A mutation invalidates the cached resolution.
+        cached_registry
+            .registry_mut()
+            .add_semconv_spec_from_string(
+                Provenance::new("local", "extra.yaml"),
+                r#"
+groups:
+  - id: span.extra
+    type: span
+    brief: An extra span.
+    stability: development
+    span_kind: internal
+    attributes:
+      - id: extra.name
+        type: string
+        brief: The extra name.
+        stability: development
+        examples: [foo]
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        let resolved = cached_registry
+            .resolved()
+            .expect("Failed to resolve the registry");
+        assert_eq!(
+            resolved.registry.groups(GroupType::Span).count(),
+            span_count + 1
+        );
+        assert_eq!(cached_registry.resolution_count(), 2);
+
+        // Explicit invalidation.
+        cached_registry.invalidate();
+        _ = cached_registry
+            .resolved()
+            .expect("Failed to resolve the registry");
+        assert_eq!(cached_registry.resolution_count(), 3);
+    }
+
+    #[test]
+    fn test_resolution_cache() {
+        let load = || {
+            let mut registry = SemConvRegistry::new("local");
+            registry
+                .add_semconv_spec_from_string(
+                    Provenance::new("local", "registry.yaml"),
+                    r#"
+groups:
+  - id: span.extra
+    type: span
+    brief: An extra span.
+    stability: stable
+    span_kind: internal
+    attributes:
+      - id: extra.name
+        type: string
+        brief: The extra name.
+        stability: stable
+        examples: [foo]
+"#,
+                )
+                .into_result_failing_non_fatal()
+                .expect("Failed to add the spec");
+            registry
+        };
+        let dir = tempfile::tempdir().expect("Failed to create a temp dir");
+        let cache = ResolutionCache::new(dir.path());
+
+        let resolved = cache
+            .resolve(&mut load(), false)
+            .into_result_failing_non_fatal()
+            .expect("Failed to resolve the registry");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        // The unchanged registry is read from the cache.
+        let cached = cache
+            .resolve(&mut load(), false)
+            .into_result_failing_non_fatal()
+            .expect("Failed to resolve the registry");
+        assert_eq!(
+            serde_json::to_value(&cached).unwrap(),
+            serde_json::to_value(&resolved).unwrap()
+        );
+
+        // Other options or another version stamp resolve the registry again.
+        _ = cache
+            .resolve(&mut load(), true)
+            .into_result_failing_non_fatal()
+            .expect("Failed to resolve the registry");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 2);
+        let entry_path = cache.entry_path(&load(), false);
+        assert!(ResolutionCache::with_version(dir.path(), "other")
+            .read_entry(&entry_path)
+            .is_none());
+    }
+}
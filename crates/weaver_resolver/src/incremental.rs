@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Incremental validation of a registry, re-resolving only the groups affected
+//! by a change (e.g. on every keystroke of an editor integration).
+
+use crate::registry::{resolve_registry, ResolvedRegistry};
+use crate::Error;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use weaver_semconv::attribute::AttributeSpec;
+use weaver_semconv::attribute_catalog::build_attribute_catalog;
+use weaver_semconv::group::GroupSpecWithProvenance;
+
+/// The dependencies between the groups of a registry, i.e. the groups each
+/// group `extends`, includes or references an attribute of.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroupDependencies {
+    /// The groups each group depends on.
+    dependencies: BTreeMap<String, BTreeSet<String>>,
+    /// The groups depending on each group.
+    dependents: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl GroupDependencies {
+    /// Builds the dependencies of the given groups. A reference to an attribute
+    /// makes the group depend on all the groups defining the attribute.
+    #[must_use]
+    pub fn from_groups(groups: &[GroupSpecWithProvenance]) -> Self {
+        let catalog = build_attribute_catalog(groups);
+        let mut dependencies = GroupDependencies::default();
+        for group in groups {
+            let group_id = &group.spec.id;
+            _ = dependencies
+                .dependencies
+                .entry(group_id.clone())
+                .or_default();
+            let inherited = group.spec.extends.iter().chain(&group.spec.include_groups);
+            let referenced = group
+                .spec
+                .attributes
+                .iter()
+                .filter(|attribute| matches!(attribute, AttributeSpec::Ref { .. }))
+                .flat_map(|attribute| catalog.definitions(&attribute.id()))
+                .map(|usage| &usage.group_id);
+            for dependency in inherited.chain(referenced) {
+                if dependency != group_id {
+                    dependencies.add(group_id, dependency);
+                }
+            }
+        }
+        dependencies
+    }
+
+    /// Returns the given groups along with all the groups depending on them,
+    /// directly or transitively.
+    #[must_use]
+    pub fn affected_groups(&self, group_ids: &[&str]) -> BTreeSet<String> {
+        closure(&self.dependents, group_ids.iter().copied())
+    }
+
+    /// Returns the given groups along with all the groups they depend on,
+    /// directly or transitively.
+    #[must_use]
+    pub fn required_groups(&self, group_ids: &BTreeSet<String>) -> BTreeSet<String> {
+        closure(&self.dependencies, group_ids.iter().map(String::as_str))
+    }
+
+    fn add(&mut self, group_id: &str, dependency: &str) {
+        _ = self
+            .dependencies
+            .entry(group_id.to_owned())
+            .or_default()
+            .insert(dependency.to_owned());
+        _ = self
+            .dependents
+            .entry(dependency.to_owned())
+            .or_default()
+            .insert(group_id.to_owned());
+    }
+}
+
+/// Returns the given ids along with all the ids reachable from them through the
+/// given edges.
+fn closure<'a>(
+    edges: &BTreeMap<String, BTreeSet<String>>,
+    start: impl Iterator<Item = &'a str>,
+) -> BTreeSet<String> {
+    let mut reached = BTreeSet::new();
+    let mut pending: Vec<String> = start.map(ToOwned::to_owned).collect();
+    while let Some(id) = pending.pop() {
+        if let Some(next) = edges.get(&id) {
+            pending.extend(next.iter().filter(|id| !reached.contains(*id)).cloned());
+        }
+        _ = reached.insert(id);
+    }
+    reached
+}
+
+/// The outcome of an incremental validation.
+#[derive(Debug)]
+pub struct IncrementalValidation {
+    /// The ids of the groups validated again, i.e. the changed groups and the
+    /// groups depending on them.
+    pub validated_group_ids: BTreeSet<String>,
+    /// The findings about the validated groups.
+    pub errors: Vec<Error>,
+}
+
+/// Validates again the groups affected by a change of the given groups.
+///
+/// The affected groups are the changed groups and the groups depending on
+/// them, before (according to `previous`) or after the change. They are
+/// resolved along with the groups they depend on, so the findings about them
+/// are the ones a full resolution would report. The findings about the other
+/// groups are not reported, the findings that can't be attributed to a group
+/// (e.g. an invalid wildcard) are always reported.
+///
+/// Note: the uniqueness checks (e.g. duplicate group ids) only consider the
+/// resolved groups.
+#[must_use]
+pub fn revalidate_registry(
+    previous: &ResolvedRegistry,
+    groups: &[GroupSpecWithProvenance],
+    changed_group_ids: &[&str],
+) -> IncrementalValidation {
+    let dependencies = GroupDependencies::from_groups(groups);
+    let mut validated_group_ids = dependencies.affected_groups(changed_group_ids);
+    validated_group_ids.extend(previous.dependencies.affected_groups(changed_group_ids));
+    let required_group_ids = dependencies.required_groups(&validated_group_ids);
+
+    let required_groups: Vec<_> = groups
+        .iter()
+        .filter(|group| required_group_ids.contains(&group.spec.id))
+        .cloned()
+        .collect();
+    let errors = match resolve_registry(&required_groups) {
+        Ok(_) => vec![],
+        Err(Error::CompoundError(errors)) => errors,
+        Err(error) => vec![error],
+    }
+    .into_iter()
+    .filter(|error| {
+        let group_ids = error.group_ids();
+        group_ids.is_empty()
+            || group_ids
+                .iter()
+                .any(|group_id| validated_group_ids.contains(*group_id))
+    })
+    .collect();
+
+    IncrementalValidation {
+        validated_group_ids,
+        errors,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use weaver_semconv::provenance::Provenance;
+    use weaver_semconv::registry::SemConvRegistry;
+
+    fn groups(yaml: &str) -> Vec<GroupSpecWithProvenance> {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(Provenance::new("test", "registry.yaml"), yaml)
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        registry.unresolved_group_with_provenance_iter().collect()
+    }
+
+    #[test]
+    fn test_revalidate_registry() {
+        let registry = |http_attribute: &str| {
+            format!(
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: {http_attribute}
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: http.request.method
+  - id: registry.db
+    type: attribute_group
+    brief: Database attributes.
+    attributes:
+      - id: db.system.name
+        type: string
+        brief: The database system.
+        stability: stable
+        examples: [postgresql]
+  - id: span.db.client
+    type: span
+    brief: A database client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: db.system.name
+"#
+            )
+        };
+        let previous = resolve_registry(&groups(&registry("http.request.method")))
+            .expect("The registry should resolve");
+        assert_eq!(
+            previous
+                .dependencies
+                .affected_groups(&["registry.http"])
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["registry.http", "span.http.client"]
+        );
+
+        // The attribute referenced by the span is renamed.
+        let changed = groups(&registry("http.method"));
+        let validation = revalidate_registry(&previous, &changed, &["registry.http"]);
+        assert_eq!(
+            validation
+                .validated_group_ids
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            vec!["registry.http", "span.http.client"]
+        );
+        assert_eq!(validation.errors.len(), 1);
+        assert!(matches!(
+            &validation.errors[0],
+            Error::UnresolvedAttributeRef { group_id, attribute_ref, .. }
+                if group_id == "span.http.client" && attribute_ref == "http.request.method"
+        ));
+
+        // A change of an unrelated group doesn't validate the HTTP groups.
+        let validation = revalidate_registry(&previous, &changed, &["span.db.client"]);
+        assert_eq!(
+            validation
+                .validated_group_ids
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+            vec!["span.db.client"]
+        );
+        assert!(validation.errors.is_empty());
+    }
+}
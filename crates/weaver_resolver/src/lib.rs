@@ -7,8 +7,8 @@ use std::collections::HashSet;
 use std::path::{PathBuf, MAIN_SEPARATOR};
 use weaver_common::log_error;
 
+use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
-use rayon::iter::{IntoParallelIterator, ParallelBridge};
 use serde::Serialize;
 use walkdir::DirEntry;
 
@@ -26,6 +26,8 @@ use weaver_semconv::registry_repo::{RegistryRepo, REGISTRY_MANIFEST};
 use weaver_semconv::semconv::SemConvSpecWithProvenance;
 
 pub mod attribute;
+pub mod cache;
+pub mod incremental;
 pub mod registry;
 
 /// Maximum allowed depth for registry dependency chains.
@@ -117,6 +119,66 @@ pub enum Error {
         provenance: Provenance,
     },
 
+    /// A cycle of `extends` (or `include_groups`) references between groups.
+    #[error("The group '{group_id}' inherits from itself through the following chain: {}\nProvenance: {provenance}", cycle.join(" → "))]
+    CircularExtends {
+        /// The id of the group the cycle was detected at.
+        group_id: String,
+        /// The ids of the groups of the cycle, starting and ending with `group_id`.
+        cycle: Vec<String>,
+        /// The provenance of the group (URL or path).
+        provenance: Provenance,
+    },
+
+    /// A metric group missing required fields after the resolution.
+    #[error("The metric group '{group_id}' is invalid after resolution. {error}\nProvenance: {provenance}")]
+    InvalidMetric {
+        /// The id of the metric group.
+        group_id: String,
+        /// The reason of the error.
+        error: String,
+        /// The provenance of the metric group (URL or path).
+        provenance: Provenance,
+    },
+
+    /// A conditionally required attribute whose condition references an
+    /// attribute that is not an attribute of the group.
+    #[error("The condition of the conditionally required attribute '{attribute_id}' in the group '{group_id}' references the attribute '{condition_attribute}', which is not an attribute of the group.\nProvenance: {provenance}")]
+    UnknownConditionAttribute {
+        /// The id of the group.
+        group_id: String,
+        /// The id of the conditionally required attribute.
+        attribute_id: String,
+        /// The attribute referenced by the condition.
+        condition_attribute: String,
+        /// The provenance of the group (URL or path).
+        provenance: Provenance,
+    },
+
+    /// A group inheriting from a group of an incompatible type.
+    #[error("The group '{group_id}' is invalid. {error}\nProvenance: {provenance}")]
+    InvalidGroup {
+        /// The id of the group.
+        group_id: String,
+        /// The reason of the error.
+        error: String,
+        /// The provenance of the group (URL or path).
+        provenance: Provenance,
+    },
+
+    /// An attribute invalid in the group it is inherited into.
+    #[error("The attribute '{attribute_id}' is invalid in the group '{group_id}'. {error}\nProvenance: {provenance}")]
+    InvalidAttribute {
+        /// The id of the group inheriting the attribute.
+        group_id: String,
+        /// The id of the attribute.
+        attribute_id: String,
+        /// The reason of the error.
+        error: String,
+        /// The provenance of the file defining the attribute (URL or path).
+        provenance: Provenance,
+    },
+
     /// An invalid Schema path.
     #[error("Invalid Schema path: {path}")]
     InvalidSchemaPath {
@@ -163,6 +225,26 @@ pub enum Error {
         attribute_id: String,
     },
 
+    /// A group renamed from a group id that is not defined in the baseline
+    /// registry.
+    #[error("The group '{group_id}' is renamed from '{renamed_from}', which is not a group of the baseline registry.")]
+    UnknownRenamedFromGroup {
+        /// The id of the group.
+        group_id: String,
+        /// The id of the group in the baseline registry.
+        renamed_from: String,
+    },
+
+    /// An attribute renamed from an attribute id that is not defined in the
+    /// baseline registry.
+    #[error("The attribute '{attribute_id}' is renamed from '{renamed_from}', which is not an attribute of the baseline registry.")]
+    UnknownRenamedFromAttribute {
+        /// The id of the attribute.
+        attribute_id: String,
+        /// The id of the attribute in the baseline registry.
+        renamed_from: String,
+    },
+
     /// Invalid import wildcard.
     #[error("Invalid import wildcard: {error:?}")]
     #[diagnostic(help(
@@ -208,6 +290,33 @@ impl From<Error> for DiagnosticMessages {
 }
 
 impl Error {
+    /// Returns the ids of the groups this error is about, empty when the error
+    /// is not about specific groups.
+    #[must_use]
+    pub fn group_ids(&self) -> Vec<&str> {
+        match self {
+            Error::UnresolvedAttributeRef { group_id, .. }
+            | Error::UnresolvedExtendsRef { group_id, .. }
+            | Error::UnresolvedIncludeRef { group_id, .. }
+            | Error::InvalidMetric { group_id, .. }
+            | Error::UnknownConditionAttribute { group_id, .. }
+            | Error::InvalidGroup { group_id, .. }
+            | Error::InvalidAttribute { group_id, .. }
+            | Error::DuplicateGroupId { group_id, .. }
+            | Error::UnknownRenamedFromGroup { group_id, .. } => vec![group_id.as_str()],
+            Error::DuplicateAttributeId { group_ids, .. } => {
+                group_ids.iter().map(String::as_str).collect()
+            }
+            Error::CircularExtends { cycle, .. } => {
+                let mut group_ids: Vec<_> = cycle.iter().map(String::as_str).collect();
+                _ = group_ids.pop();
+                group_ids
+            }
+            Error::CompoundError(errors) => errors.iter().flat_map(Error::group_ids).collect(),
+            _ => vec![],
+        }
+    }
+
     /// Logs one or multiple errors (if current error is a 1CompoundError`)
     /// using the given logger.
     pub fn log(&self) {
@@ -252,6 +361,39 @@ impl SchemaResolver {
         )
     }
 
+    /// Checks that the `renamed_from` ids of the groups and attributes of a
+    /// resolved schema exist in the resolved schema of the baseline registry.
+    pub fn check_renamed_from(
+        schema: &ResolvedTelemetrySchema,
+        baseline_schema: &ResolvedTelemetrySchema,
+    ) -> WResult<(), Error> {
+        let mut errors = vec![];
+        for group in &schema.registry.groups {
+            if let Some(renamed_from) = &group.renamed_from {
+                if baseline_schema.group(renamed_from).is_none() {
+                    errors.push(Error::UnknownRenamedFromGroup {
+                        group_id: group.id.clone(),
+                        renamed_from: renamed_from.clone(),
+                    });
+                }
+            }
+        }
+        let baseline_attributes = baseline_schema.registry_attribute_map();
+        let mut attributes: Vec<_> = schema.registry_attribute_map().into_values().collect();
+        attributes.sort_by(|a, b| a.name.cmp(&b.name));
+        for attribute in attributes {
+            if let Some(renamed_from) = &attribute.renamed_from {
+                if !baseline_attributes.contains_key(renamed_from.as_str()) {
+                    errors.push(Error::UnknownRenamedFromAttribute {
+                        attribute_id: attribute.name.clone(),
+                        renamed_from: renamed_from.clone(),
+                    });
+                }
+            }
+        }
+        WResult::with_non_fatal_errors((), errors)
+    }
+
     /// Loads the semantic convention specifications from the given registry path.
     /// Implementation note: semconv files are read and parsed in parallel and
     /// all errors are collected and returned as a compound error.
@@ -327,12 +469,16 @@ impl SchemaResolver {
 
         // Loads the semantic convention specifications from the git repo.
         // All yaml files are recursively loaded and parsed in parallel from
-        // the given path.
+        // the given path. The directory is walked in file name order and the
+        // results are collected in that order, so the order of the specs (and
+        // of the resolved groups) is deterministic.
         let result = walkdir::WalkDir::new(local_path.clone())
             .follow_links(follow_symlinks)
+            .sort_by_file_name()
             .into_iter()
             .filter_entry(|e| !is_hidden(e))
-            .par_bridge()
+            .collect::<Vec<_>>()
+            .into_par_iter()
             .flat_map(|entry| {
                 match entry {
                     Ok(entry) => {
@@ -480,7 +626,7 @@ impl SchemaResolver {
 
 #[cfg(test)]
 mod tests {
-    use crate::SchemaResolver;
+    use crate::{Error, SchemaResolver};
     use std::collections::HashSet;
     use weaver_common::result::WResult;
     use weaver_common::vdir::VirtualDirectoryPath;
@@ -870,4 +1016,85 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_check_renamed_from() {
+        fn resolve(spec: &str) -> weaver_resolved_schema::ResolvedTelemetrySchema {
+            let mut registry = SemConvRegistry::new("test");
+            registry
+                .add_semconv_spec_from_string(Provenance::new("test", "registry.yaml"), spec)
+                .into_result_failing_non_fatal()
+                .expect("Failed to add the spec");
+            SchemaResolver::resolve_semantic_convention_registry(&mut registry, false)
+                .into_result_failing_non_fatal()
+                .expect("Failed to resolve the registry")
+        }
+
+        let baseline = resolve(
+            r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+"#,
+        );
+        let latest = resolve(
+            r#"
+groups:
+  - id: registry.http.attributes
+    renamed_from: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        renamed_from: http.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+  - id: registry.url
+    renamed_from: registry.uri
+    type: attribute_group
+    brief: URL attributes.
+    attributes:
+      - id: url.full
+        renamed_from: http.url
+        type: string
+        brief: The URL.
+        stability: stable
+        examples: ["https://example.com"]
+"#,
+        );
+        let group = latest.group("registry.http.attributes").unwrap();
+        assert_eq!(group.renamed_from.as_deref(), Some("registry.http"));
+        assert_eq!(
+            latest.registry_attribute_map()["http.request.method"]
+                .renamed_from
+                .as_deref(),
+            Some("http.method")
+        );
+
+        let WResult::OkWithNFEs((), errors) =
+            SchemaResolver::check_renamed_from(&latest, &baseline)
+        else {
+            panic!("The unknown `renamed_from` ids should be reported");
+        };
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            &errors[0],
+            Error::UnknownRenamedFromGroup { group_id, renamed_from }
+                if group_id == "registry.url" && renamed_from == "registry.uri"
+        ));
+        assert!(matches!(
+            &errors[1],
+            Error::UnknownRenamedFromAttribute { attribute_id, renamed_from }
+                if attribute_id == "url.full" && renamed_from == "http.url"
+        ));
+    }
 }
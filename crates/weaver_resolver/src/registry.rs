@@ -3,19 +3,22 @@
 //! Functions to resolve a semantic convention registry.
 
 use crate::attribute::AttributeCatalog;
+use crate::incremental::GroupDependencies;
 use crate::Error;
 use crate::Error::{DuplicateGroupId, DuplicateGroupName, DuplicateMetricName};
 use globset::GlobSet;
 use itertools::Itertools;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Display;
 use std::hash::Hash;
+use weaver_common::error::WeaverError;
 use weaver_common::result::WResult;
-use weaver_resolved_schema::attribute::UnresolvedAttribute;
+use weaver_resolved_schema::attribute::{Attribute, UnresolvedAttribute};
+use weaver_resolved_schema::catalog::Catalog;
 use weaver_resolved_schema::lineage::{AttributeLineage, GroupLineage};
 use weaver_resolved_schema::registry::{Group, Registry};
-use weaver_semconv::attribute::AttributeSpec;
+use weaver_semconv::attribute::{effective_attribute_name, AttributeSpec};
 use weaver_semconv::group::{
     GroupSpecWithProvenance, GroupType, GroupWildcard, ImportsWithProvenance,
 };
@@ -86,16 +89,76 @@ pub fn resolve_semconv_registry(
     registry: &SemConvRegistry,
     include_unreferenced: bool,
 ) -> WResult<Registry, Error> {
-    let mut ureg = unresolved_registry_from_specs(registry_url, registry);
+    let ureg = unresolved_registry_from_specs(registry_url, registry);
+    resolve_unresolved_registry(
+        attr_catalog,
+        ureg,
+        registry.manifest(),
+        include_unreferenced,
+    )
+}
+
+/// The groups of a registry in resolved form along with the catalog of the
+/// attributes they reference.
+#[derive(Serialize, Debug)]
+pub struct ResolvedRegistry {
+    /// The resolved registry.
+    pub registry: Registry,
+    /// The catalog of the attributes referenced by the resolved groups.
+    pub catalog: Catalog,
+    /// The dependencies between the resolved groups.
+    pub dependencies: GroupDependencies,
+}
 
+/// Resolves the given groups without any registry context (dry run) and
+/// returns them in resolved form, i.e. with the `extends`, `include_groups`
+/// and attribute references inlined.
+///
+/// All the resolution errors are aggregated into a single
+/// `Error::CompoundError`.
+pub fn resolve_registry(groups: &[GroupSpecWithProvenance]) -> Result<ResolvedRegistry, Error> {
+    let mut attr_catalog = AttributeCatalog::default();
+    let ureg = UnresolvedRegistry {
+        registry: Registry::new(""),
+        groups: groups.iter().cloned().map(group_from_spec).collect(),
+        imports: vec![],
+    };
+    match resolve_unresolved_registry(&mut attr_catalog, ureg, None, true)
+        .into_result_with_non_fatal()
+    {
+        Ok((registry, errors)) if errors.is_empty() => Ok(ResolvedRegistry {
+            registry,
+            catalog: Catalog::from_attributes(attr_catalog.drain_attributes()),
+            dependencies: GroupDependencies::from_groups(groups),
+        }),
+        Ok((_, errors)) => Err(Error::compound(errors)),
+        Err(e) => Err(Error::compound(vec![e])),
+    }
+}
+
+fn resolve_unresolved_registry(
+    attr_catalog: &mut AttributeCatalog,
+    mut ureg: UnresolvedRegistry,
+    manifest: Option<&RegistryManifest>,
+    include_unreferenced: bool,
+) -> WResult<Registry, Error> {
     if let Err(e) = resolve_prefix_on_attributes(&mut ureg) {
         return WResult::FatalErr(e);
     }
 
+    let mut errors = vec![];
+    check_inheritance_types(&ureg.groups, &mut errors);
+
+    if let Err(e) = check_circular_extends(&ureg.groups) {
+        return WResult::FatalErr(e);
+    }
+
     if let Err(e) = resolve_extends_references(&mut ureg) {
         return WResult::FatalErr(e);
     }
 
+    check_inherited_attributes(&ureg.groups, &mut errors);
+
     if let Err(e) = resolve_attribute_references(&mut ureg, attr_catalog) {
         return WResult::FatalErr(e);
     }
@@ -113,8 +176,6 @@ pub fn resolve_semconv_registry(
         })
         .collect();
 
-    let mut errors = vec![];
-
     let attr_name_index = attr_catalog.attribute_name_index();
 
     // Other complementary checks.
@@ -149,14 +210,13 @@ pub fn resolve_semconv_registry(
         },
     );
     check_root_attribute_id_duplicates(&ureg.registry, &attr_name_index, &mut errors);
+    check_metric_required_fields(&ureg.registry, &mut errors);
+    check_requirement_conditions(&ureg.registry, &attr_catalog.attribute_index(), &mut errors);
 
     if !include_unreferenced {
-        if let Err(e) = gc_unreferenced_objects(
-            registry.manifest(),
-            &mut ureg.registry,
-            &ureg.imports,
-            attr_catalog,
-        ) {
+        if let Err(e) =
+            gc_unreferenced_objects(manifest, &mut ureg.registry, &ureg.imports, attr_catalog)
+        {
             return WResult::FatalErr(e);
         }
     }
@@ -361,6 +421,263 @@ pub fn check_root_attribute_id_duplicates(
     errors.extend(local_errors);
 }
 
+/// Checks that every metric group defines its required fields (`metric_name`,
+/// `instrument` and `unit`) once the `extends` references are resolved.
+///
+/// Only the attributes are inherited from an extended group, so a metric group
+/// relying on its parent to supply these fields is reported with an
+/// [`Error::InvalidMetric`].
+pub fn check_metric_required_fields(registry: &Registry, errors: &mut Vec<Error>) {
+    for group in registry
+        .groups
+        .iter()
+        .filter(|group| group.r#type == GroupType::Metric)
+    {
+        let missing_fields: Vec<_> = [
+            ("metric_name", group.metric_name.is_none()),
+            ("instrument", group.instrument.is_none()),
+            ("unit", group.unit.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(field, missing)| missing.then_some(format!("`{field}`")))
+        .collect();
+        if missing_fields.is_empty() {
+            continue;
+        }
+
+        let mut error = format!("Missing required fields: {}.", missing_fields.join(", "));
+        if let Some(extends_group) = group
+            .lineage
+            .as_ref()
+            .and_then(|lineage| lineage.extends_group.as_ref())
+        {
+            error.push_str(&format!(
+                " The extended group `{extends_group}` doesn't supply them, only the attributes are inherited."
+            ));
+        }
+        errors.push(Error::InvalidMetric {
+            group_id: group.id.clone(),
+            error,
+            provenance: group.provenance(),
+        });
+    }
+}
+
+/// Checks that the machine-readable conditions of the conditionally required
+/// attributes only reference attributes of their group (including the
+/// inherited ones), see [`Error::UnknownConditionAttribute`].
+pub fn check_requirement_conditions(
+    registry: &Registry,
+    attr_index: &[&Attribute],
+    errors: &mut Vec<Error>,
+) {
+    for group in &registry.groups {
+        let attributes: Vec<_> = group
+            .attributes
+            .iter()
+            .map(|attr_ref| attr_index[attr_ref.0 as usize])
+            .collect();
+        let names: HashSet<_> = attributes.iter().map(|attr| attr.name.as_str()).collect();
+        for attribute in &attributes {
+            let Some(condition) = attribute.requirement_level.condition() else {
+                continue;
+            };
+            for condition_attribute in condition.attributes() {
+                if !names.contains(condition_attribute) {
+                    errors.push(Error::UnknownConditionAttribute {
+                        group_id: group.id.clone(),
+                        attribute_id: attribute.name.clone(),
+                        condition_attribute: condition_attribute.to_owned(),
+                        provenance: group.provenance(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if a group of type `group_type` can inherit (through `extends`
+/// or `include_groups`) from a group of type `parent_type`.
+///
+/// The compatibility matrix is:
+/// - an `attribute_group` can be inherited by any group,
+/// - a `metric_group` can be inherited by a `metric` or a `metric_group`,
+/// - any other type can only be inherited by a group of the same type,
+/// - a group without type is compatible with all the types.
+#[must_use]
+pub fn is_inheritance_compatible(group_type: &GroupType, parent_type: &GroupType) -> bool {
+    match (group_type, parent_type) {
+        (_, GroupType::AttributeGroup)
+        | (GroupType::Undefined, _)
+        | (_, GroupType::Undefined)
+        | (GroupType::Metric, GroupType::MetricGroup) => true,
+        _ => group_type == parent_type,
+    }
+}
+
+/// Checks that each group only inherits (through `extends` or
+/// `include_groups`) from groups of a compatible type, see
+/// [`is_inheritance_compatible`].
+///
+/// The unknown parent groups are ignored, they are reported during the
+/// resolution of the `extends` references.
+///
+/// Note: this function must be called before the `extends` references are
+/// resolved.
+pub fn check_inheritance_types(groups: &[UnresolvedGroup], errors: &mut Vec<Error>) {
+    let types_by_id: HashMap<&str, &GroupType> = groups
+        .iter()
+        .map(|group| (group.group.id.as_str(), &group.group.r#type))
+        .collect();
+    for group in groups {
+        let group_type = &group.group.r#type;
+        let parent_ids = group
+            .group
+            .extends
+            .iter()
+            .map(|id| ("extends", id))
+            .chain(group.include_groups.iter().map(|id| ("includes", id)));
+        for (relation, parent_id) in parent_ids {
+            let Some(parent_type) = types_by_id.get(parent_id.as_str()) else {
+                continue;
+            };
+            if !is_inheritance_compatible(group_type, parent_type) {
+                errors.push(Error::InvalidGroup {
+                    group_id: group.group.id.clone(),
+                    error: format!(
+                        "This group of type `{group_type}` {relation} the group `{parent_id}` of the incompatible type `{parent_type}`."
+                    ),
+                    provenance: group.provenance.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Checks that no group inherits (through `extends` or `include_groups`,
+/// directly or transitively) from itself, reporting every cycle with the full
+/// chain of groups.
+///
+/// The unknown parent groups are ignored, they are reported during the
+/// resolution of the `extends` references.
+///
+/// Note: this function must be called before the `extends` references are
+/// resolved, as a cycle would otherwise only surface as unresolved references.
+fn check_circular_extends(groups: &[UnresolvedGroup]) -> Result<(), Error> {
+    /// Visits the parents of a group depth-first, `visited` maps the ids of
+    /// the visited groups to `true` once all their ancestors are visited.
+    fn visit<'a>(
+        group_id: &'a str,
+        groups_by_id: &HashMap<&'a str, &'a UnresolvedGroup>,
+        visited: &mut HashMap<&'a str, bool>,
+        chain: &mut Vec<&'a str>,
+        errors: &mut Vec<Error>,
+    ) {
+        let Some(group) = groups_by_id.get(group_id) else {
+            return;
+        };
+        match visited.get(group_id) {
+            Some(true) => return,
+            Some(false) => {
+                let start = chain.iter().position(|id| *id == group_id).unwrap_or(0);
+                let mut cycle: Vec<String> =
+                    chain[start..].iter().map(|id| (*id).to_owned()).collect();
+                cycle.push(group_id.to_owned());
+                errors.push(Error::CircularExtends {
+                    group_id: group_id.to_owned(),
+                    cycle,
+                    provenance: group.provenance.clone(),
+                });
+                return;
+            }
+            None => {}
+        }
+        _ = visited.insert(group_id, false);
+        chain.push(group_id);
+        for parent_id in group.group.extends.iter().chain(&group.include_groups) {
+            visit(parent_id, groups_by_id, visited, chain, errors);
+        }
+        _ = chain.pop();
+        _ = visited.insert(group_id, true);
+    }
+
+    let groups_by_id: HashMap<&str, &UnresolvedGroup> = groups
+        .iter()
+        .map(|group| (group.group.id.as_str(), group))
+        .collect();
+    let mut visited = HashMap::new();
+    let mut errors = vec![];
+    for group in groups {
+        visit(
+            &group.group.id,
+            &groups_by_id,
+            &mut visited,
+            &mut vec![],
+            &mut errors,
+        );
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::compound(errors))
+    }
+}
+
+/// Checks that the attributes inherited through `extends` or `include_groups`
+/// are valid in the type of the inheriting group.
+///
+/// The errors point at the provenance of the file defining the attribute, not
+/// at the inheriting group. The local attributes are not checked, they are
+/// already validated with their group.
+///
+/// Note: this function must be called once the `extends` references are
+/// resolved and before the attribute references are resolved.
+pub fn check_inherited_attributes(groups: &[UnresolvedGroup], errors: &mut Vec<Error>) {
+    for group in groups {
+        let Some(lineage) = group.group.lineage.as_ref() else {
+            continue;
+        };
+        let group_type = &group.group.r#type;
+        for attr in &group.attributes {
+            let attribute_id = attr.spec.id();
+            if !lineage.has_attribute(&attribute_id) {
+                continue;
+            }
+            let (sampling_relevant, scope) = match &attr.spec {
+                AttributeSpec::Ref {
+                    sampling_relevant,
+                    scope,
+                    ..
+                }
+                | AttributeSpec::Id {
+                    sampling_relevant,
+                    scope,
+                    ..
+                } => (sampling_relevant, scope),
+            };
+            let mut fields = vec![];
+            if sampling_relevant.is_some()
+                && !matches!(group_type, GroupType::Span | GroupType::AttributeGroup)
+            {
+                fields.push("sampling_relevant");
+            }
+            if scope.is_some() && !matches!(group_type, GroupType::Span | GroupType::Undefined) {
+                fields.push("scope");
+            }
+            for field in fields {
+                errors.push(Error::InvalidAttribute {
+                    group_id: group.group.id.clone(),
+                    attribute_id: attribute_id.clone(),
+                    error: format!(
+                        "This inherited attribute contains a {field} field but the group type is not set to span."
+                    ),
+                    provenance: attr.provenance.clone(),
+                });
+            }
+        }
+    }
+}
+
 /// Creates a semantic convention registry from a set of semantic convention
 /// specifications.
 ///
@@ -402,12 +719,16 @@ fn group_from_spec(group: GroupSpecWithProvenance) -> UnresolvedGroup {
         .spec
         .attributes
         .into_iter()
-        .map(|attr| UnresolvedAttribute { spec: attr })
+        .map(|attr| UnresolvedAttribute {
+            spec: attr,
+            provenance: group.provenance.clone(),
+        })
         .collect::<Vec<UnresolvedAttribute>>();
 
     UnresolvedGroup {
         group: Group {
             id: group.spec.id,
+            renamed_from: group.spec.renamed_from,
             r#type: group.spec.r#type,
             brief: group.spec.brief,
             note: group.spec.note,
@@ -445,7 +766,7 @@ fn resolve_prefix_on_attributes(ureg: &mut UnresolvedRegistry) -> Result<(), Err
         if !unresolved_group.group.prefix.is_empty() {
             for attribute in unresolved_group.attributes.iter_mut() {
                 if let AttributeSpec::Id { id, .. } = &mut attribute.spec {
-                    *id = format!("{}.{}", unresolved_group.group.prefix, id);
+                    *id = effective_attribute_name(&unresolved_group.group.prefix, id);
                 }
             }
         }
@@ -691,6 +1012,7 @@ fn resolve_inheritance_attrs_unified(
     struct AttrWithLineage {
         spec: AttributeSpec,
         lineage: AttributeLineage,
+        provenance: Provenance,
     }
 
     // A map attribute_id -> attribute_spec + lineage.
@@ -716,6 +1038,7 @@ fn resolve_inheritance_attrs_unified(
                 AttrWithLineage {
                     spec: parent_attr.spec.clone(),
                     lineage,
+                    provenance: parent_attr.provenance.clone(),
                 },
             );
         }
@@ -728,15 +1051,19 @@ fn resolve_inheritance_attrs_unified(
                 if let Some(AttrWithLineage {
                     spec: parent_attr,
                     lineage,
+                    provenance,
                 }) = inherited_attrs.get_mut(r#ref)
                 {
                     *parent_attr = resolve_inheritance_attr(&attr.spec, parent_attr, lineage);
+                    // The overridden attribute is now owned by the group.
+                    provenance.clone_from(&attr.provenance);
                 } else {
                     _ = inherited_attrs.insert(
                         r#ref.clone(),
                         AttrWithLineage {
                             spec: attr.spec.clone(),
                             lineage: AttributeLineage::new(group_id),
+                            provenance: attr.provenance.clone(),
                         },
                     );
                 }
@@ -747,6 +1074,7 @@ fn resolve_inheritance_attrs_unified(
                     AttrWithLineage {
                         spec: attr.spec.clone(),
                         lineage: AttributeLineage::new(group_id),
+                        provenance: attr.provenance.clone(),
                     },
                 );
             }
@@ -765,6 +1093,7 @@ fn resolve_inheritance_attrs_unified(
                 }
                 UnresolvedAttribute {
                     spec: attr_with_lineage.spec,
+                    provenance: attr_with_lineage.provenance,
                 }
             })
             .collect()
@@ -772,6 +1101,7 @@ fn resolve_inheritance_attrs_unified(
         inherited_attrs
             .map(|attr_with_lineage| UnresolvedAttribute {
                 spec: attr_with_lineage.spec,
+                provenance: attr_with_lineage.provenance,
             })
             .collect()
     }
@@ -791,12 +1121,22 @@ fn resolve_inheritance_attr(
             requirement_level,
             sampling_relevant,
             note,
+            examples_note,
             stability,
             deprecated,
             prefix,
             annotations,
             role,
+            scope,
         } => {
+            // The examples of the note follow the note they illustrate.
+            let inherited_examples_note = |parent_examples_note: &Option<String>| {
+                if note.is_some() {
+                    examples_note.clone()
+                } else {
+                    parent_examples_note.clone()
+                }
+            };
             match parent_attr {
                 AttributeSpec::Ref {
                     brief: parent_brief,
@@ -805,11 +1145,13 @@ fn resolve_inheritance_attr(
                     requirement_level: parent_requirement_level,
                     sampling_relevant: parent_sampling_relevant,
                     note: parent_note,
+                    examples_note: parent_examples_note,
                     stability: parent_stability,
                     deprecated: parent_deprecated,
                     prefix: parent_prefix,
                     annotations: parent_annotations,
                     role: parent_role,
+                    scope: parent_scope,
                     ..
                 } => {
                     // attr and attr_parent are both references.
@@ -825,11 +1167,13 @@ fn resolve_inheritance_attr(
                         sampling_relevant: lineage
                             .sampling_relevant(sampling_relevant, parent_sampling_relevant),
                         note: lineage.optional_note(note, parent_note),
+                        examples_note: inherited_examples_note(parent_examples_note),
                         stability: lineage.stability(stability, parent_stability),
                         deprecated: lineage.deprecated(deprecated, parent_deprecated),
                         prefix: lineage.prefix(prefix, parent_prefix),
                         annotations: lineage.annotations(annotations, parent_annotations),
                         role: lineage.optional_role(role, parent_role),
+                        scope: scope.clone().or_else(|| parent_scope.clone()),
                     }
                 }
                 AttributeSpec::Id {
@@ -840,16 +1184,19 @@ fn resolve_inheritance_attr(
                     requirement_level: parent_requirement_level,
                     sampling_relevant: parent_sampling_relevant,
                     note: parent_note,
+                    examples_note: parent_examples_note,
                     stability: parent_stability,
                     deprecated: parent_deprecated,
                     annotations: parent_annotations,
                     role: parent_role,
+                    scope: parent_scope,
                     ..
                 } => {
                     // attr is a reference and attr_parent is an id.
                     // We need to override the reference with the id.
                     AttributeSpec::Id {
                         id: r#ref.clone(),
+                        renamed_from: None,
                         r#type: parent_type.clone(),
                         brief: lineage.optional_brief(brief, parent_brief),
                         examples: lineage.examples(examples, parent_examples),
@@ -859,10 +1206,12 @@ fn resolve_inheritance_attr(
                         sampling_relevant: lineage
                             .sampling_relevant(sampling_relevant, parent_sampling_relevant),
                         note: lineage.note(note, parent_note),
+                        examples_note: inherited_examples_note(parent_examples_note),
                         stability: lineage.stability(stability, parent_stability),
                         deprecated: lineage.deprecated(deprecated, parent_deprecated),
                         annotations: lineage.annotations(annotations, parent_annotations),
                         role: lineage.optional_role(role, parent_role),
+                        scope: scope.clone().or_else(|| parent_scope.clone()),
                     }
                 }
             }
@@ -883,12 +1232,13 @@ mod tests {
     use weaver_diff::canonicalize_json_string;
     use weaver_resolved_schema::attribute;
     use weaver_resolved_schema::registry::Registry;
+    use weaver_semconv::attribute::{BasicRequirementLevelSpec, RequirementLevel};
     use weaver_semconv::group::GroupType;
     use weaver_semconv::provenance::Provenance;
     use weaver_semconv::registry::SemConvRegistry;
 
     use crate::attribute::AttributeCatalog;
-    use crate::registry::resolve_semconv_registry;
+    use crate::registry::{is_inheritance_compatible, resolve_registry, resolve_semconv_registry};
     use crate::SchemaResolver;
 
     /// Test the resolution of semantic convention registries stored in the
@@ -943,6 +1293,9 @@ mod tests {
                             weaver_semconv::Error::InvalidGroupUsesPrefix {
                                 path_or_url: _,
                                 group_id: _
+                            } | weaver_semconv::Error::InvalidGroupUnusedPrefix {
+                                path_or_url: _,
+                                group_id: _
                             }
                         )
                 })
@@ -1083,6 +1436,229 @@ groups:
         }
     }
 
+    #[test]
+    fn test_registry_error_circular_extends() {
+        let result = create_registry_from_string(
+            "
+groups:
+    - id: group.a
+      type: attribute_group
+      brief: \"Group A\"
+      extends: group.b
+    - id: group.b
+      type: attribute_group
+      brief: \"Group B\"
+      extends: group.c
+    - id: group.c
+      type: attribute_group
+      brief: \"Group C\"
+      include_groups: [group.a]
+      attributes:
+        - id: c.name
+          type: string
+          brief: \"The name\"
+          stability: stable
+          examples: [c]
+    - id: group.d
+      type: attribute_group
+      brief: \"Group D\"
+      extends: group.a
+    - id: group.e
+      type: attribute_group
+      brief: \"Group E\"
+      extends: group.e",
+        )
+        .into_result_failing_non_fatal();
+
+        let Err(crate::Error::CompoundError(errors)) = result else {
+            panic!("Expected a CompoundError");
+        };
+        // Each cycle is reported once, with the full chain of groups.
+        let cycles: Vec<_> = errors
+            .iter()
+            .map(|error| match error {
+                crate::Error::CircularExtends { cycle, .. } => cycle.join(" → "),
+                error => panic!("Unexpected error: {error:?}"),
+            })
+            .collect();
+        assert_eq!(
+            cycles,
+            vec!["group.a → group.b → group.c → group.a", "group.e → group.e"]
+        );
+        assert_eq!(errors[0].group_ids(), vec!["group.a", "group.b", "group.c"]);
+    }
+
+    #[test]
+    fn test_registry_error_metric_missing_unit() {
+        let mut sc_specs = SemConvRegistry::new("default");
+        // The spec validation reports the missing unit as a non-fatal error.
+        let nfes = sc_specs
+            .add_semconv_spec_from_string(
+                Provenance::new("main", "<str>"),
+                "
+groups:
+    - id: metric_attributes.http.server
+      type: attribute_group
+      brief: 'HTTP server attributes'
+      attributes:
+        - id: http.route
+          type: string
+          brief: 'The route'
+          stability: stable
+          examples: ['/users/:id']
+    - id: metric.http.server.active_requests
+      type: metric
+      metric_name: http.server.active_requests
+      instrument: updowncounter
+      stability: stable
+      brief: 'Active requests'
+      extends: metric_attributes.http.server",
+            )
+            .into_result_with_non_fatal()
+            .expect("Failed to load semconv spec")
+            .1;
+        assert!(!nfes.is_empty());
+
+        let mut attr_catalog = AttributeCatalog::default();
+        let result =
+            resolve_semconv_registry(&mut attr_catalog, "https://127.0.0.1", &sc_specs, false)
+                .into_result_failing_non_fatal();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            crate::Error::InvalidMetric {
+                group_id: "metric.http.server.active_requests".to_owned(),
+                error: "Missing required fields: `unit`. The extended group `metric_attributes.http.server` doesn't supply them, only the attributes are inherited.".to_owned(),
+                provenance: Provenance::new("main", "<str>"),
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_registry_error_unknown_condition_attribute() {
+        let mut sc_specs = SemConvRegistry::new("default");
+        sc_specs
+            .add_semconv_spec_from_string(
+                Provenance::new("main", "<str>"),
+                "
+groups:
+    - id: registry.http
+      type: attribute_group
+      brief: 'HTTP attributes'
+      attributes:
+        - id: http.response.status_code
+          type: int
+          brief: 'The status code'
+          stability: stable
+          examples: [200]
+        - id: error.type
+          type: string
+          brief: 'The error type'
+          stability: stable
+          examples: ['timeout']
+    - id: span.http.client
+      type: span
+      span_kind: client
+      stability: stable
+      brief: 'HTTP client span'
+      attributes:
+        - ref: http.response.status_code
+        - ref: error.type
+          requirement_level:
+            conditionally_required: If the status code is 5xx or the request failed.
+            condition: 'http.response.status_code >= 500 || network.transport'",
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to load semconv spec");
+
+        let mut attr_catalog = AttributeCatalog::default();
+        let result =
+            resolve_semconv_registry(&mut attr_catalog, "https://127.0.0.1", &sc_specs, false)
+                .into_result_with_non_fatal()
+                .expect("The resolution should succeed");
+        // Only `network.transport` is not an attribute of the span.
+        assert_eq!(result.1.len(), 1);
+        assert_eq!(
+            result.1[0].to_string(),
+            crate::Error::UnknownConditionAttribute {
+                group_id: "span.http.client".to_owned(),
+                attribute_id: "error.type".to_owned(),
+                condition_attribute: "network.transport".to_owned(),
+                provenance: Provenance::new("main", "<str>"),
+            }
+            .to_string()
+        );
+
+        // The parsed condition is exposed in the resolved attribute.
+        let attributes = attr_catalog.attribute_index();
+        let error_type = attributes
+            .iter()
+            .find(|attr| attr.name == "error.type" && attr.requirement_level.condition().is_some())
+            .expect("The conditionally required attribute should be resolved");
+        assert_eq!(
+            error_type
+                .requirement_level
+                .condition()
+                .unwrap()
+                .to_string(),
+            "http.response.status_code >= 500 || network.transport"
+        );
+    }
+
+    #[test]
+    fn test_registry_error_inherited_attribute_provenance() {
+        let mut sc_specs = SemConvRegistry::new("default");
+        sc_specs
+            .add_semconv_spec_from_string(
+                Provenance::new("main", "parent.yaml"),
+                "
+groups:
+    - id: attributes.http.server
+      type: attribute_group
+      brief: 'HTTP server attributes'
+      attributes:
+        - id: http.route
+          type: string
+          brief: 'The route'
+          stability: stable
+          examples: ['/users/:id']
+          sampling_relevant: true",
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to load semconv spec");
+        sc_specs
+            .add_semconv_spec_from_string(
+                Provenance::new("main", "child.yaml"),
+                "
+groups:
+    - id: metric.http.server.active_requests
+      type: metric
+      metric_name: http.server.active_requests
+      instrument: updowncounter
+      unit: '{request}'
+      stability: stable
+      brief: 'Active requests'
+      extends: attributes.http.server",
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to load semconv spec");
+
+        let mut attr_catalog = AttributeCatalog::default();
+        let result =
+            resolve_semconv_registry(&mut attr_catalog, "https://127.0.0.1", &sc_specs, false)
+                .into_result_failing_non_fatal();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            crate::Error::InvalidAttribute {
+                group_id: "metric.http.server.active_requests".to_owned(),
+                attribute_id: "http.route".to_owned(),
+                error: "This inherited attribute contains a sampling_relevant field but the group type is not set to span.".to_owned(),
+                provenance: Provenance::new("main", "parent.yaml"),
+            }
+            .to_string()
+        );
+    }
+
     #[test]
     fn test_registry_error_unresolved_refs() {
         let result = create_registry_from_string(
@@ -1158,6 +1734,275 @@ groups:
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_registry() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "http.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    extends: registry.http
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        let groups: Vec<_> = registry.unresolved_group_with_provenance_iter().collect();
+
+        let resolved = resolve_registry(&groups).expect("Failed to resolve the registry");
+        let span = resolved
+            .registry
+            .groups
+            .iter()
+            .find(|group| group.id == "span.http.client")
+            .expect("Span not found");
+        let attribute_names: Vec<_> = span
+            .attributes(&resolved.catalog)
+            .expect("Failed to get the attributes")
+            .into_iter()
+            .map(|attribute| attribute.name.as_str())
+            .collect();
+        assert_eq!(attribute_names, vec!["http.request.method"]);
+        assert!(serde_json::to_string(&resolved).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_registry_ref_overrides() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "http.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.route
+        type: string
+        brief: The route.
+        note: The matched route template.
+        stability: stable
+        examples: ['/users/:id']
+  - id: span.http.server
+    type: span
+    brief: An HTTP server span.
+    stability: stable
+    span_kind: server
+    attributes:
+      - ref: http.route
+        requirement_level: required
+        note: The route is always known on the server side.
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: http.route
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        let groups: Vec<_> = registry.unresolved_group_with_provenance_iter().collect();
+
+        let resolved = resolve_registry(&groups).expect("Failed to resolve the registry");
+        let route = |group_id: &str| {
+            let group = resolved
+                .registry
+                .groups
+                .iter()
+                .find(|group| group.id == group_id)
+                .expect("Group not found");
+            group
+                .attributes(&resolved.catalog)
+                .expect("Failed to get the attributes")
+                .into_iter()
+                .find(|attribute| attribute.name == "http.route")
+                .cloned()
+                .expect("Attribute not found")
+        };
+
+        let server_route = route("span.http.server");
+        assert_eq!(
+            server_route.requirement_level,
+            RequirementLevel::Basic(BasicRequirementLevelSpec::Required)
+        );
+        assert_eq!(
+            server_route.note,
+            "The route is always known on the server side."
+        );
+        assert_eq!(server_route.brief, "The route.");
+
+        // The other references keep the definition of the shared attribute.
+        let client_route = route("span.http.client");
+        assert_eq!(client_route.requirement_level, RequirementLevel::default());
+        assert_eq!(client_route.note, "The matched route template.");
+    }
+
+    #[test]
+    fn test_resolve_registry_examples_note() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "http.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.body
+        type: string
+        brief: The request body.
+        stability: development
+        note: The body is recorded as is.
+        examples_note: '{"user": "alice"}'
+        examples: ['{}']
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: development
+    span_kind: client
+    attributes:
+      - ref: http.request.body
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        let groups: Vec<_> = registry.unresolved_group_with_provenance_iter().collect();
+
+        let resolved = resolve_registry(&groups).expect("Failed to resolve the registry");
+        let span = resolved
+            .registry
+            .groups
+            .iter()
+            .find(|group| group.id == "span.http.client")
+            .expect("Span not found");
+        let attributes = span
+            .attributes(&resolved.catalog)
+            .expect("Failed to get the attributes");
+        assert_eq!(
+            attributes[0].note,
+            "The body is recorded as is.\n\n```\n{\"user\": \"alice\"}\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_resolve_registry_errors() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "http.yaml"),
+                r#"
+groups:
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: http.request.method
+      - ref: http.route
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        let groups: Vec<_> = registry.unresolved_group_with_provenance_iter().collect();
+
+        match resolve_registry(&groups) {
+            Err(crate::Error::CompoundError(errors)) => assert_eq!(errors.len(), 2),
+            other => panic!("Expected a CompoundError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_registry_inheritance_types() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "http.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+  - id: metric.http.client.duration
+    type: metric
+    brief: The HTTP client duration.
+    metric_name: http.client.duration
+    instrument: histogram
+    unit: s
+    stability: stable
+    attributes:
+      - ref: http.request.method
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    extends: registry.http
+  - id: span.http.server
+    type: span
+    brief: An HTTP server span.
+    stability: stable
+    span_kind: server
+    extends: metric.http.client.duration
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        let groups: Vec<_> = registry.unresolved_group_with_provenance_iter().collect();
+
+        match resolve_registry(&groups) {
+            Err(crate::Error::CompoundError(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert!(matches!(
+                    &errors[0],
+                    crate::Error::InvalidGroup { group_id, error, provenance }
+                        if group_id == "span.http.server"
+                            && error == "This group of type `span` extends the group `metric.http.client.duration` of the incompatible type `metric`."
+                            && *provenance == Provenance::new("test", "http.yaml")
+                ));
+            }
+            other => panic!("Expected a CompoundError, got {other:?}"),
+        }
+        assert!(resolve_registry(&groups[..3]).is_ok());
+
+        assert!(is_inheritance_compatible(
+            &GroupType::Span,
+            &GroupType::AttributeGroup
+        ));
+        assert!(is_inheritance_compatible(
+            &GroupType::Metric,
+            &GroupType::MetricGroup
+        ));
+        assert!(!is_inheritance_compatible(
+            &GroupType::AttributeGroup,
+            &GroupType::Span
+        ));
+    }
+
     fn to_json<T: Serialize + ?Sized>(value: &T) -> String {
         serde_json::to_string_pretty(value).unwrap()
     }
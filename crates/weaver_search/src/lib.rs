@@ -154,7 +154,7 @@ fn search_mode_with_total(
         .collect();
 
     // Sort by score descending
-    scored_items.sort_by(|a, b| b.0.cmp(&a.0));
+    scored_items.sort_by_key(|item| std::cmp::Reverse(item.0));
 
     // Calculate total before taking limit
     let total = scored_items.len();
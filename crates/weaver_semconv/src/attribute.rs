@@ -5,13 +5,15 @@
 //! Attribute specification.
 
 use crate::any_value::AnyValueSpec;
+use crate::condition::Condition;
 use crate::deprecated::Deprecated;
 use crate::stability::Stability;
 use crate::{Error, YamlValue};
 use ordered_float::OrderedFloat;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Display, Formatter};
 use std::ops::Not;
 use weaver_common::result::WResult;
@@ -63,6 +65,10 @@ pub enum AttributeSpec {
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         note: Option<String>,
+        /// Example payloads illustrating the note, rendered as a fenced code
+        /// block after the note. Requires a note.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        examples_note: Option<String>,
         /// Specifies the stability of the attribute.
         /// Note that, if stability is missing but deprecated is present, it will
         /// automatically set the stability to deprecated. If deprecated is
@@ -90,11 +96,23 @@ pub enum AttributeSpec {
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         role: Option<AttributeRole>,
+        /// Specifies whether the attribute is recorded on the span itself, on
+        /// its events or on its links. It defaults to the span.
+        /// Note: only valid if the group type is span.
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scope: Option<AttributeScope>,
     },
     /// Attribute definition.
     Id {
         /// String that uniquely identifies the attribute.
         id: String,
+        /// The id of the attribute in a previous version of the registry, if
+        /// the attribute has been renamed. The diff tooling reports a rename
+        /// instead of a removal and an addition.
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        renamed_from: Option<String>,
         /// Either a string literal denoting the type as a primitive or an
         /// array type, a template type or an enum definition.
         r#type: AttributeType,
@@ -128,6 +146,10 @@ pub enum AttributeSpec {
         /// It defaults to an empty string.
         #[serde(default)]
         note: String,
+        /// Example payloads illustrating the note, rendered as a fenced code
+        /// block after the note. Requires a note.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        examples_note: Option<String>,
         /// Specifies the stability of the attribute.
         /// Note that, if stability is missing but deprecated is present, it will
         /// automatically set the stability to deprecated. If deprecated is
@@ -148,6 +170,12 @@ pub enum AttributeSpec {
         #[serde(default)]
         #[serde(skip_serializing_if = "Option::is_none")]
         role: Option<AttributeRole>,
+        /// Specifies whether the attribute is recorded on the span itself, on
+        /// its events or on its links. It defaults to the span.
+        /// Note: only valid if the group type is span.
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scope: Option<AttributeScope>,
     },
 }
 
@@ -204,6 +232,228 @@ impl AttributeSpec {
             AttributeSpec::Id { tag, .. } => tag.clone(),
         }
     }
+
+    /// Returns the requirement level of the attribute, `recommended` when not
+    /// set.
+    ///
+    /// Note: a reference without a requirement level inherits the level of the
+    /// referenced attribute during the resolution, this method doesn't look it
+    /// up and returns the default.
+    #[must_use]
+    pub fn requirement_level(&self) -> RequirementLevel {
+        match self {
+            AttributeSpec::Ref {
+                requirement_level, ..
+            } => requirement_level.clone().unwrap_or_default(),
+            AttributeSpec::Id {
+                requirement_level, ..
+            } => requirement_level.clone(),
+        }
+    }
+
+    /// Returns true if the attribute is deprecated, i.e. it has a `deprecated`
+    /// field or a deprecated stability.
+    #[allow(deprecated)]
+    #[must_use]
+    pub fn is_deprecated(&self) -> bool {
+        match self {
+            AttributeSpec::Ref {
+                stability,
+                deprecated,
+                ..
+            }
+            | AttributeSpec::Id {
+                stability,
+                deprecated,
+                ..
+            } => deprecated.is_some() || *stability == Some(Stability::Deprecated),
+        }
+    }
+
+    /// Returns the deprecation of the attribute, if any.
+    #[must_use]
+    pub fn deprecated(&self) -> Option<&Deprecated> {
+        match self {
+            AttributeSpec::Ref { deprecated, .. } | AttributeSpec::Id { deprecated, .. } => {
+                deprecated.as_ref()
+            }
+        }
+    }
+
+    /// Returns the part of a span the attribute is recorded on.
+    #[must_use]
+    pub fn scope(&self) -> AttributeScope {
+        match self {
+            AttributeSpec::Ref { scope, .. } | AttributeSpec::Id { scope, .. } => {
+                scope.clone().unwrap_or_default()
+            }
+        }
+    }
+
+    /// Applies an overlay of this attribute (see
+    /// [`SemConvRegistry::apply_overlay`](crate::registry::SemConvRegistry::apply_overlay)):
+    /// the fields specified by the overlay reference override the fields of
+    /// this definition or reference.
+    ///
+    /// # Errors
+    ///
+    /// If the overlay is a definition (`id`), an existing attribute can only be
+    /// overridden by a reference (`ref`).
+    pub fn apply_overlay(&mut self, overlay: AttributeSpec) -> Result<(), String> {
+        fn override_with<T>(field: &mut Option<T>, value: Option<T>) {
+            if value.is_some() {
+                *field = value;
+            }
+        }
+
+        let attribute_id = overlay.id();
+        let AttributeSpec::Ref {
+            brief: overlay_brief,
+            examples: overlay_examples,
+            tag: overlay_tag,
+            requirement_level: overlay_requirement_level,
+            sampling_relevant: overlay_sampling_relevant,
+            note: overlay_note,
+            examples_note: overlay_examples_note,
+            stability: overlay_stability,
+            deprecated: overlay_deprecated,
+            annotations: overlay_annotations,
+            role: overlay_role,
+            scope: overlay_scope,
+            ..
+        } = overlay
+        else {
+            return Err(format!(
+                "The attribute `{attribute_id}` is already defined, an overlay can only override its fields with a `ref`."
+            ));
+        };
+
+        match self {
+            AttributeSpec::Ref {
+                brief,
+                examples,
+                tag,
+                requirement_level,
+                sampling_relevant,
+                note,
+                examples_note,
+                stability,
+                deprecated,
+                annotations,
+                role,
+                scope,
+                ..
+            } => {
+                override_with(requirement_level, overlay_requirement_level);
+                override_with(note, overlay_note);
+                override_with(brief, overlay_brief);
+                override_with(examples, overlay_examples);
+                override_with(tag, overlay_tag);
+                override_with(sampling_relevant, overlay_sampling_relevant);
+                override_with(examples_note, overlay_examples_note);
+                override_with(stability, overlay_stability);
+                override_with(deprecated, overlay_deprecated);
+                override_with(annotations, overlay_annotations);
+                override_with(role, overlay_role);
+                override_with(scope, overlay_scope);
+            }
+            AttributeSpec::Id {
+                brief,
+                examples,
+                tag,
+                requirement_level,
+                sampling_relevant,
+                note,
+                examples_note,
+                stability,
+                deprecated,
+                annotations,
+                role,
+                scope,
+                ..
+            } => {
+                if let Some(overlay_requirement_level) = overlay_requirement_level {
+                    *requirement_level = overlay_requirement_level;
+                }
+                if let Some(overlay_note) = overlay_note {
+                    *note = overlay_note;
+                }
+                override_with(brief, overlay_brief);
+                override_with(examples, overlay_examples);
+                override_with(tag, overlay_tag);
+                override_with(sampling_relevant, overlay_sampling_relevant);
+                override_with(examples_note, overlay_examples_note);
+                override_with(stability, overlay_stability);
+                override_with(deprecated, overlay_deprecated);
+                override_with(annotations, overlay_annotations);
+                override_with(role, overlay_role);
+                override_with(scope, overlay_scope);
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that a concrete value, e.g. recorded by an instrumentation or
+    /// used as test data, is valid for this attribute: the value must be of
+    /// the attribute type, each element of an array must be of the element
+    /// type, and the value of an enum must be the value of one of its members
+    /// unless the enum is open (`_OTHER` member).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AttributeValueError`] describing why the value is invalid,
+    /// or [`AttributeValueError::UnresolvedReference`] for a reference, whose
+    /// type is only known once the registry is resolved.
+    pub fn validate_value(&self, value: &Value) -> Result<(), AttributeValueError> {
+        match self {
+            AttributeSpec::Ref { r#ref, .. } => Err(AttributeValueError::UnresolvedReference {
+                attribute_ref: r#ref.clone(),
+            }),
+            AttributeSpec::Id { r#type, .. } => r#type.validate_value(value),
+        }
+    }
+}
+
+/// The reason why a value is not valid for an attribute.
+#[derive(thiserror::Error, Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AttributeValueError {
+    /// The value is not of the attribute type.
+    #[error("The value `{value}` is not of type `{expected}`.")]
+    InvalidType {
+        /// The value.
+        value: String,
+        /// The expected type.
+        expected: String,
+    },
+
+    /// An element of an array value is not of the element type.
+    #[error("The element #{index} `{value}` of the array is not of type `{expected}`.")]
+    InvalidArrayElement {
+        /// The index of the element, starting at 0.
+        index: usize,
+        /// The element.
+        value: String,
+        /// The expected type of the elements.
+        expected: String,
+    },
+
+    /// The value is not the value of any member of a closed enum.
+    #[error("The value `{value}` is not the value of any member of the enum, expected one of: {members}.")]
+    UnknownEnumMember {
+        /// The value.
+        value: String,
+        /// The values of the members, comma separated.
+        members: String,
+    },
+
+    /// The attribute is a reference, its type is unknown until the registry
+    /// is resolved.
+    #[error("The attribute `{attribute_ref}` is a reference, its type is unknown until the registry is resolved.")]
+    UnresolvedReference {
+        /// The id of the referenced attribute.
+        attribute_ref: String,
+    },
 }
 
 /// The different types of attributes (specification).
@@ -223,6 +473,53 @@ pub enum AttributeType {
     },
 }
 
+impl AttributeType {
+    /// Returns true if the type is an open enum, i.e. an enum with an `_OTHER`
+    /// member accepting custom values.
+    #[must_use]
+    pub fn is_open_enum(&self) -> bool {
+        matches!(self, Enum { members } if members.iter().any(|m| m.value.to_string() == "_OTHER"))
+    }
+
+    /// Checks that a concrete value is of this type. For a template type, the
+    /// value is the one of a single key of the template.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AttributeValueError`] describing why the value is invalid.
+    pub fn validate_value(&self, value: &Value) -> Result<(), AttributeValueError> {
+        match self {
+            PrimitiveOrArray(t) => t.validate_value(value, self),
+            Template(t) => t.value_type().validate_value(value, self),
+            Enum { members } => {
+                if members.iter().any(|member| member.value.matches(value)) {
+                    Ok(())
+                } else if !members
+                    .iter()
+                    .any(|member| member.value.is_same_kind(value))
+                {
+                    Err(AttributeValueError::InvalidType {
+                        value: value.to_string(),
+                        expected: self.to_string(),
+                    })
+                } else if self.is_open_enum() {
+                    // The `_OTHER` member accepts custom values.
+                    Ok(())
+                } else {
+                    Err(AttributeValueError::UnknownEnumMember {
+                        value: value.to_string(),
+                        members: members
+                            .iter()
+                            .map(|member| format!("`{}`", member.value))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    })
+                }
+            }
+        }
+    }
+}
+
 /// Implements a human readable display for AttributeType.
 impl Display for AttributeType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -259,6 +556,19 @@ impl AttributeRole {
     }
 }
 
+/// The part of a span an attribute is recorded on.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq, Hash, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeScope {
+    /// The attribute is recorded on the span itself.
+    #[default]
+    Span,
+    /// The attribute is recorded on the events of the span.
+    Event,
+    /// The attribute is recorded on the links of the span.
+    Link,
+}
+
 /// Primitive or array types.
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash, JsonSchema)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
@@ -317,6 +627,57 @@ impl PrimitiveOrArrayTypeSpec {
             _ => self == other,
         }
     }
+
+    /// Checks that a concrete value is of this type, `attr_type` is the type
+    /// reported in the errors.
+    fn validate_value(
+        &self,
+        value: &Value,
+        attr_type: &AttributeType,
+    ) -> Result<(), AttributeValueError> {
+        let is_of_type = |value: &Value, scalar_type: &PrimitiveOrArrayTypeSpec| match scalar_type {
+            PrimitiveOrArrayTypeSpec::Boolean => value.is_boolean(),
+            PrimitiveOrArrayTypeSpec::Int => value.is_i64(),
+            // An integer is a valid double, e.g. `1` recorded as `1.0`.
+            PrimitiveOrArrayTypeSpec::Double => value.is_number(),
+            PrimitiveOrArrayTypeSpec::String => value.is_string(),
+            _ => !value.is_null(),
+        };
+        let element_type = match self {
+            PrimitiveOrArrayTypeSpec::Strings => PrimitiveOrArrayTypeSpec::String,
+            PrimitiveOrArrayTypeSpec::Ints => PrimitiveOrArrayTypeSpec::Int,
+            PrimitiveOrArrayTypeSpec::Doubles => PrimitiveOrArrayTypeSpec::Double,
+            PrimitiveOrArrayTypeSpec::Booleans => PrimitiveOrArrayTypeSpec::Boolean,
+            scalar_type => {
+                return if is_of_type(value, scalar_type) {
+                    Ok(())
+                } else {
+                    Err(AttributeValueError::InvalidType {
+                        value: value.to_string(),
+                        expected: attr_type.to_string(),
+                    })
+                };
+            }
+        };
+        let Some(elements) = value.as_array() else {
+            return Err(AttributeValueError::InvalidType {
+                value: value.to_string(),
+                expected: attr_type.to_string(),
+            });
+        };
+        match elements
+            .iter()
+            .enumerate()
+            .find(|(_, element)| !is_of_type(element, &element_type))
+        {
+            Some((index, element)) => Err(AttributeValueError::InvalidArrayElement {
+                index,
+                value: element.to_string(),
+                expected: element_type.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Template types.
@@ -370,6 +731,23 @@ impl Display for TemplateTypeSpec {
     }
 }
 
+impl TemplateTypeSpec {
+    /// Returns the type of the value of each key of the template.
+    fn value_type(&self) -> PrimitiveOrArrayTypeSpec {
+        match self {
+            TemplateTypeSpec::Boolean => PrimitiveOrArrayTypeSpec::Boolean,
+            TemplateTypeSpec::Int => PrimitiveOrArrayTypeSpec::Int,
+            TemplateTypeSpec::Double => PrimitiveOrArrayTypeSpec::Double,
+            TemplateTypeSpec::String => PrimitiveOrArrayTypeSpec::String,
+            TemplateTypeSpec::Any => PrimitiveOrArrayTypeSpec::Any,
+            TemplateTypeSpec::Strings => PrimitiveOrArrayTypeSpec::Strings,
+            TemplateTypeSpec::Ints => PrimitiveOrArrayTypeSpec::Ints,
+            TemplateTypeSpec::Doubles => PrimitiveOrArrayTypeSpec::Doubles,
+            TemplateTypeSpec::Booleans => PrimitiveOrArrayTypeSpec::Booleans,
+        }
+    }
+}
+
 /// Possible enum entries.
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash, JsonSchema)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
@@ -439,6 +817,28 @@ impl Display for ValueSpec {
     }
 }
 
+impl ValueSpec {
+    /// Returns true if the concrete value is equal to this value.
+    fn matches(&self, value: &Value) -> bool {
+        match self {
+            ValueSpec::Int(v) => value.as_i64() == Some(*v),
+            ValueSpec::Double(v) => value.as_f64() == Some(v.0),
+            ValueSpec::String(v) => value.as_str() == Some(v.as_str()),
+            ValueSpec::Bool(v) => value.as_bool() == Some(*v),
+        }
+    }
+
+    /// Returns true if the concrete value is of the same type as this value.
+    fn is_same_kind(&self, value: &Value) -> bool {
+        match self {
+            ValueSpec::Int(_) => value.is_i64(),
+            ValueSpec::Double(_) => value.is_number(),
+            ValueSpec::String(_) => value.is_string(),
+            ValueSpec::Bool(_) => value.is_boolean(),
+        }
+    }
+}
+
 /// Allows to convert a i64 into a ValueSpec.
 impl From<i64> for ValueSpec {
     /// Converts a i64 into a ValueSpec.
@@ -510,6 +910,15 @@ pub enum Examples {
     ListOfStrings(Vec<Vec<String>>),
 }
 
+/// A single example, see [`Examples::as_vec`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ExampleValue {
+    /// An example of a scalar attribute.
+    Scalar(ValueSpec),
+    /// An example of an array attribute.
+    Array(Vec<ValueSpec>),
+}
+
 impl Examples {
     /// Validation logic for the group.
     pub(crate) fn validate(
@@ -533,10 +942,33 @@ impl Examples {
             | (Examples::ListOfBools(_), PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Booleans))
             | (Examples::ListOfStrings(_), PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Strings))
             | (_, PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Any)) => WResult::Ok(()),
-            (_, Enum { .. }) => {
-                // enum types are open so it's not possible to validate the examples
+            (_, Enum { .. }) if attr_type.is_open_enum() => {
+                // The `_OTHER` member explicitly opens the enum, examples can
+                // show custom values.
                 WResult::Ok(())
             }
+            (_, Enum { members }) => {
+                // Examples must be drawn from the values of the members.
+                let errors = self
+                    .as_vec()
+                    .into_iter()
+                    .flat_map(|example| match example {
+                        ExampleValue::Scalar(value) => vec![value],
+                        ExampleValue::Array(values) => values,
+                    })
+                    .filter(|value| !members.iter().any(|member| &member.value == value))
+                    .map(|value| Error::InvalidAttribute {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: group_id.to_owned(),
+                        attribute_id: attr_id.to_owned(),
+                        error: format!(
+                            "The example `{value}` is not the value of any member of the enum."
+                        ),
+                        path: "/examples".into(),
+                    })
+                    .collect();
+                WResult::with_non_fatal_errors((), errors)
+            }
             // Only if future mode is disabled, we allow to have examples following
             // the conventions used in semconv 1.27.0 and earlier.
             (Examples::Ints(_), PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Ints))
@@ -550,6 +982,7 @@ impl Examples {
                         group_id: group_id.to_owned(),
                         attribute_id: attr_id.to_owned(),
                         error: format!("All examples SHOULD be of type `{attr_type}`"),
+                        path: "/examples".into(),
                     }],
                 )
             }
@@ -576,6 +1009,28 @@ impl Examples {
                     group_id: group_id.to_owned(),
                     attribute_id: attr_id.to_owned(),
                     error: format!("All examples SHOULD be of type `{attr_type}`"),
+                    path: "/examples".into(),
+                }],
+            ),
+            // A single scalar can't be an example of an array attribute.
+            (
+                Examples::Bool(_) | Examples::Int(_) | Examples::Double(_) | Examples::String(_),
+                PrimitiveOrArray(
+                    PrimitiveOrArrayTypeSpec::Booleans
+                    | PrimitiveOrArrayTypeSpec::Ints
+                    | PrimitiveOrArrayTypeSpec::Doubles
+                    | PrimitiveOrArrayTypeSpec::Strings,
+                ),
+            ) => WResult::OkWithNFEs(
+                (),
+                vec![Error::InvalidAttribute {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: group_id.to_owned(),
+                    attribute_id: attr_id.to_owned(),
+                    error: format!(
+                        "This attribute is of type `{attr_type}` but its example is a scalar, each example must be an array (e.g. `examples: [[a, b]]`)."
+                    ),
+                    path: "/examples".into(),
                 }],
             ),
             _ => WResult::OkWithNFEs(
@@ -585,6 +1040,7 @@ impl Examples {
                     group_id: group_id.to_owned(),
                     attribute_id: attr_id.to_owned(),
                     error: format!("All examples MUST be of type `{attr_type}`"),
+                    path: "/examples".into(),
                 }],
             ),
         }
@@ -673,6 +1129,11 @@ pub enum RequirementLevel {
         /// The description of the condition.
         #[serde(rename = "conditionally_required")]
         text: String,
+        /// The machine-readable condition, referencing other attributes of
+        /// the group, e.g. `http.response.status_code >= 500`.
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        condition: Option<Box<Condition>>,
     },
     /// A recommended requirement level.
     Recommended {
@@ -688,12 +1149,37 @@ pub enum RequirementLevel {
     },
 }
 
+impl RequirementLevel {
+    /// Returns the custom description of the requirement level (e.g. the
+    /// condition of a conditionally required attribute) or `None` for the
+    /// basic requirement levels.
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            RequirementLevel::Basic(_) => None,
+            RequirementLevel::ConditionallyRequired { text, .. }
+            | RequirementLevel::Recommended { text }
+            | RequirementLevel::OptIn { text } => Some(text),
+        }
+    }
+
+    /// Returns the machine-readable condition of a conditionally required
+    /// attribute, if any.
+    #[must_use]
+    pub fn condition(&self) -> Option<&Condition> {
+        match self {
+            RequirementLevel::ConditionallyRequired { condition, .. } => condition.as_deref(),
+            _ => None,
+        }
+    }
+}
+
 /// Implements a human readable display for RequirementLevel.
 impl Display for RequirementLevel {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             RequirementLevel::Basic(brl) => write!(f, "{brl}"),
-            RequirementLevel::ConditionallyRequired { text } => {
+            RequirementLevel::ConditionallyRequired { text, .. } => {
                 write!(f, "conditionally required (condition: {text})")
             }
             RequirementLevel::Recommended { text } => write!(f, "recommended ({text})"),
@@ -746,6 +1232,152 @@ impl Examples {
     pub fn from_f64s(values: Vec<f64>) -> Self {
         Examples::Doubles(values.into_iter().map(OrderedFloat).collect())
     }
+
+    /// Returns true if the examples are given as a list (e.g. `Strings` or
+    /// `ListOfStrings`) rather than as a single scalar example.
+    #[must_use]
+    pub fn is_list(&self) -> bool {
+        !matches!(
+            self,
+            Examples::Bool(_)
+                | Examples::Int(_)
+                | Examples::Double(_)
+                | Examples::String(_)
+                | Examples::Any(_)
+        )
+    }
+
+    /// Returns the examples as a list, a single example being returned as a
+    /// one-element list.
+    ///
+    /// The sequences of scalars (e.g. `Strings`) are considered as several
+    /// scalar examples and the lists of sequences (e.g. `ListOfStrings`) as
+    /// several array examples.
+    #[must_use]
+    pub fn as_vec(&self) -> Vec<ExampleValue> {
+        fn scalars<T: Clone>(values: &[T], f: fn(T) -> ValueSpec) -> Vec<ExampleValue> {
+            values
+                .iter()
+                .cloned()
+                .map(|v| ExampleValue::Scalar(f(v)))
+                .collect()
+        }
+        fn arrays<T: Clone>(values: &[Vec<T>], f: fn(T) -> ValueSpec) -> Vec<ExampleValue> {
+            values
+                .iter()
+                .map(|v| ExampleValue::Array(v.iter().cloned().map(f).collect()))
+                .collect()
+        }
+
+        match self {
+            Examples::Bool(v) => vec![ExampleValue::Scalar(ValueSpec::Bool(*v))],
+            Examples::Int(v) => vec![ExampleValue::Scalar(ValueSpec::Int(*v))],
+            Examples::Double(v) => vec![ExampleValue::Scalar(ValueSpec::Double(*v))],
+            Examples::String(v) => vec![ExampleValue::Scalar(ValueSpec::String(v.clone()))],
+            Examples::Any(v) => vec![ExampleValue::Scalar(v.clone())],
+            Examples::Ints(v) => scalars(v, ValueSpec::Int),
+            Examples::Doubles(v) => scalars(v, ValueSpec::Double),
+            Examples::Bools(v) => scalars(v, ValueSpec::Bool),
+            Examples::Strings(v) => scalars(v, ValueSpec::String),
+            Examples::Anys(v) => v.iter().cloned().map(ExampleValue::Scalar).collect(),
+            Examples::ListOfInts(v) => arrays(v, ValueSpec::Int),
+            Examples::ListOfDoubles(v) => arrays(v, ValueSpec::Double),
+            Examples::ListOfBools(v) => arrays(v, ValueSpec::Bool),
+            Examples::ListOfStrings(v) => arrays(v, ValueSpec::String),
+        }
+    }
+}
+
+/// Parses a standalone attribute definition (a reference or a local
+/// definition) from YAML.
+///
+/// Unknown fields are rejected, the returned error names the first unknown
+/// field of the attribute definition.
+pub fn parse_attribute(yaml: &str) -> Result<AttributeSpec, Error> {
+    serde_yaml::from_str::<AttributeSpec>(yaml).map_err(|e| match unknown_attribute_field(yaml) {
+        Some(field) => Error::UnknownAttributeField { field },
+        None => Error::deserialization("<attribute>", e),
+    })
+}
+
+/// Returns the fields accepted on a reference to an attribute (`ref`).
+#[must_use]
+pub fn ref_attribute_fields() -> BTreeSet<String> {
+    attribute_variant_fields(true)
+}
+
+/// Returns the fields accepted on an attribute definition (`id`) but not on a
+/// reference (e.g. `type`), i.e. the fields a reference can't override.
+#[must_use]
+pub fn definition_only_attribute_fields() -> BTreeSet<String> {
+    let ref_fields = attribute_variant_fields(true);
+    attribute_variant_fields(false)
+        .into_iter()
+        .filter(|field| !ref_fields.contains(field))
+        .collect()
+}
+
+/// Returns the note of an attribute followed by its `examples_note` as a
+/// fenced code block, so renderers keep the example payloads apart from the
+/// prose. The note is returned as is when there is no `examples_note`.
+#[must_use]
+pub fn note_with_examples(note: &str, examples_note: Option<&str>) -> String {
+    match examples_note
+        .map(str::trim_end)
+        .filter(|examples| !examples.is_empty())
+    {
+        Some(examples) => format!("{}\n\n```\n{examples}\n```\n", note.trim_end()),
+        None => note.to_owned(),
+    }
+}
+
+/// Returns the effective name of an attribute defined with the given id in a
+/// group with the given prefix, i.e. `{prefix}.{id}`, or the id itself when
+/// the group has no prefix.
+///
+/// The prefix and the id are joined with a single dot, a prefix ending with a
+/// dot or an id starting with one results in an empty name segment.
+#[must_use]
+pub fn effective_attribute_name(prefix: &str, id: &str) -> String {
+    if prefix.is_empty() {
+        id.to_owned()
+    } else {
+        format!("{prefix}.{id}")
+    }
+}
+
+/// Returns the fields of the `Ref` or `Id` variant of [`AttributeSpec`] as
+/// defined by its JSON schema.
+fn attribute_variant_fields(is_ref: bool) -> BTreeSet<String> {
+    let root_schema = schemars::schema_for!(AttributeSpec);
+    root_schema
+        .schema
+        .subschemas
+        .and_then(|subschemas| subschemas.any_of)
+        .into_iter()
+        .flatten()
+        .find_map(|variant| match variant {
+            schemars::schema::Schema::Object(schema) => {
+                let object = schema.object?;
+                (object.required.contains("ref") == is_ref)
+                    .then(|| object.properties.into_keys().collect())
+            }
+            schemars::schema::Schema::Bool(_) => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the first field of the attribute definition that is not defined by
+/// the matching variant of [`AttributeSpec`] (`Ref` if a `ref` field is
+/// present, `Id` otherwise).
+fn unknown_attribute_field(yaml: &str) -> Option<String> {
+    let mapping: serde_yaml::Mapping = serde_yaml::from_str(yaml).ok()?;
+    let fields = attribute_variant_fields(mapping.contains_key("ref"));
+    mapping.keys().find_map(|key| match key.as_str() {
+        Some(key) if fields.contains(key) => None,
+        Some(key) => Some(key.to_owned()),
+        None => Some(format!("{key:?}")),
+    })
 }
 
 #[cfg(test)]
@@ -786,7 +1418,8 @@ mod tests {
             format!(
                 "{}",
                 RequirementLevel::ConditionallyRequired {
-                    text: "condition".to_owned()
+                    text: "condition".to_owned(),
+                    condition: None,
                 }
             ),
             "conditionally required (condition: condition)"
@@ -811,6 +1444,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_requirement_level_serde() {
+        let opt_in: RequirementLevel = serde_yaml::from_str("opt_in").unwrap();
+        assert_eq!(
+            opt_in,
+            RequirementLevel::Basic(BasicRequirementLevelSpec::OptIn)
+        );
+        assert_eq!(serde_yaml::to_string(&opt_in).unwrap(), "opt_in\n");
+
+        let opt_in: RequirementLevel =
+            serde_yaml::from_str("opt_in: Only when the user enables it.").unwrap();
+        assert_eq!(
+            opt_in,
+            RequirementLevel::OptIn {
+                text: "Only when the user enables it.".to_owned()
+            }
+        );
+        assert_eq!(
+            serde_yaml::to_string(&opt_in).unwrap(),
+            "opt_in: Only when the user enables it.\n"
+        );
+
+        // The opt-in level is distinct from the recommended one.
+        let recommended: RequirementLevel = serde_yaml::from_str("recommended").unwrap();
+        assert_ne!(opt_in, recommended);
+        assert!(serde_yaml::from_str::<RequirementLevel>("opt-in").is_err());
+    }
+
     #[test]
     fn test_basic_requirement_level_spec_display() {
         assert_eq!(
@@ -984,6 +1645,7 @@ mod tests {
     fn test_attribute() {
         let attr = AttributeSpec::Id {
             id: "id".to_owned(),
+            renamed_from: None,
             r#type: PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
             brief: Some("brief".to_owned()),
             examples: Some(Examples::Int(42)),
@@ -991,12 +1653,14 @@ mod tests {
             requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
             sampling_relevant: Some(true),
             note: "note".to_owned(),
+            examples_note: None,
             stability: Some(Stability::Stable),
             deprecated: Some(Deprecated::Obsoleted {
                 note: "".to_owned(),
             }),
             annotations: None,
             role: Default::default(),
+            scope: None,
         };
         assert_eq!(attr.id(), "id");
         assert_eq!(attr.brief(), "brief");
@@ -1012,6 +1676,7 @@ mod tests {
             requirement_level: Some(RequirementLevel::Basic(BasicRequirementLevelSpec::Required)),
             sampling_relevant: Some(true),
             note: Some("note".to_owned()),
+            examples_note: None,
             stability: Some(Stability::Stable),
             deprecated: Some(Deprecated::Obsoleted {
                 note: "".to_owned(),
@@ -1019,6 +1684,7 @@ mod tests {
             prefix: false,
             annotations: None,
             role: Default::default(),
+            scope: None,
         };
         assert_eq!(attr.id(), "ref");
         assert_eq!(attr.brief(), "brief");
@@ -1366,6 +2032,98 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn test_examples_validate_enum() {
+        let member = |id: &str, value: ValueSpec| EnumEntriesSpec {
+            id: id.to_owned(),
+            value,
+            brief: None,
+            note: None,
+            stability: None,
+            deprecated: None,
+            annotations: None,
+        };
+        let attr_enum = Enum {
+            members: vec![
+                member("get", ValueSpec::String("GET".to_owned())),
+                member("post", ValueSpec::String("POST".to_owned())),
+            ],
+        };
+
+        let examples = Examples::Strings(vec!["GET".to_owned(), "POST".to_owned()]);
+        assert!(examples
+            .validate(&attr_enum, "grp", "attr", "url")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        let examples = Examples::Strings(vec!["GET".to_owned(), "PUT".to_owned()]);
+        assert_eq!(
+            examples
+                .validate(&attr_enum, "grp", "attr", "url")
+                .into_result_failing_non_fatal(),
+            Err(Error::InvalidAttribute {
+                path_or_url: "url".to_owned(),
+                group_id: "grp".to_owned(),
+                attribute_id: "attr".to_owned(),
+                error: "The example `PUT` is not the value of any member of the enum.".to_owned(),
+                path: "/examples".into(),
+            })
+        );
+
+        // Values are compared with their type.
+        let attr_enum = Enum {
+            members: vec![member("one", ValueSpec::Int(1))],
+        };
+        assert!(Examples::Int(1)
+            .validate(&attr_enum, "grp", "attr", "url")
+            .into_result_failing_non_fatal()
+            .is_ok());
+        assert!(Examples::String("1".to_owned())
+            .validate(&attr_enum, "grp", "attr", "url")
+            .into_result_failing_non_fatal()
+            .is_err());
+
+        // An enum with the `_OTHER` member accepts custom examples.
+        let attr_enum = Enum {
+            members: vec![member("other", ValueSpec::String("_OTHER".to_owned()))],
+        };
+        assert!(Examples::Strings(vec!["timeout".to_owned()])
+            .validate(&attr_enum, "grp", "attr", "url")
+            .into_result_failing_non_fatal()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_examples_as_vec() {
+        let string = |s: &str| ValueSpec::String(s.to_owned());
+
+        let examples: Examples = serde_yaml::from_str("foo").unwrap();
+        assert_eq!(examples.as_vec(), vec![ExampleValue::Scalar(string("foo"))]);
+
+        let examples: Examples = serde_yaml::from_str("[foo, bar]").unwrap();
+        assert_eq!(
+            examples.as_vec(),
+            vec![
+                ExampleValue::Scalar(string("foo")),
+                ExampleValue::Scalar(string("bar"))
+            ]
+        );
+
+        let examples: Examples = serde_yaml::from_str("[[foo, bar], [baz]]").unwrap();
+        assert_eq!(
+            examples.as_vec(),
+            vec![
+                ExampleValue::Array(vec![string("foo"), string("bar")]),
+                ExampleValue::Array(vec![string("baz")])
+            ]
+        );
+
+        assert_eq!(
+            Examples::Int(42).as_vec(),
+            vec![ExampleValue::Scalar(ValueSpec::Int(42))]
+        );
+    }
+
     #[test]
     fn test_is_compatible() {
         assert!(PrimitiveOrArrayTypeSpec::Boolean.is_compatible(&PrimitiveOrArrayTypeSpec::Boolean));
@@ -1375,6 +2133,228 @@ mod tests {
         assert!(PrimitiveOrArrayTypeSpec::Any.is_compatible(&PrimitiveOrArrayTypeSpec::Double));
         assert!(PrimitiveOrArrayTypeSpec::Any.is_compatible(&PrimitiveOrArrayTypeSpec::Any));
     }
+
+    #[test]
+    fn test_validate_value() {
+        use serde_json::json;
+
+        let attribute = |r#type: &str| {
+            parse_attribute(&format!(
+                "id: attr\ntype: {type}\nbrief: An attribute.\nstability: stable\n"
+            ))
+            .expect("Failed to parse the attribute")
+        };
+
+        let int = attribute("int");
+        assert_eq!(int.validate_value(&json!(42)), Ok(()));
+        assert_eq!(
+            int.validate_value(&json!(4.2)),
+            Err(AttributeValueError::InvalidType {
+                value: "4.2".to_owned(),
+                expected: "int".to_owned(),
+            })
+        );
+        assert!(attribute("double").validate_value(&json!(1)).is_ok());
+        assert!(attribute("boolean").validate_value(&json!("true")).is_err());
+        assert!(attribute("any").validate_value(&json!([1, 2])).is_ok());
+        assert!(attribute("any").validate_value(&json!(null)).is_err());
+
+        let strings = attribute("string[]");
+        assert!(strings.validate_value(&json!(["a", "b"])).is_ok());
+        assert!(strings.validate_value(&json!("a")).is_err());
+        assert_eq!(
+            strings.validate_value(&json!(["a", 2])),
+            Err(AttributeValueError::InvalidArrayElement {
+                index: 1,
+                value: "2".to_owned(),
+                expected: "string".to_owned(),
+            })
+        );
+        assert!(attribute("template[int[]]")
+            .validate_value(&json!([1, 2]))
+            .is_ok());
+
+        let members = "\n  members:\n    - id: get\n      value: GET\n      stability: stable\n    - id: post\n      value: POST\n      stability: stable";
+        let closed = attribute(members);
+        assert!(closed.validate_value(&json!("GET")).is_ok());
+        assert_eq!(
+            closed.validate_value(&json!("PATCH")),
+            Err(AttributeValueError::UnknownEnumMember {
+                value: "\"PATCH\"".to_owned(),
+                members: "`GET`, `POST`".to_owned(),
+            })
+        );
+        assert!(matches!(
+            closed.validate_value(&json!(1)),
+            Err(AttributeValueError::InvalidType { .. })
+        ));
+        let open = attribute(&format!(
+            "{members}\n    - id: other\n      value: _OTHER\n      stability: stable"
+        ));
+        assert!(open.validate_value(&json!("PATCH")).is_ok());
+        assert!(open.validate_value(&json!(1)).is_err());
+
+        let reference = parse_attribute("ref: attr\n").expect("Failed to parse the attribute");
+        assert_eq!(
+            reference.validate_value(&json!(42)),
+            Err(AttributeValueError::UnresolvedReference {
+                attribute_ref: "attr".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_attribute() {
+        let attribute = parse_attribute(
+            r#"
+id: http.request.method
+type: string
+brief: The HTTP request method.
+stability: stable
+examples: [GET, POST]
+"#,
+        )
+        .expect("Failed to parse the attribute");
+        assert!(matches!(attribute, AttributeSpec::Id { .. }));
+        assert_eq!(attribute.id(), "http.request.method");
+
+        let attribute = parse_attribute(
+            r#"
+ref: http.request.method
+requirement_level: required
+"#,
+        )
+        .expect("Failed to parse the attribute");
+        assert!(matches!(attribute, AttributeSpec::Ref { .. }));
+        assert!(attribute.is_required());
+
+        assert_eq!(
+            parse_attribute(
+                r#"
+ref: http.request.method
+requirement: required
+"#
+            ),
+            Err(Error::UnknownAttributeField {
+                field: "requirement".to_owned(),
+            })
+        );
+        assert_eq!(
+            parse_attribute(
+                r#"
+id: http.request.method
+type: string
+brief: The HTTP request method.
+stability: stable
+sampling: true
+"#
+            ),
+            Err(Error::UnknownAttributeField {
+                field: "sampling".to_owned(),
+            })
+        );
+        assert!(matches!(
+            parse_attribute("ref: [http.request.method]"),
+            Err(Error::DeserializationError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_requirement_level() {
+        let attribute = parse_attribute(
+            r#"
+id: http.request.method
+type: string
+brief: The HTTP method.
+stability: stable
+requirement_level: required
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            attribute.requirement_level(),
+            RequirementLevel::Basic(BasicRequirementLevelSpec::Required)
+        );
+
+        let attribute = parse_attribute(
+            r#"
+id: http.route
+type: string
+brief: The route.
+stability: stable
+"#,
+        )
+        .unwrap();
+        assert_eq!(attribute.requirement_level(), RequirementLevel::default());
+
+        let attribute = parse_attribute("ref: http.request.method").unwrap();
+        assert_eq!(
+            attribute.requirement_level(),
+            RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended)
+        );
+        let attribute = parse_attribute(
+            r#"
+ref: http.request.method
+requirement_level: opt_in
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            attribute.requirement_level(),
+            RequirementLevel::Basic(BasicRequirementLevelSpec::OptIn)
+        );
+    }
+
+    #[test]
+    fn test_examples_note() {
+        let attribute = parse_attribute(
+            r#"
+id: http.request.body
+type: string
+brief: The request body.
+stability: development
+note: The body is recorded as is.
+examples_note: |
+  {"user": "alice"}
+"#,
+        )
+        .unwrap();
+        let AttributeSpec::Id {
+            note,
+            examples_note,
+            ..
+        } = &attribute
+        else {
+            panic!("Expected an attribute definition");
+        };
+        assert_eq!(examples_note.as_deref(), Some("{\"user\": \"alice\"}\n"));
+
+        // Rendered as a fenced code block after the prose.
+        assert_eq!(
+            note_with_examples(note, examples_note.as_deref()),
+            "The body is recorded as is.\n\n```\n{\"user\": \"alice\"}\n```\n"
+        );
+        assert_eq!(
+            note_with_examples(note, None),
+            "The body is recorded as is."
+        );
+        assert_eq!(
+            parse_attribute("ref: http.request.body\nexamples_note: '{}'").map(
+                |attribute| matches!(
+                    attribute,
+                    AttributeSpec::Ref { examples_note: Some(examples), .. } if examples == "{}"
+                )
+            ),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn test_effective_attribute_name() {
+        assert_eq!(effective_attribute_name("http", "method"), "http.method");
+        assert_eq!(effective_attribute_name("", "http.method"), "http.method");
+        assert_eq!(effective_attribute_name("http.", "method"), "http..method");
+    }
 }
 
 /// An attribute definition with its provenance (path or URL).
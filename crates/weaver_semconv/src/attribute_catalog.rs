@@ -0,0 +1,307 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A registry-wide catalog of the attributes with back-references to the
+//! groups defining or referencing them.
+
+use crate::attribute::{effective_attribute_name, AttributeSpec};
+use crate::group::{GroupSpecWithProvenance, GroupType};
+use crate::provenance::Provenance;
+use crate::registry::SemConvRegistry;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+
+/// How a group uses an attribute.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeUsageKind {
+    /// The group defines the attribute (`id` field).
+    Definition,
+    /// The group references the attribute (`ref` field).
+    Reference,
+}
+
+/// A group defining or referencing an attribute.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AttributeUsage {
+    /// The id of the group.
+    pub group_id: String,
+    /// The provenance of the group.
+    pub provenance: Provenance,
+    /// How the group uses the attribute.
+    pub kind: AttributeUsageKind,
+}
+
+/// A catalog mapping each attribute id to the groups using it.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AttributeCatalog {
+    /// The usages of each attribute, in the order of the groups.
+    attributes: BTreeMap<String, Vec<AttributeUsage>>,
+}
+
+impl AttributeCatalog {
+    /// Returns the ids of the attributes, sorted.
+    pub fn attribute_ids(&self) -> impl Iterator<Item = &str> {
+        self.attributes.keys().map(String::as_str)
+    }
+
+    /// Returns the groups defining or referencing the given attribute.
+    #[must_use]
+    pub fn usages(&self, attribute_id: &str) -> &[AttributeUsage] {
+        self.attributes
+            .get(attribute_id)
+            .map_or(&[], |usages| usages.as_slice())
+    }
+
+    /// Returns the groups defining the given attribute.
+    pub fn definitions<'a>(
+        &'a self,
+        attribute_id: &str,
+    ) -> impl Iterator<Item = &'a AttributeUsage> {
+        self.usages_of_kind(attribute_id, AttributeUsageKind::Definition)
+    }
+
+    /// Returns the groups referencing the given attribute.
+    pub fn references<'a>(
+        &'a self,
+        attribute_id: &str,
+    ) -> impl Iterator<Item = &'a AttributeUsage> {
+        self.usages_of_kind(attribute_id, AttributeUsageKind::Reference)
+    }
+
+    /// Returns the ids of the attributes defined but never referenced, sorted.
+    pub fn unreferenced_attribute_ids(&self) -> impl Iterator<Item = &str> {
+        self.attributes
+            .iter()
+            .filter(|(_, usages)| {
+                usages
+                    .iter()
+                    .all(|usage| usage.kind == AttributeUsageKind::Definition)
+            })
+            .map(|(id, _)| id.as_str())
+    }
+
+    fn usages_of_kind<'a>(
+        &'a self,
+        attribute_id: &str,
+        kind: AttributeUsageKind,
+    ) -> impl Iterator<Item = &'a AttributeUsage> {
+        self.usages(attribute_id)
+            .iter()
+            .filter(move |usage| usage.kind == kind)
+    }
+}
+
+/// Builds the attribute catalog of the given groups.
+///
+/// The attributes are indexed by their effective id, i.e. with the prefix of
+/// the group when the group defines one (or when a reference sets `prefix`).
+#[must_use]
+pub fn build_attribute_catalog(groups: &[GroupSpecWithProvenance]) -> AttributeCatalog {
+    let mut catalog = AttributeCatalog::default();
+    for group in groups {
+        for attribute in &group.spec.attributes {
+            let (attribute_id, kind) = effective_attribute_id(&group.spec.prefix, attribute);
+            catalog
+                .attributes
+                .entry(attribute_id)
+                .or_default()
+                .push(AttributeUsage {
+                    group_id: group.spec.id.clone(),
+                    provenance: group.provenance.clone(),
+                    kind,
+                });
+        }
+    }
+    catalog
+}
+
+/// Returns the ids of the attribute groups and metric groups nothing uses, in
+/// the order of the registry.
+///
+/// A group is unused when it is neither the target of an `extends` or
+/// `include_groups` clause nor defines an attribute referenced by a group.
+#[must_use]
+pub fn find_unreferenced_groups(registry: &SemConvRegistry) -> Vec<String> {
+    let groups: Vec<_> = registry.unresolved_group_with_provenance_iter().collect();
+    let catalog = build_attribute_catalog(&groups);
+    let inherited_group_ids: HashSet<&str> = groups
+        .iter()
+        .flat_map(|group| group.spec.extends.iter().chain(&group.spec.include_groups))
+        .map(String::as_str)
+        .collect();
+
+    groups
+        .iter()
+        .filter(|group| {
+            matches!(
+                group.spec.r#type,
+                GroupType::AttributeGroup | GroupType::MetricGroup
+            )
+        })
+        .filter(|group| !inherited_group_ids.contains(group.spec.id.as_str()))
+        .filter(|group| {
+            group.spec.attributes.iter().all(|attribute| {
+                match effective_attribute_id(&group.spec.prefix, attribute) {
+                    (attribute_id, AttributeUsageKind::Definition) => {
+                        catalog.references(&attribute_id).next().is_none()
+                    }
+                    (_, AttributeUsageKind::Reference) => true,
+                }
+            })
+        })
+        .map(|group| group.spec.id.clone())
+        .collect()
+}
+
+/// Returns the effective id of an attribute of a group and how the group uses it.
+pub(crate) fn effective_attribute_id(
+    group_prefix: &str,
+    attribute: &AttributeSpec,
+) -> (String, AttributeUsageKind) {
+    match attribute {
+        AttributeSpec::Id { id, .. } => (
+            effective_attribute_name(group_prefix, id),
+            AttributeUsageKind::Definition,
+        ),
+        AttributeSpec::Ref {
+            r#ref,
+            prefix: true,
+            ..
+        } if !group_prefix.is_empty() => (
+            format!("{group_prefix}.{ref}"),
+            AttributeUsageKind::Reference,
+        ),
+        AttributeSpec::Ref { r#ref, .. } => (r#ref.clone(), AttributeUsageKind::Reference),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_attribute_catalog() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "http.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+      - id: http.route
+        type: string
+        brief: The route.
+        stability: stable
+        examples: ['/users/:id']
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: http.request.method
+  - id: metric.http.server.duration
+    type: metric
+    brief: The HTTP server duration.
+    metric_name: http.server.duration
+    instrument: histogram
+    unit: s
+    stability: stable
+    attributes:
+      - ref: http.request.method
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        let groups: Vec<_> = registry.unresolved_group_with_provenance_iter().collect();
+        let catalog = build_attribute_catalog(&groups);
+
+        assert_eq!(
+            catalog.attribute_ids().collect::<Vec<_>>(),
+            vec!["http.request.method", "http.route"]
+        );
+        let definitions: Vec<_> = catalog
+            .definitions("http.request.method")
+            .map(|usage| usage.group_id.as_str())
+            .collect();
+        assert_eq!(definitions, vec!["registry.http"]);
+        let references: Vec<_> = catalog
+            .references("http.request.method")
+            .map(|usage| usage.group_id.as_str())
+            .collect();
+        assert_eq!(
+            references,
+            vec!["span.http.client", "metric.http.server.duration"]
+        );
+        assert!(catalog
+            .usages("http.request.method")
+            .iter()
+            .all(|usage| usage.provenance == Provenance::new("test", "http.yaml")));
+        assert_eq!(
+            catalog.unreferenced_attribute_ids().collect::<Vec<_>>(),
+            vec!["http.route"]
+        );
+        assert!(catalog.usages("unknown").is_empty());
+    }
+
+    #[test]
+    fn test_find_unreferenced_groups() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "groups.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+  - id: registry.legacy
+    type: attribute_group
+    brief: Legacy attributes.
+    attributes:
+      - id: legacy.name
+        type: string
+        brief: The legacy name.
+        stability: stable
+        examples: [foo]
+  - id: attributes.http.common
+    type: attribute_group
+    brief: Common HTTP attributes.
+    attributes:
+      - ref: http.request.method
+  - id: attributes.http.unused
+    type: attribute_group
+    brief: Unused HTTP attributes.
+    attributes:
+      - ref: http.request.method
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    extends: attributes.http.common
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+
+        assert_eq!(
+            find_unreferenced_groups(&registry),
+            vec!["registry.legacy", "attributes.http.unused"]
+        );
+    }
+}
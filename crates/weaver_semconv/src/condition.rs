@@ -0,0 +1,473 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Machine-readable conditions of `conditionally_required` attributes.
+//!
+//! A condition is written as an expression referencing other attributes of
+//! the group, e.g. `http.response.status_code >= 500 && !error.type`. The
+//! grammar is the following:
+//!
+//! ```plaintext
+//! condition  := or
+//! or         := and ("||" and)*
+//! and        := unary ("&&" unary)*
+//! unary      := "!" unary | "(" condition ")" | comparison
+//! comparison := attribute (operator literal)?
+//! operator   := "==" | "!=" | "<" | "<=" | ">" | ">="
+//! literal    := string | int | double | "true" | "false"
+//! ```
+//!
+//! An attribute without comparison is true when the attribute is present.
+//! Once parsed, a condition is serialized in its structured form, so code
+//! generators can render runtime checks without parsing the expression.
+
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+use ordered_float::OrderedFloat;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::attribute::ValueSpec;
+
+/// A condition on the attributes of a group.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash, JsonSchema)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+#[serde(try_from = "ConditionDef")]
+pub enum Condition {
+    /// The attribute is present.
+    Exists(String),
+    /// The value of the attribute is compared to a literal.
+    Compare {
+        /// The name of the attribute.
+        attribute: String,
+        /// The comparison operator.
+        op: ComparisonOperator,
+        /// The literal the value of the attribute is compared to.
+        value: ValueSpec,
+    },
+    /// The negation of a condition.
+    #[cfg_attr(feature = "openapi", schema(no_recursion))]
+    Not(Box<Condition>),
+    /// All the conditions are true.
+    #[cfg_attr(feature = "openapi", schema(no_recursion))]
+    All(Vec<Condition>),
+    /// At least one of the conditions is true.
+    #[cfg_attr(feature = "openapi", schema(no_recursion))]
+    Any(Vec<Condition>),
+}
+
+/// A comparison operator.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash, JsonSchema)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOperator {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+/// A condition is either an expression to parse or its structured form.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ConditionDef {
+    Expression(String),
+    Structured(StructuredCondition),
+}
+
+/// Mirror of [`Condition`] used to deserialize its structured form.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum StructuredCondition {
+    Exists(String),
+    Compare {
+        attribute: String,
+        op: ComparisonOperator,
+        value: ValueSpec,
+    },
+    Not(Box<Condition>),
+    All(Vec<Condition>),
+    Any(Vec<Condition>),
+}
+
+impl TryFrom<ConditionDef> for Condition {
+    type Error = String;
+
+    fn try_from(def: ConditionDef) -> Result<Self, Self::Error> {
+        Ok(match def {
+            ConditionDef::Expression(expression) => expression.parse()?,
+            ConditionDef::Structured(StructuredCondition::Exists(attribute)) => {
+                Condition::Exists(attribute)
+            }
+            ConditionDef::Structured(StructuredCondition::Compare {
+                attribute,
+                op,
+                value,
+            }) => Condition::Compare {
+                attribute,
+                op,
+                value,
+            },
+            ConditionDef::Structured(StructuredCondition::Not(condition)) => {
+                Condition::Not(condition)
+            }
+            ConditionDef::Structured(StructuredCondition::All(conditions)) => {
+                Condition::All(conditions)
+            }
+            ConditionDef::Structured(StructuredCondition::Any(conditions)) => {
+                Condition::Any(conditions)
+            }
+        })
+    }
+}
+
+impl Condition {
+    /// Returns the names of the attributes referenced by the condition.
+    #[must_use]
+    pub fn attributes(&self) -> BTreeSet<&str> {
+        let mut attributes = BTreeSet::new();
+        self.collect_attributes(&mut attributes);
+        attributes
+    }
+
+    fn collect_attributes<'a>(&'a self, attributes: &mut BTreeSet<&'a str>) {
+        match self {
+            Condition::Exists(attribute) | Condition::Compare { attribute, .. } => {
+                _ = attributes.insert(attribute);
+            }
+            Condition::Not(condition) => condition.collect_attributes(attributes),
+            Condition::All(conditions) | Condition::Any(conditions) => conditions
+                .iter()
+                .for_each(|condition| condition.collect_attributes(attributes)),
+        }
+    }
+}
+
+impl std::str::FromStr for Condition {
+    type Err = String;
+
+    /// Parses a condition expression.
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let tokens =
+            tokenize(expression).map_err(|e| format!("Invalid condition `{expression}`: {e}"))?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let condition = parser
+            .parse_or()
+            .and_then(|condition| match parser.peek() {
+                None => Ok(condition),
+                Some(token) => Err(format!("unexpected {token}")),
+            })
+            .map_err(|e| format!("Invalid condition `{expression}`: {e}"))?;
+        Ok(condition)
+    }
+}
+
+/// Implements the expression form of a condition.
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn write_operand(f: &mut Formatter<'_>, condition: &Condition) -> std::fmt::Result {
+            match condition {
+                Condition::All(_) | Condition::Any(_) => write!(f, "({condition})"),
+                _ => write!(f, "{condition}"),
+            }
+        }
+        fn write_list(
+            f: &mut Formatter<'_>,
+            conditions: &[Condition],
+            separator: &str,
+        ) -> std::fmt::Result {
+            for (i, condition) in conditions.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {separator} ")?;
+                }
+                write_operand(f, condition)?;
+            }
+            Ok(())
+        }
+
+        match self {
+            Condition::Exists(attribute) => write!(f, "{attribute}"),
+            Condition::Compare {
+                attribute,
+                op,
+                value,
+            } => match value {
+                ValueSpec::String(value) => write!(f, "{attribute} {op} {value:?}"),
+                value => write!(f, "{attribute} {op} {value}"),
+            },
+            Condition::Not(condition) => {
+                write!(f, "!")?;
+                match condition.as_ref() {
+                    Condition::Compare { .. } => write!(f, "({condition})"),
+                    condition => write_operand(f, condition),
+                }
+            }
+            Condition::All(conditions) => write_list(f, conditions, "&&"),
+            Condition::Any(conditions) => write_list(f, conditions, "||"),
+        }
+    }
+}
+
+impl Display for ComparisonOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComparisonOperator::Eq => write!(f, "=="),
+            ComparisonOperator::Ne => write!(f, "!="),
+            ComparisonOperator::Lt => write!(f, "<"),
+            ComparisonOperator::Le => write!(f, "<="),
+            ComparisonOperator::Gt => write!(f, ">"),
+            ComparisonOperator::Ge => write!(f, ">="),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Identifier(String),
+    Literal(ValueSpec),
+    Operator(ComparisonOperator),
+    And,
+    Or,
+    Not,
+    LeftParen,
+    RightParen,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Identifier(identifier) => write!(f, "`{identifier}`"),
+            Token::Literal(value) => write!(f, "literal `{value}`"),
+            Token::Operator(op) => write!(f, "`{op}`"),
+            Token::And => write!(f, "`&&`"),
+            Token::Or => write!(f, "`||`"),
+            Token::Not => write!(f, "`!`"),
+            Token::LeftParen => write!(f, "`(`"),
+            Token::RightParen => write!(f, "`)`"),
+        }
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (token, len) = match (c, next) {
+            (c, _) if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('&', Some('&')) => (Token::And, 2),
+            ('|', Some('|')) => (Token::Or, 2),
+            ('=', Some('=')) => (Token::Operator(ComparisonOperator::Eq), 2),
+            ('!', Some('=')) => (Token::Operator(ComparisonOperator::Ne), 2),
+            ('<', Some('=')) => (Token::Operator(ComparisonOperator::Le), 2),
+            ('>', Some('=')) => (Token::Operator(ComparisonOperator::Ge), 2),
+            ('<', _) => (Token::Operator(ComparisonOperator::Lt), 1),
+            ('>', _) => (Token::Operator(ComparisonOperator::Gt), 1),
+            ('!', _) => (Token::Not, 1),
+            ('(', _) => (Token::LeftParen, 1),
+            (')', _) => (Token::RightParen, 1),
+            ('"' | '\'', _) => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&end| end == c)
+                    .ok_or_else(|| "unterminated string literal".to_owned())?;
+                let value: String = chars[i + 1..i + 1 + end].iter().collect();
+                (Token::Literal(ValueSpec::String(value)), end + 2)
+            }
+            (c, _) if c.is_ascii_digit() || c == '-' => {
+                let len = chars[i..]
+                    .iter()
+                    .skip(1)
+                    .take_while(|c| c.is_ascii_digit() || **c == '.')
+                    .count()
+                    + 1;
+                let number: String = chars[i..i + len].iter().collect();
+                let value = if number.contains('.') {
+                    number
+                        .parse::<f64>()
+                        .map(|value| ValueSpec::Double(OrderedFloat(value)))
+                        .map_err(|e| format!("invalid number `{number}`: {e}"))?
+                } else {
+                    number
+                        .parse::<i64>()
+                        .map(ValueSpec::Int)
+                        .map_err(|e| format!("invalid number `{number}`: {e}"))?
+                };
+                (Token::Literal(value), len)
+            }
+            (c, _) if c.is_ascii_alphabetic() => {
+                let len = chars[i..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_alphanumeric() || **c == '.' || **c == '_')
+                    .count();
+                let identifier: String = chars[i..i + len].iter().collect();
+                let token = match identifier.as_str() {
+                    "true" => Token::Literal(ValueSpec::Bool(true)),
+                    "false" => Token::Literal(ValueSpec::Bool(false)),
+                    _ => Token::Identifier(identifier),
+                };
+                (token, len)
+            }
+            (c, _) => return Err(format!("unexpected character `{c}`")),
+        };
+        tokens.push(token);
+        i += len;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Condition, String> {
+        let mut conditions = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            conditions.push(self.parse_and()?);
+        }
+        Ok(if conditions.len() == 1 {
+            conditions.remove(0)
+        } else {
+            Condition::Any(conditions)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Condition, String> {
+        let mut conditions = vec![self.parse_unary()?];
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            conditions.push(self.parse_unary()?);
+        }
+        Ok(if conditions.len() == 1 {
+            conditions.remove(0)
+        } else {
+            Condition::All(conditions)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Condition, String> {
+        match self.next() {
+            Some(Token::Not) => Ok(Condition::Not(Box::new(self.parse_unary()?))),
+            Some(Token::LeftParen) => {
+                let condition = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RightParen) => Ok(condition),
+                    Some(token) => Err(format!("expected `)`, found {token}")),
+                    None => Err("expected `)`".to_owned()),
+                }
+            }
+            Some(Token::Identifier(attribute)) => match self.peek() {
+                Some(Token::Operator(op)) => {
+                    let op = *op;
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Literal(value)) => Ok(Condition::Compare {
+                            attribute,
+                            op,
+                            value,
+                        }),
+                        Some(token) => Err(format!("expected a literal, found {token}")),
+                        None => Err(format!("expected a literal after `{op}`")),
+                    }
+                }
+                _ => Ok(Condition::Exists(attribute)),
+            },
+            Some(token) => Err(format!("expected an attribute, found {token}")),
+            None => Err("expected an attribute".to_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_condition() {
+        let condition: Condition = "http.response.status_code >= 500 && !error.type"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            condition,
+            Condition::All(vec![
+                Condition::Compare {
+                    attribute: "http.response.status_code".to_owned(),
+                    op: ComparisonOperator::Ge,
+                    value: ValueSpec::Int(500),
+                },
+                Condition::Not(Box::new(Condition::Exists("error.type".to_owned()))),
+            ])
+        );
+        assert_eq!(
+            condition.attributes().into_iter().collect::<Vec<_>>(),
+            vec!["error.type", "http.response.status_code"]
+        );
+
+        let condition: Condition =
+            "(network.protocol.name != 'http' || network.transport == \"udp\") && server.port"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            condition.to_string(),
+            "(network.protocol.name != \"http\" || network.transport == \"udp\") && server.port"
+        );
+        // The expression form can be parsed back.
+        assert_eq!(
+            condition.to_string().parse::<Condition>().unwrap(),
+            condition
+        );
+
+        assert!("".parse::<Condition>().is_err());
+        assert!("a.b ==".parse::<Condition>().is_err());
+        assert!("(a.b".parse::<Condition>().is_err());
+        assert!("a.b c.d".parse::<Condition>().is_err());
+        assert!("a.b == 'c".parse::<Condition>().is_err());
+    }
+
+    #[test]
+    fn test_serde_condition() {
+        let condition: Condition =
+            serde_yaml::from_str("\"url.scheme == 'https' || server.port > 1.5\"").unwrap();
+        let json = serde_json::to_string(&condition).unwrap();
+        assert_eq!(
+            json,
+            r#"{"any":[{"compare":{"attribute":"url.scheme","op":"eq","value":"https"}},{"compare":{"attribute":"server.port","op":"gt","value":1.5}}]}"#
+        );
+        // The structured form can be deserialized back.
+        assert_eq!(serde_json::from_str::<Condition>(&json).unwrap(), condition);
+        let yaml = "all:\n- exists: server.address\n- not:\n    exists: error.type\n";
+        assert_eq!(
+            serde_yaml::from_str::<Condition>(yaml).unwrap().to_string(),
+            "server.address && !error.type"
+        );
+
+        assert!(serde_yaml::from_str::<Condition>("\"a.b &&\"").is_err());
+    }
+}
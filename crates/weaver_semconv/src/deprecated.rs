@@ -7,11 +7,13 @@
 //! - A map with the action (renamed or removed) and optionally a note. When the
 //!   action is renamed, the map must also contain the field renamed_to.
 
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::de::{MapAccess, Visitor};
 use serde::{de, Deserialize, Deserializer, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::sync::LazyLock;
 
 /// The different ways to deprecate an attribute, a metric, ...
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash, JsonSchema)]
@@ -53,6 +55,58 @@ pub enum Deprecated {
     },
 }
 
+impl Deprecated {
+    /// Returns the id of the replacement of the deprecated telemetry object, if any.
+    ///
+    /// For the `renamed` reason this is the `renamed_to` field, otherwise the
+    /// replacement is extracted from notes such as "Replaced by `foo.bar`." or
+    /// "Use `foo.bar` instead.".
+    #[must_use]
+    pub fn replacement(&self) -> Option<&str> {
+        static REPLACEMENT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"(?i)\b(?:replaced by|use)\s+`([^`]+)`").expect("Invalid regex")
+        });
+
+        match self {
+            Deprecated::Renamed { renamed_to, .. } => Some(renamed_to.as_str()),
+            Deprecated::Obsoleted { note }
+            | Deprecated::Uncategorized { note }
+            | Deprecated::Unspecified { note } => REPLACEMENT_REGEX
+                .captures(note)
+                .and_then(|c| c.get(1))
+                .map(|m| m.as_str()),
+        }
+    }
+
+    /// Returns true if the note of the deprecation doesn't explain anything, i.e.
+    /// a boolean placeholder (`true` or `false`) or a text shorter than
+    /// [`MIN_DEPRECATION_NOTE_LEN`] characters.
+    ///
+    /// An empty note is only considered non-descriptive for the unstructured
+    /// deprecations, the structured ones carry their reason in the variant.
+    #[must_use]
+    pub fn has_non_descriptive_note(&self) -> bool {
+        let note = match self {
+            Deprecated::Renamed { note, .. }
+            | Deprecated::Obsoleted { note }
+            | Deprecated::Uncategorized { note } => {
+                if note.trim().is_empty() {
+                    return false;
+                }
+                note
+            }
+            Deprecated::Unspecified { note } => note,
+        };
+        let note = note.trim();
+        note.eq_ignore_ascii_case("true")
+            || note.eq_ignore_ascii_case("false")
+            || note.chars().count() < MIN_DEPRECATION_NOTE_LEN
+    }
+}
+
+/// The minimum number of characters of a descriptive deprecation note.
+pub const MIN_DEPRECATION_NOTE_LEN: usize = 5;
+
 /// Custom deserialization function to handle both old and new formats.
 /// The old format is a string with the deprecation message.
 /// The new format is a map with the action (renamed or removed) and optionally a note. When the
@@ -121,6 +175,10 @@ where
                 Some("uncategorized") => Ok(Deprecated::Uncategorized {
                     note: note.unwrap_or_else(|| "Uncategorized.".to_owned()),
                 }),
+                // The serialized form of the old format.
+                Some("unspecified") => Ok(Deprecated::Unspecified {
+                    note: note.unwrap_or_default(),
+                }),
                 _ => Err(de::Error::missing_field("action")),
             }
         }
@@ -213,10 +271,13 @@ mod tests {
     reason: renamed
     renamed_to: foo.unique_id
     note: Replaced by a new attribute `foo.unique_id`.
+- deprecated:
+    reason: unspecified
+    note: Replaced by `foo.unique_id`.
 "#;
 
         let items: Vec<Item> = serde_yaml::from_str(yaml_data).unwrap();
-        assert_eq!(items.len(), 5);
+        assert_eq!(items.len(), 6);
         assert_eq!(
             items[0].deprecated,
             Some(Deprecated::Unspecified {
@@ -249,5 +310,35 @@ mod tests {
                 note: "Replaced by a new attribute `foo.unique_id`.".to_owned()
             })
         );
+        assert_eq!(
+            items[5].deprecated,
+            Some(Deprecated::Unspecified {
+                note: "Replaced by `foo.unique_id`.".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_replacement() {
+        let renamed = Deprecated::Renamed {
+            renamed_to: "foo.unique_id".to_owned(),
+            note: "Replaced by a new attribute `foo.unique_id`.".to_owned(),
+        };
+        assert_eq!(renamed.replacement(), Some("foo.unique_id"));
+
+        let replaced = Deprecated::Unspecified {
+            note: "Replaced by `jvm.buffer.memory.used`.".to_owned(),
+        };
+        assert_eq!(replaced.replacement(), Some("jvm.buffer.memory.used"));
+
+        let used_instead = Deprecated::Uncategorized {
+            note: "Split in two, use `foo.bar` instead.".to_owned(),
+        };
+        assert_eq!(used_instead.replacement(), Some("foo.bar"));
+
+        let obsoleted = Deprecated::Obsoleted {
+            note: "Obsoleted.".to_owned(),
+        };
+        assert_eq!(obsoleted.replacement(), None);
     }
 }
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A registry-wide report of the deprecated groups and attributes, e.g. to
+//! track the migrations still to be done.
+
+use crate::attribute_catalog::effective_attribute_id;
+use crate::deprecated::Deprecated;
+use crate::provenance::Provenance;
+use crate::registry::SemConvRegistry;
+use serde::Serialize;
+
+/// A deprecated group or attribute.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DeprecationEntry {
+    /// The id of the deprecated group, or of the group declaring the
+    /// deprecated attribute.
+    pub group_id: String,
+    /// The effective id of the deprecated attribute, `None` when the entry is
+    /// about the group itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_id: Option<String>,
+    /// The reason of the deprecation, `None` when only the stability is
+    /// deprecated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecated>,
+    /// The provenance of the group.
+    pub provenance: Provenance,
+}
+
+/// Returns the deprecated groups and attributes of the registry, in the order
+/// of the registry.
+///
+/// An attribute reference is reported when the reference itself deprecates
+/// the attribute.
+#[must_use]
+pub fn deprecations(registry: &SemConvRegistry) -> Vec<DeprecationEntry> {
+    let mut entries = vec![];
+    for group in registry.unresolved_group_with_provenance_iter() {
+        if group.spec.is_deprecated() {
+            entries.push(DeprecationEntry {
+                group_id: group.spec.id.clone(),
+                attribute_id: None,
+                deprecated: group.spec.deprecated.clone(),
+                provenance: group.provenance.clone(),
+            });
+        }
+        for attribute in group
+            .spec
+            .attributes
+            .iter()
+            .filter(|attribute| attribute.is_deprecated())
+        {
+            entries.push(DeprecationEntry {
+                group_id: group.spec.id.clone(),
+                attribute_id: Some(effective_attribute_id(&group.spec.prefix, attribute).0),
+                deprecated: attribute.deprecated().cloned(),
+                provenance: group.provenance.clone(),
+            });
+        }
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deprecations() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "http.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+      - id: http.method
+        type: string
+        brief: The HTTP method.
+        stability: development
+        deprecated:
+          reason: renamed
+          renamed_to: http.request.method
+        examples: [GET]
+  - id: metric.http.server.duration
+    type: metric
+    brief: The HTTP server duration.
+    metric_name: http.server.duration
+    instrument: histogram
+    unit: s
+    stability: stable
+    deprecated:
+      reason: obsoleted
+      note: Not reported anymore.
+    attributes:
+      - ref: http.request.method
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+
+        assert_eq!(
+            deprecations(&registry),
+            vec![
+                DeprecationEntry {
+                    group_id: "registry.http".to_owned(),
+                    attribute_id: Some("http.method".to_owned()),
+                    deprecated: Some(Deprecated::Renamed {
+                        renamed_to: "http.request.method".to_owned(),
+                        note: "Replaced by `http.request.method`.".to_owned(),
+                    }),
+                    provenance: Provenance::new("test", "http.yaml"),
+                },
+                DeprecationEntry {
+                    group_id: "metric.http.server.duration".to_owned(),
+                    attribute_id: None,
+                    deprecated: Some(Deprecated::Obsoleted {
+                        note: "Not reported anymore.".to_owned(),
+                    }),
+                    provenance: Provenance::new("test", "http.yaml"),
+                },
+            ]
+        );
+    }
+}
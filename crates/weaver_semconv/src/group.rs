@@ -5,13 +5,19 @@
 //! A group specification.
 
 use globset::Glob;
+use miette::Diagnostic;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 use crate::any_value::AnyValueSpec;
-use crate::attribute::{AttributeSpec, AttributeType, PrimitiveOrArrayTypeSpec};
+use crate::attribute::{
+    effective_attribute_name, AttributeScope, AttributeSpec, AttributeType,
+    BasicRequirementLevelSpec, PrimitiveOrArrayTypeSpec, RequirementLevel,
+};
+use crate::attribute_catalog::effective_attribute_id;
 use crate::deprecated::Deprecated;
 use crate::group::InstrumentSpec::{Counter, Gauge, Histogram, UpDownCounter};
 use crate::provenance::Provenance;
@@ -41,6 +47,12 @@ pub struct GroupSpec {
     #[serde(default)]
     #[serde(skip_serializing_if = "String::is_empty")]
     pub note: String,
+    /// The id of the group in a previous version of the registry, if the group
+    /// has been renamed. The diff tooling reports a rename instead of a removal
+    /// and an addition.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renamed_from: Option<String>,
     /// Prefix for the attributes for this semantic convention.
     /// It defaults to an empty string.
     #[serde(default)]
@@ -137,19 +149,67 @@ pub struct GroupSpec {
 
 /// Represents a wildcard expression to import one or several groups defined in an imported
 /// registry.
-#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema, PartialEq, Eq)]
 pub struct GroupWildcard(#[schemars(with = "String")] pub Glob);
 
 impl GroupSpec {
     /// Validation logic for the group.
+    #[allow(deprecated)]
     pub(crate) fn validate(&self, path_or_url: &str) -> WResult<(), Error> {
         let mut errors = vec![];
 
+        // Field brief is required and must not be blank.
+        if self.brief.trim().is_empty() {
+            errors.push(Error::InvalidGroup {
+                path_or_url: path_or_url.to_owned(),
+                group_id: self.id.clone(),
+                error: "This group contains an empty brief field.".to_owned(),
+            });
+        } else if has_trailing_whitespace(&self.brief) {
+            errors.push(Error::InvalidGroupWarning {
+                path_or_url: path_or_url.to_owned(),
+                group_id: self.id.clone(),
+                error: "The brief field ends with trailing whitespace.".to_owned(),
+            });
+        }
+
         if !self.prefix.is_empty() {
             errors.push(Error::InvalidGroupUsesPrefix {
                 path_or_url: path_or_url.to_owned(),
                 group_id: self.id.clone(),
             });
+
+            // The prefix is only applied to attributes defined in this group.
+            if !self
+                .attributes
+                .iter()
+                .any(|attr| matches!(attr, AttributeSpec::Id { .. }))
+            {
+                errors.push(Error::InvalidGroupUnusedPrefix {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: self.id.clone(),
+                });
+            }
+
+            // The prefix and the attribute ids must not collide into an empty
+            // name segment, e.g. `http.` + `.method`.
+            for (index, attribute) in self.attributes.iter().enumerate() {
+                if let AttributeSpec::Id { id, .. } = attribute {
+                    let name = effective_attribute_name(&self.prefix, id);
+                    if name.split('.').any(str::is_empty) {
+                        errors.push(Error::InvalidAttribute {
+                            path_or_url: path_or_url.to_owned(),
+                            group_id: self.id.clone(),
+                            attribute_id: id.clone(),
+                            error: format!(
+                                "The effective name `{name}` of this attribute (prefix `{}` + id `{id}`) contains an empty segment.",
+                                self.prefix
+                            ),
+                            path: format!("/attributes/{index}/id").into(),
+                        });
+                    }
+                }
+            }
         }
 
         // Field stability is required for all group types except attribute group.
@@ -244,6 +304,28 @@ impl GroupSpec {
                     error: "This group contains an event type but the name is not set and no prefix is defined.".to_owned(),
                 });
             }
+            if let Some(name) = self.name.as_ref().filter(|name| !is_dotted_name(name)) {
+                errors.push(Error::InvalidGroup {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: self.id.clone(),
+                    error: format!(
+                        "The event name `{name}` is not made of dot-separated lowercase segments (e.g. `rpc.message`)."
+                    ),
+                });
+            }
+            if let Some(name) = self.name.as_ref().filter(|_| !self.prefix.is_empty()) {
+                if *name != self.prefix {
+                    // The name takes precedence, the prefix only derives it when missing.
+                    errors.push(Error::InvalidGroupWarning {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: self.id.clone(),
+                        error: format!(
+                            "This event group sets both the name `{name}` and the prefix `{}`, only the name is used as the event name.",
+                            self.prefix
+                        ),
+                    });
+                }
+            }
 
             validate_any_value(&mut errors, self.body.as_ref(), &self.id, path_or_url);
 
@@ -267,6 +349,45 @@ impl GroupSpec {
             });
         }
 
+        // Fields metric_name, instrument and unit are only valid if type is metric.
+        if let Some(group_type) = match self.r#type {
+            GroupType::AttributeGroup => Some("attribute_group"),
+            GroupType::Span => Some("span"),
+            GroupType::Event => Some("event"),
+            GroupType::Metric => None,
+            GroupType::MetricGroup => Some("metric_group"),
+            GroupType::Entity => Some("entity (resource)"),
+            GroupType::Scope => Some("scope"),
+            GroupType::Undefined => Some("undefined"),
+        } {
+            for (field, is_set) in [
+                ("metric_name", self.metric_name.is_some()),
+                ("instrument", self.instrument.is_some()),
+                ("unit", self.unit.is_some()),
+            ] {
+                if is_set {
+                    errors.push(Error::InvalidGroup {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: self.id.clone(),
+                        error: format!(
+                            "This group contains the {field} field but the type is set to {group_type}."
+                        ),
+                    });
+                }
+            }
+        }
+
+        // Attribute groups don't have any signal-specific fields, the fields
+        // span_kind, events, metric_name, instrument and unit are checked above.
+        if self.r#type == GroupType::AttributeGroup && self.name.is_some() {
+            errors.push(Error::InvalidGroup {
+                path_or_url: path_or_url.to_owned(),
+                group_id: self.id.clone(),
+                error: "This group contains the name field but the type is set to attribute_group."
+                    .to_owned(),
+            });
+        }
+
         // Fields metric_name, instrument and unit are required if type is metric.
         if self.r#type == GroupType::Metric {
             if self.metric_name.is_none() {
@@ -285,12 +406,22 @@ impl GroupSpec {
                         .to_owned(),
                 });
             }
-            if self.unit.is_none() {
-                errors.push(Error::InvalidMetric {
+            match self.unit.as_deref() {
+                None => errors.push(Error::InvalidMetric {
                     path_or_url: path_or_url.to_owned(),
                     group_id: self.id.clone(),
                     error: "This group contains a metric type but the unit is not set.".to_owned(),
-                });
+                }),
+                Some(unit) if is_dimensionless_unit_alias(unit) => {
+                    errors.push(Error::InvalidMetric {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: self.id.clone(),
+                        error: format!(
+                            "The unit `{unit}` is not a valid dimensionless unit, use \"1\" instead."
+                        ),
+                    });
+                }
+                Some(_) => {}
             }
         }
 
@@ -302,14 +433,42 @@ impl GroupSpec {
             });
         }
 
+        // A deprecated group must not be replaced by itself.
+        if let Some(replacement) = self.deprecated.as_ref().and_then(|d| d.replacement()) {
+            let signal_name = self.metric_name.as_deref().or(self.name.as_deref());
+            if replacement == self.id || Some(replacement) == signal_name {
+                errors.push(Error::InvalidGroup {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: self.id.clone(),
+                    error: format!(
+                        "This group is deprecated in favor of `{replacement}` which is the group itself."
+                    ),
+                });
+            }
+        }
+
+        if self
+            .deprecated
+            .as_ref()
+            .is_some_and(Deprecated::has_non_descriptive_note)
+        {
+            errors.push(Error::InvalidGroup {
+                path_or_url: path_or_url.to_owned(),
+                group_id: self.id.clone(),
+                error: "The deprecation note of this group doesn't explain why it is deprecated or what to use instead.".to_owned(),
+            });
+        }
+
         // Validates the attributes.
-        for attribute in &self.attributes {
+        for (index, attribute) in self.attributes.iter().enumerate() {
+            let attribute_path = format!("/attributes/{index}");
             match attribute {
                 AttributeSpec::Id {
                     brief,
                     deprecated,
                     stability,
                     r#type,
+                    requirement_level,
                     ..
                 } => {
                     if brief.is_none() && deprecated.is_none() {
@@ -318,15 +477,39 @@ impl GroupSpec {
                             group_id: self.id.clone(),
                             attribute_id: attribute.id(),
                             error: "This attribute is not deprecated and does not contain a brief field.".to_owned(),
+                            path: attribute_path.as_str().into(),
                         });
                     }
 
+                    if let Some(brief) = brief {
+                        if brief.trim().is_empty() {
+                            if deprecated.is_none() {
+                                errors.push(Error::InvalidAttribute {
+                                    path_or_url: path_or_url.to_owned(),
+                                    group_id: self.id.clone(),
+                                    attribute_id: attribute.id(),
+                                    error: "This attribute is not deprecated and contains an empty brief field.".to_owned(),
+                                    path: format!("{attribute_path}/brief").into(),
+                                });
+                            }
+                        } else if has_trailing_whitespace(brief) {
+                            errors.push(Error::InvalidAttributeWarning {
+                                path_or_url: path_or_url.to_owned(),
+                                group_id: self.id.clone(),
+                                attribute_id: attribute.id(),
+                                error: "The brief field ends with trailing whitespace.".to_owned(),
+                                path: format!("{attribute_path}/brief").into(),
+                            });
+                        }
+                    }
+
                     if stability.is_none() {
                         errors.push(Error::InvalidAttributeWarning {
                             path_or_url: path_or_url.to_owned(),
                             group_id: self.id.clone(),
                             attribute_id: attribute.id(),
                             error: "Missing stability field.".to_owned(),
+                            path: attribute_path.as_str().into(),
                         });
                     } else if stability.clone() == Some(Stability::Deprecated) {
                         errors.push(Error::InvalidAttributeWarning {
@@ -334,11 +517,12 @@ impl GroupSpec {
                             group_id: self.id.clone(),
                             attribute_id: attribute.id(),
                             error: "Attribute stability is set to 'deprecated' which is no longer supported.".to_owned(),
+                            path: format!("{attribute_path}/stability").into(),
                         });
                     }
 
                     if let AttributeType::Enum { members, .. } = r#type {
-                        for member in members {
+                        for (member_index, member) in members.iter().enumerate() {
                             if member.stability.is_none() {
                                 errors.push(Error::InvalidAttributeWarning {
                                     path_or_url: path_or_url.to_owned(),
@@ -348,6 +532,8 @@ impl GroupSpec {
                                         "Missing stability field on enum member {}.",
                                         member.id
                                     ),
+                                    path: format!("{attribute_path}/type/members/{member_index}")
+                                        .into(),
                                 });
                             } else if member.stability == Some(Stability::Deprecated) {
                                 errors.push(Error::InvalidAttributeWarning {
@@ -358,6 +544,45 @@ impl GroupSpec {
                                         "Member {} stability is set to 'deprecated' which is no longer supported.",
                                         member.id
                                     ),
+                                    path: format!("{attribute_path}/type/members/{member_index}/stability").into(),
+                                });
+                            }
+
+                            if member.deprecated.as_ref().and_then(|d| d.replacement())
+                                == Some(member.id.as_str())
+                            {
+                                errors.push(Error::InvalidAttribute {
+                                    path_or_url: path_or_url.to_owned(),
+                                    group_id: self.id.clone(),
+                                    attribute_id: attribute.id(),
+                                    error: format!(
+                                        "Enum member {} is deprecated in favor of itself.",
+                                        member.id
+                                    ),
+                                    path: format!(
+                                        "{attribute_path}/type/members/{member_index}/deprecated"
+                                    )
+                                    .into(),
+                                });
+                            }
+
+                            if member
+                                .deprecated
+                                .as_ref()
+                                .is_some_and(Deprecated::has_non_descriptive_note)
+                            {
+                                errors.push(Error::InvalidAttribute {
+                                    path_or_url: path_or_url.to_owned(),
+                                    group_id: self.id.clone(),
+                                    attribute_id: attribute.id(),
+                                    error: format!(
+                                        "The deprecation note of enum member {} doesn't explain why it is deprecated or what to use instead.",
+                                        member.id
+                                    ),
+                                    path: format!(
+                                        "{attribute_path}/type/members/{member_index}/deprecated"
+                                    )
+                                    .into(),
                                 });
                             }
 
@@ -374,6 +599,34 @@ impl GroupSpec {
                         }
                     }
 
+                    // Implementations must not be required to set what is being removed.
+                    if deprecated.is_some()
+                        && *requirement_level
+                            == RequirementLevel::Basic(BasicRequirementLevelSpec::Required)
+                    {
+                        errors.push(Error::InvalidAttribute {
+                            path_or_url: path_or_url.to_owned(),
+                            group_id: self.id.clone(),
+                            attribute_id: attribute.id(),
+                            error: "This attribute is deprecated but its requirement level is `required`.".to_owned(),
+                            path: format!("{attribute_path}/requirement_level").into(),
+                        });
+                    }
+
+                    if let Some(replacement) = deprecated.as_ref().and_then(|d| d.replacement()) {
+                        if replacement == attribute.id() {
+                            errors.push(Error::InvalidAttribute {
+                                path_or_url: path_or_url.to_owned(),
+                                group_id: self.id.clone(),
+                                attribute_id: attribute.id(),
+                                error: format!(
+                                    "This attribute is deprecated in favor of `{replacement}` which is the attribute itself."
+                                ),
+                                path: format!("{attribute_path}/deprecated").into(),
+                            });
+                        }
+                    }
+
                     if matches!(deprecated, Some(Deprecated::Unspecified { .. })) {
                         errors.push(Error::UnstructuredDeprecatedProperty {
                             path_or_url: path_or_url.to_owned(),
@@ -386,6 +639,113 @@ impl GroupSpec {
                 AttributeSpec::Ref { .. } => {}
             }
 
+            // The examples of a note illustrate the note, they can't replace it.
+            let (note, examples_note) = match attribute {
+                AttributeSpec::Id {
+                    note,
+                    examples_note,
+                    ..
+                } => (Some(note.as_str()), examples_note),
+                AttributeSpec::Ref {
+                    note,
+                    examples_note,
+                    ..
+                } => (note.as_deref(), examples_note),
+            };
+            if examples_note
+                .as_ref()
+                .is_some_and(|examples| !examples.trim().is_empty())
+                && !note.is_some_and(|note| !note.trim().is_empty())
+            {
+                errors.push(Error::InvalidAttribute {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: self.id.clone(),
+                    attribute_id: attribute.id(),
+                    error: "This attribute defines an examples_note but no note.".to_owned(),
+                    path: format!("{attribute_path}/examples_note").into(),
+                });
+            }
+
+            // A custom requirement level must describe the requirement.
+            let requirement_level = match attribute {
+                AttributeSpec::Id {
+                    requirement_level, ..
+                } => Some(requirement_level),
+                AttributeSpec::Ref {
+                    requirement_level, ..
+                } => requirement_level.as_ref(),
+            };
+            if requirement_level
+                .and_then(|rl| rl.text())
+                .is_some_and(|text| text.trim().is_empty())
+            {
+                errors.push(Error::InvalidAttribute {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: self.id.clone(),
+                    attribute_id: attribute.id(),
+                    error: "The requirement level contains an empty description.".to_owned(),
+                    path: format!("{attribute_path}/requirement_level").into(),
+                });
+            }
+
+            // Field sampling_relevant is only valid on span attributes (attribute
+            // groups are allowed as they are shared with spans).
+            let sampling_relevant = match attribute {
+                AttributeSpec::Id {
+                    sampling_relevant, ..
+                }
+                | AttributeSpec::Ref {
+                    sampling_relevant, ..
+                } => sampling_relevant,
+            };
+            if sampling_relevant.is_some()
+                && !matches!(self.r#type, GroupType::Span | GroupType::AttributeGroup)
+            {
+                errors.push(Error::InvalidAttribute {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: self.id.clone(),
+                    attribute_id: attribute.id(),
+                    error: "This attribute contains a sampling_relevant field but the group type is not set to span.".to_owned(),
+                    path: format!("{attribute_path}/sampling_relevant").into(),
+                });
+            }
+
+            let deprecated = match attribute {
+                AttributeSpec::Id { deprecated, .. } | AttributeSpec::Ref { deprecated, .. } => {
+                    deprecated
+                }
+            };
+            if deprecated
+                .as_ref()
+                .is_some_and(Deprecated::has_non_descriptive_note)
+            {
+                errors.push(Error::InvalidAttribute {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: self.id.clone(),
+                    attribute_id: attribute.id(),
+                    error: "The deprecation note of this attribute doesn't explain why it is deprecated or what to use instead.".to_owned(),
+                    path: format!("{attribute_path}/deprecated").into(),
+                });
+            }
+
+            // A metric attribute embedding the metric name doesn't add any dimension.
+            if let (GroupType::Metric, Some(metric_name)) = (&self.r#type, &self.metric_name) {
+                let attribute_id = attribute.id();
+                if attribute_id.len() > metric_name.len()
+                    && attribute_id.contains(metric_name.as_str())
+                {
+                    errors.push(Error::InvalidAttributeWarning {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: self.id.clone(),
+                        attribute_id,
+                        error: format!(
+                            "This attribute duplicates the name of the metric `{metric_name}`."
+                        ),
+                        path: attribute_path.as_str().into(),
+                    });
+                }
+            }
+
             // Examples are required only for string and string array attributes.
             // When examples are set, the attribute type and examples type must match.
             if let AttributeSpec::Id {
@@ -398,7 +758,10 @@ impl GroupSpec {
                 if let Some(examples) = examples {
                     match examples.validate(r#type, &self.id, id, path_or_url) {
                         WResult::Ok(_) => {}
-                        WResult::OkWithNFEs(_, errs) => errors.extend(errs),
+                        WResult::OkWithNFEs(_, errs) => errors.extend(
+                            errs.into_iter()
+                                .map(|e| e.with_path_prefix(&attribute_path)),
+                        ),
                         WResult::FatalErr(err) => return WResult::FatalErr(err),
                     }
                 } else {
@@ -414,6 +777,7 @@ impl GroupSpec {
                             error:
                                 "This attribute is a string but it does not contain any examples."
                                     .to_owned(),
+                            path: attribute_path.as_str().into(),
                         });
                     }
 
@@ -427,12 +791,46 @@ impl GroupSpec {
                             error:
                             "This attribute is a string array but it does not contain any examples."
                                 .to_owned(),
+                            path: attribute_path.as_str().into(),
+                        });
+                    }
+
+                    // Open enums must have examples showing a custom value, the
+                    // members of closed enums are their own examples.
+                    if r#type.is_open_enum() {
+                        errors.push(Error::InvalidExampleWarning {
+                            path_or_url: path_or_url.to_owned(),
+                            group_id: self.id.clone(),
+                            attribute_id: attribute.id(),
+                            error:
+                                "This attribute is an open enum but it does not contain any examples."
+                                    .to_owned(),
+                            path: attribute_path.as_str().into(),
                         });
                     }
                 }
             }
         }
 
+        // Field scope on attributes is only valid if type is span.
+        if self.r#type != GroupType::Span && self.r#type != GroupType::Undefined {
+            for (index, attribute) in self.attributes.iter().enumerate() {
+                if matches!(
+                    attribute,
+                    AttributeSpec::Ref { scope: Some(_), .. }
+                        | AttributeSpec::Id { scope: Some(_), .. }
+                ) {
+                    errors.push(Error::InvalidAttribute {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: self.id.clone(),
+                        attribute_id: attribute.id(),
+                        error: "This attribute contains a scope field but the group type is not set to span.".to_owned(),
+                        path: format!("/attributes/{index}/scope").into(),
+                    });
+                }
+            }
+        }
+
         // Ensure entity associations show up on the right groups.
         if !self.entity_associations.is_empty() {
             match &self.r#type {
@@ -448,72 +846,468 @@ impl GroupSpec {
 
         WResult::with_non_fatal_errors((), errors)
     }
-}
 
-fn validate_duplicate_attribute_ref(
-    errors: &mut Vec<Error>,
-    attributes: &[AttributeSpec],
-    group_id: &str,
-    path_or_url: &str,
-) {
-    let mut seen = HashSet::new();
-    for a in attributes.iter() {
-        if let AttributeSpec::Ref { r#ref, .. } = a {
-            if !seen.insert(r#ref.to_owned()) {
-                errors.push(Error::InvalidGroupDuplicateAttributeRef {
-                    path_or_url: path_or_url.to_owned(),
-                    group_id: group_id.to_owned(),
-                    attribute_ref: r#ref.to_owned(),
-                });
+    /// Validates the group according to the provided options.
+    ///
+    /// In lenient mode (the default), only the findings with an `Error` severity
+    /// make the validation fail. In strict mode, the warnings are reported as
+    /// errors as well.
+    pub fn validate_with_options(
+        &self,
+        path_or_url: &str,
+        options: &ValidationOptions,
+    ) -> Result<(), Error> {
+        self.validate_for_options(path_or_url, options)
+            .ignore(|e| !options.is_reported(e))
+            .into_result_failing_non_fatal()
+    }
+
+    /// Validates the group according to the provided options, along with the
+    /// given custom rules (e.g. house rules of an organization).
+    ///
+    /// The findings of the custom rules are merged with the built-in ones and
+    /// filtered the same way according to their severity.
+    pub fn validate_with_rules(
+        &self,
+        path_or_url: &str,
+        options: &ValidationOptions,
+        rules: &[Box<dyn GroupRule>],
+    ) -> Result<(), Error> {
+        let result = self.validate_for_options(path_or_url, options);
+        let custom_errors: Vec<_> = rules
+            .iter()
+            .flat_map(|rule| rule.check(self, path_or_url))
+            .collect();
+        match result {
+            WResult::Ok(()) => WResult::with_non_fatal_errors((), custom_errors),
+            WResult::OkWithNFEs((), mut errors) => {
+                errors.extend(custom_errors);
+                WResult::OkWithNFEs((), errors)
             }
+            WResult::FatalErr(error) => WResult::FatalErr(error),
         }
+        .ignore(|e| !options.is_reported(e))
+        .into_result_failing_non_fatal()
     }
-}
 
-fn validate_any_value_examples(
-    errors: &mut Vec<Error>,
-    any_value: Option<&AnyValueSpec>,
-    group_id: &str,
-    path_or_url: &str,
-) -> WResult<(), Error> {
-    if let Some(value) = any_value {
-        if let Some(examples) = &value.common().examples {
-            match examples.validate_any_value(value, group_id, path_or_url) {
-                WResult::Ok(_) => {}
-                WResult::OkWithNFEs(_, errs) => errors.extend(errs),
-                WResult::FatalErr(err) => return WResult::FatalErr(err),
+    /// Validates the group, including the rules enabled by the provided options.
+    fn validate_for_options(
+        &self,
+        path_or_url: &str,
+        options: &ValidationOptions,
+    ) -> WResult<(), Error> {
+        let mut errors = match self.validate(path_or_url) {
+            WResult::Ok(_) => vec![],
+            WResult::OkWithNFEs(_, errors) => errors,
+            WResult::FatalErr(error) => return WResult::FatalErr(error),
+        };
+
+        // Ids are used to derive file names and must be lowercase dotted names.
+        if options.check_group_ids && !is_dotted_name(&self.id) {
+            errors.push(Error::InvalidGroup {
+                path_or_url: path_or_url.to_owned(),
+                group_id: self.id.clone(),
+                error: format!(
+                    "The id `{}` is not made of dot-separated lowercase segments (e.g. `metric.http.server.request.duration`).",
+                    self.id
+                ),
+            });
+        }
+
+        if let (GroupType::Metric, Some(metric_name)) = (&self.r#type, &self.metric_name) {
+            // Counters measure a total, a suffix repeating it is redundant.
+            let discouraged_suffixes = match self.instrument {
+                Some(Counter) if options.check_counter_suffixes => DISCOURAGED_COUNTER_SUFFIXES,
+                Some(UpDownCounter) if options.check_counter_suffixes => {
+                    DISCOURAGED_UPDOWNCOUNTER_SUFFIXES
+                }
+                _ => &[],
+            };
+            if let Some(suffix) = discouraged_suffixes
+                .iter()
+                .find(|suffix| metric_name.ends_with(*suffix))
+            {
+                errors.push(Error::InvalidMetricWarning {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: self.id.clone(),
+                    error: format!(
+                        "The metric name `{metric_name}` of this {} ends with the discouraged suffix `{suffix}`.",
+                        self.instrument.as_ref().map(ToString::to_string).unwrap_or_default()
+                    ),
+                });
             }
-        } else {
-            match value {
-                AnyValueSpec::String { .. } | AnyValueSpec::Strings { .. } => {
-                    errors.push(Error::InvalidAnyValueExampleError {
+
+            // Durations must be measured with a unit of time.
+            if options.check_duration_units
+                && self.instrument == Some(Histogram)
+                && metric_name.ends_with(".duration")
+            {
+                let unit = self.unit.as_deref().unwrap_or_default();
+                if !TIME_UNITS.contains(&unit) {
+                    errors.push(Error::InvalidMetricWarning {
                         path_or_url: path_or_url.to_owned(),
-                        group_id: group_id.to_owned(),
-                        value_id: value.id(),
+                        group_id: self.id.clone(),
                         error: format!(
-                            "This value is a {} but it does not contain any examples.",
-                            if let AnyValueSpec::String { .. } = value {
-                                "string"
-                            } else {
-                                "string array"
-                            }
+                            "The unit `{unit}` of the duration histogram `{metric_name}` is not a unit of time."
+                        ),
+                    });
+                }
+            }
+
+            // Gauges record an instantaneous value, a rate is better modeled
+            // with a counter.
+            if options.check_gauge_units && self.instrument == Some(Gauge) {
+                if let Some(unit) = self.unit.as_deref().filter(|unit| unit.contains('/')) {
+                    errors.push(Error::InvalidMetricWarning {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: self.id.clone(),
+                        error: format!(
+                            "The unit `{unit}` of the gauge `{metric_name}` is a rate, consider using a counter instead."
                         ),
                     });
                 }
-                _ => {}
             }
         }
 
-        if let AnyValueSpec::Map { fields, .. } = value {
-            for field in fields {
-                if let WResult::FatalErr(err) =
-                    validate_any_value_examples(errors, Some(field), group_id, path_or_url)
+        // Enum attributes with many members create high cardinality metrics.
+        if let (GroupType::Metric, Some(max_members)) =
+            (&self.r#type, options.max_metric_enum_members)
+        {
+            for (index, attribute) in self.attributes.iter().enumerate() {
+                if let AttributeSpec::Id {
+                    id,
+                    r#type: AttributeType::Enum { members },
+                    ..
+                } = attribute
                 {
-                    return WResult::FatalErr(err);
+                    if members.len() > max_members {
+                        errors.push(Error::InvalidAttributeWarning {
+                            path_or_url: path_or_url.to_owned(),
+                            group_id: self.id.clone(),
+                            attribute_id: id.clone(),
+                            error: format!(
+                                "This enum attribute defines {} members, more than the maximum of {max_members} allowed on metric attributes.",
+                                members.len()
+                            ),
+                            path: format!("/attributes/{index}/type/members").into(),
+                        });
+                    }
                 }
             }
         }
-    }
+
+        // Long lists of examples blow up the generated documentation.
+        if let Some(max_examples) = options.max_examples {
+            for (index, attribute) in self.attributes.iter().enumerate() {
+                let (AttributeSpec::Ref {
+                    examples: Some(examples),
+                    ..
+                }
+                | AttributeSpec::Id {
+                    examples: Some(examples),
+                    ..
+                }) = attribute
+                else {
+                    continue;
+                };
+                if examples.is_list() && examples.as_vec().len() > max_examples {
+                    errors.push(Error::InvalidAttributeWarning {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: self.id.clone(),
+                        attribute_id: attribute.id(),
+                        error: format!(
+                            "This attribute defines {} examples, more than the maximum of {max_examples} allowed.",
+                            examples.as_vec().len()
+                        ),
+                        path: format!("/attributes/{index}/examples").into(),
+                    });
+                }
+            }
+        }
+
+        // Tags must belong to the configured taxonomy.
+        if !options.allowed_tags.is_empty() {
+            for (index, attribute) in self.attributes.iter().enumerate() {
+                if let Some(tag) = attribute.tag() {
+                    if !options.allowed_tags.contains(&tag) {
+                        errors.push(Error::InvalidAttributeWarning {
+                            path_or_url: path_or_url.to_owned(),
+                            group_id: self.id.clone(),
+                            attribute_id: attribute.id(),
+                            error: format!(
+                                "The tag `{tag}` is not one of the allowed tags: {}.",
+                                options
+                                    .allowed_tags
+                                    .iter()
+                                    .map(|tag| format!("`{tag}`"))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                            path: format!("/attributes/{index}/tag").into(),
+                        });
+                    }
+                }
+            }
+        }
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Checks that serializing this group to YAML and parsing it back produces
+    /// the same group.
+    ///
+    /// The `include_groups` and `visibility` fields are never serialized, they
+    /// are excluded from the comparison.
+    pub fn assert_round_trip(&self) -> Result<(), Error> {
+        let round_trip_error =
+            |error: String, source: Option<serde_yaml::Error>| Error::DeserializationError {
+                path_or_url: format!("<round trip of group `{}`>", self.id),
+                error,
+                source: source.map(Into::into),
+            };
+        let yaml =
+            serde_yaml::to_string(self).map_err(|e| round_trip_error(e.to_string(), Some(e)))?;
+        let parsed: GroupSpec = serde_yaml::from_str(&yaml)
+            .map_err(|e| round_trip_error(format!("{e}, serialized group:\n{yaml}"), Some(e)))?;
+        let expected = GroupSpec {
+            include_groups: vec![],
+            visibility: None,
+            ..self.clone()
+        };
+        if parsed == expected {
+            Ok(())
+        } else {
+            Err(round_trip_error(format!(
+                "The group changed after a round trip, serialized group:\n{yaml}\nexpected: {expected:?}\nparsed: {parsed:?}"
+            ), None))
+        }
+    }
+
+    /// Returns a key identifying the content of this group, two structurally
+    /// identical groups share the same key.
+    ///
+    /// The key is the JSON serialization of the group with the object keys
+    /// sorted, so the order of the fields (e.g. of the annotations) doesn't
+    /// matter. The `include_groups` and `visibility` fields are never
+    /// serialized, they don't contribute to the key.
+    #[must_use]
+    pub fn content_key(&self) -> String {
+        // Annotations with non-string mapping keys can't be converted to JSON,
+        // fall back on the YAML serialization in this case.
+        serde_json::to_value(self)
+            .map(|value| value.to_string())
+            .or_else(|_| serde_yaml::to_string(self))
+            .unwrap_or_default()
+    }
+
+    /// Returns the signature of this metric group, i.e. its metric name,
+    /// instrument, unit and the effective ids of its attributes, or `None` if
+    /// the group is not a metric or has no metric name.
+    ///
+    /// Only the attributes declared by the group itself are considered, the
+    /// attributes inherited through `extends` are not resolved yet.
+    #[must_use]
+    pub fn metric_signature(&self) -> Option<MetricSignature> {
+        if self.r#type != GroupType::Metric {
+            return None;
+        }
+        Some(MetricSignature {
+            metric_name: self.metric_name.clone()?,
+            instrument: self.instrument.clone(),
+            unit: self.unit.clone(),
+            attribute_ids: self
+                .attributes
+                .iter()
+                .map(|attribute| effective_attribute_id(&self.prefix, attribute).0)
+                .collect(),
+        })
+    }
+
+    /// Returns true if the group is deprecated, i.e. it has a `deprecated`
+    /// field or a deprecated stability.
+    #[allow(deprecated)]
+    #[must_use]
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.is_some() || self.stability == Some(Stability::Deprecated)
+    }
+
+    /// Returns the attributes of this group categorized by the part of the span
+    /// they are recorded on (span, events or links).
+    ///
+    /// Attributes without an explicit scope are considered span attributes.
+    #[must_use]
+    pub fn attributes_by_scope(&self) -> AttributeScopes<'_> {
+        let mut scopes = AttributeScopes::default();
+        for attribute in &self.attributes {
+            match attribute.scope() {
+                AttributeScope::Span => scopes.span.push(attribute),
+                AttributeScope::Event => scopes.event.push(attribute),
+                AttributeScope::Link => scopes.link.push(attribute),
+            }
+        }
+        scopes
+    }
+
+    /// Rewrites the common misspellings of the dimensionless unit (e.g. `""`,
+    /// `none` or `count`) to `1`.
+    pub fn normalize_unit(&mut self) {
+        if let Some(unit) = &mut self.unit {
+            if is_dimensionless_unit_alias(unit) {
+                "1".clone_into(unit);
+            }
+        }
+    }
+
+    /// Removes the trailing whitespace from the group brief and from the briefs
+    /// of the attributes defined in this group.
+    pub(crate) fn trim_briefs(&mut self) {
+        self.brief = trim_trailing_whitespace(&self.brief);
+        for attribute in &mut self.attributes {
+            if let AttributeSpec::Id {
+                brief: Some(brief), ..
+            } = attribute
+            {
+                *brief = trim_trailing_whitespace(brief);
+            }
+        }
+    }
+}
+
+/// Validates each group and reports which rule categories it passes.
+///
+/// The findings are filtered according to the provided options, so in lenient
+/// mode a group with only warnings passes every category.
+#[must_use]
+pub fn validation_matrix(
+    groups: &[GroupSpec],
+    opts: &ValidationOptions,
+) -> Vec<GroupValidationRow> {
+    groups
+        .iter()
+        .map(|group| {
+            let errors = match group.validate_for_options(&group.id, opts) {
+                WResult::Ok(_) => vec![],
+                WResult::OkWithNFEs(_, errors) => errors,
+                WResult::FatalErr(error) => vec![error],
+            };
+            let mut row = GroupValidationRow {
+                group_id: group.id.clone(),
+                naming: true,
+                stability: true,
+                metrics: true,
+                attributes: true,
+                structure: true,
+            };
+            for error in errors.iter().filter(|e| opts.is_reported(e)) {
+                match ValidationCategory::of(error) {
+                    ValidationCategory::Naming => row.naming = false,
+                    ValidationCategory::Stability => row.stability = false,
+                    ValidationCategory::Metrics => row.metrics = false,
+                    ValidationCategory::Attributes => row.attributes = false,
+                    ValidationCategory::Structure => row.structure = false,
+                }
+            }
+            row
+        })
+        .collect()
+}
+
+/// Returns true if the unit is a known misspelling of the dimensionless unit `1`.
+fn is_dimensionless_unit_alias(unit: &str) -> bool {
+    let unit = unit.trim();
+    unit.is_empty() || unit.eq_ignore_ascii_case("none") || unit.eq_ignore_ascii_case("count")
+}
+
+/// Returns true if the name is made of non-empty dot-separated segments of
+/// lowercase ASCII letters, digits and underscores.
+fn is_dotted_name(name: &str) -> bool {
+    name.split('.').all(|segment| {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    })
+}
+
+/// Returns true if the text ends with whitespace, ignoring the single line break
+/// that terminates YAML block scalars (e.g. `brief: >`).
+fn has_trailing_whitespace(text: &str) -> bool {
+    trim_trailing_whitespace(text).len() != text.len()
+}
+
+/// Removes the trailing whitespace of the text but preserves the single line break
+/// that terminates YAML block scalars (e.g. `brief: >`).
+fn trim_trailing_whitespace(text: &str) -> String {
+    match text.strip_suffix('\n') {
+        Some(text) => format!("{}\n", text.trim_end()),
+        None => text.trim_end().to_owned(),
+    }
+}
+
+fn validate_duplicate_attribute_ref(
+    errors: &mut Vec<Error>,
+    attributes: &[AttributeSpec],
+    group_id: &str,
+    path_or_url: &str,
+) {
+    let mut seen = HashSet::new();
+    for a in attributes.iter() {
+        if let AttributeSpec::Ref { r#ref, .. } = a {
+            if !seen.insert(r#ref.to_owned()) {
+                errors.push(Error::InvalidGroupDuplicateAttributeRef {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: group_id.to_owned(),
+                    attribute_ref: r#ref.to_owned(),
+                });
+            }
+        }
+    }
+}
+
+fn validate_any_value_examples(
+    errors: &mut Vec<Error>,
+    any_value: Option<&AnyValueSpec>,
+    group_id: &str,
+    path_or_url: &str,
+) -> WResult<(), Error> {
+    if let Some(value) = any_value {
+        if let Some(examples) = &value.common().examples {
+            match examples.validate_any_value(value, group_id, path_or_url) {
+                WResult::Ok(_) => {}
+                WResult::OkWithNFEs(_, errs) => errors.extend(errs),
+                WResult::FatalErr(err) => return WResult::FatalErr(err),
+            }
+        } else {
+            match value {
+                AnyValueSpec::String { .. } | AnyValueSpec::Strings { .. } => {
+                    errors.push(Error::InvalidAnyValueExampleError {
+                        path_or_url: path_or_url.to_owned(),
+                        group_id: group_id.to_owned(),
+                        value_id: value.id(),
+                        error: format!(
+                            "This value is a {} but it does not contain any examples.",
+                            if let AnyValueSpec::String { .. } = value {
+                                "string"
+                            } else {
+                                "string array"
+                            }
+                        ),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        if let AnyValueSpec::Map { fields, .. } = value {
+            for field in fields {
+                if let WResult::FatalErr(err) =
+                    validate_any_value_examples(errors, Some(field), group_id, path_or_url)
+                {
+                    return WResult::FatalErr(err);
+                }
+            }
+        }
+    }
 
     WResult::Ok(())
 }
@@ -525,6 +1319,15 @@ fn validate_any_value(
     path_or_url: &str,
 ) {
     if let Some(value) = any_value {
+        if value.common().id.trim().is_empty() {
+            errors.push(Error::InvalidAnyValue {
+                path_or_url: path_or_url.to_owned(),
+                group_id: group_id.to_owned(),
+                value_id: value.id(),
+                error: "Missing id field.".to_owned(),
+            });
+        }
+
         if value.common().stability.is_none() {
             errors.push(Error::InvalidAnyValue {
                 path_or_url: path_or_url.to_owned(),
@@ -550,7 +1353,7 @@ fn validate_any_value(
                     }
                 }
             }
-            AnyValueSpec::Map { fields, .. } => {
+            AnyValueSpec::Map { fields, .. } | AnyValueSpec::Maps { fields, .. } => {
                 for field in fields {
                     validate_any_value(errors, Some(field), group_id, path_or_url);
                 }
@@ -589,6 +1392,24 @@ pub enum GroupType {
     Undefined,
 }
 
+/// Implements a human readable display for the group type, i.e. the name of
+/// the type as written in the semconv files.
+impl Display for GroupType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GroupType::AttributeGroup => "attribute_group",
+            GroupType::Span => "span",
+            GroupType::Event => "event",
+            GroupType::Metric => "metric",
+            GroupType::MetricGroup => "metric_group",
+            GroupType::Entity => "entity",
+            GroupType::Scope => "scope",
+            GroupType::Undefined => "undefined",
+        };
+        write!(f, "{name}")
+    }
+}
+
 impl Default for GroupType {
     /// Returns the default convention type.
     /// The Undefined type is used to indicate that the type is not set.
@@ -643,6 +1464,181 @@ impl Display for InstrumentSpec {
     }
 }
 
+/// Parses an instrument from its human readable representation (the inverse
+/// of the [`Display`] implementation).
+impl FromStr for InstrumentSpec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "updowncounter" => Ok(UpDownCounter),
+            "counter" => Ok(Counter),
+            "gauge" => Ok(Gauge),
+            "histogram" => Ok(Histogram),
+            _ => Err(Error::UnknownInstrument {
+                instrument: s.to_owned(),
+            }),
+        }
+    }
+}
+
+/// The attributes of a group categorized by the part of the span they are
+/// recorded on.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AttributeScopes<'a> {
+    /// The attributes recorded on the span itself.
+    pub span: Vec<&'a AttributeSpec>,
+    /// The attributes recorded on the events of the span.
+    pub event: Vec<&'a AttributeSpec>,
+    /// The attributes recorded on the links of the span.
+    pub link: Vec<&'a AttributeSpec>,
+}
+
+/// The signature of a metric, two metric groups sharing a metric name must
+/// share the same signature.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetricSignature {
+    /// The name of the metric.
+    pub metric_name: String,
+    /// The instrument of the metric.
+    pub instrument: Option<InstrumentSpec>,
+    /// The unit of the metric.
+    pub unit: Option<String>,
+    /// The effective ids of the attributes of the metric, sorted.
+    pub attribute_ids: BTreeSet<String>,
+}
+
+impl MetricSignature {
+    /// Returns the names of the parts of the signature (`instrument`, `unit`
+    /// or `attributes`) differing from the other signature.
+    #[must_use]
+    pub fn diverging_parts(&self, other: &MetricSignature) -> Vec<&'static str> {
+        [
+            ("instrument", self.instrument != other.instrument),
+            ("unit", self.unit != other.unit),
+            ("attributes", self.attribute_ids != other.attribute_ids),
+        ]
+        .into_iter()
+        .filter_map(|(part, diverges)| diverges.then_some(part))
+        .collect()
+    }
+}
+
+/// A custom validation rule applied to each group by
+/// [`GroupSpec::validate_with_rules`], in addition to the built-in rules.
+pub trait GroupRule {
+    /// Returns the findings of the rule on the given group, an empty vector
+    /// when the group complies with the rule.
+    fn check(&self, group: &GroupSpec, path_or_url: &str) -> Vec<Error>;
+}
+
+/// Options controlling the validation of a group.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationOptions {
+    /// When set, the findings with a `Warning` severity are promoted to errors
+    /// and make the validation fail.
+    pub strict: bool,
+    /// When set, a warning is reported for each enum attribute of a metric
+    /// group defining more members than this threshold, as each member is a
+    /// distinct value of the metric dimension.
+    pub max_metric_enum_members: Option<usize>,
+    /// When set, a warning is reported for each counter or updowncounter whose
+    /// metric name ends with a discouraged suffix (see
+    /// [`DISCOURAGED_COUNTER_SUFFIXES`]).
+    pub check_counter_suffixes: bool,
+    /// When set, a warning is reported for each histogram whose metric name
+    /// ends with `.duration` but whose unit is not a unit of time.
+    pub check_duration_units: bool,
+    /// When set, a warning is reported for each gauge whose unit is a rate
+    /// (e.g. `By/s`), as the rate is better modeled with a counter.
+    pub check_gauge_units: bool,
+    /// When set, an error is reported for each group whose id is not made of
+    /// dot-separated lowercase segments. Off by default as legacy ids may not
+    /// conform.
+    pub check_group_ids: bool,
+    /// The taxonomy of the attribute tags. When not empty, a warning is
+    /// reported for each attribute whose tag is not in this set.
+    pub allowed_tags: BTreeSet<String>,
+    /// When set, a warning is reported for each attribute whose list of
+    /// examples is longer than this limit, to keep the generated documentation
+    /// readable.
+    pub max_examples: Option<usize>,
+}
+
+/// The metric name suffixes discouraged on counters.
+///
+/// The `.count` suffix is only discouraged on counters as it is the
+/// conventional suffix of the updowncounters counting entities.
+pub const DISCOURAGED_COUNTER_SUFFIXES: &[&str] = &[".total", ".count"];
+
+/// The metric name suffixes discouraged on updowncounters.
+pub const DISCOURAGED_UPDOWNCOUNTER_SUFFIXES: &[&str] = &[".total"];
+
+/// The units of time accepted on the `*.duration` histograms.
+const TIME_UNITS: &[&str] = &["d", "h", "min", "s", "ms", "us", "ns"];
+
+impl ValidationOptions {
+    /// Returns true if the finding must be reported with these options.
+    fn is_reported(&self, error: &Error) -> bool {
+        self.strict || !matches!(error.severity(), Some(miette::Severity::Warning))
+    }
+}
+
+/// The outcome of the validation of a group per rule category.
+///
+/// Each flag is true when the group passes all the rules of the category.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct GroupValidationRow {
+    /// The id of the validated group.
+    pub group_id: String,
+    /// The rules on the prefix and the name of the group.
+    pub naming: bool,
+    /// The rules on the stability and the deprecation of the group and its attributes.
+    pub stability: bool,
+    /// The rules specific to metric groups.
+    pub metrics: bool,
+    /// The rules on the attributes of the group.
+    pub attributes: bool,
+    /// The rules on the overall shape of the group (type, brief, type-specific fields).
+    pub structure: bool,
+}
+
+/// The rule categories used to build a validation matrix.
+enum ValidationCategory {
+    Naming,
+    Stability,
+    Metrics,
+    Attributes,
+    Structure,
+}
+
+impl ValidationCategory {
+    fn of(error: &Error) -> Self {
+        match error {
+            Error::InvalidGroupUsesPrefix { .. } | Error::InvalidGroupUnusedPrefix { .. } => {
+                ValidationCategory::Naming
+            }
+            Error::InvalidGroupStability { .. } | Error::UnstructuredDeprecatedProperty { .. } => {
+                ValidationCategory::Stability
+            }
+            Error::InvalidMetric { .. } | Error::InvalidMetricWarning { .. } => {
+                ValidationCategory::Metrics
+            }
+            Error::InvalidAttribute { path, .. }
+            | Error::InvalidAttributeWarning { path, .. }
+            | Error::InvalidExampleError { path, .. }
+            | Error::InvalidExampleWarning { path, .. } => {
+                if path.ends_with("/stability") || path.ends_with("/deprecated") {
+                    ValidationCategory::Stability
+                } else {
+                    ValidationCategory::Attributes
+                }
+            }
+            _ => ValidationCategory::Structure,
+        }
+    }
+}
+
 /// A group spec with its provenance (path or URL).
 #[derive(Debug, Clone)]
 pub struct GroupSpecWithProvenance {
@@ -650,6 +1646,9 @@ pub struct GroupSpecWithProvenance {
     pub spec: GroupSpec,
     /// The provenance of the group spec (path or URL).
     pub provenance: Provenance,
+    /// The provenance of the base group this group was layered on (see
+    /// [`crate::overlay::overlay_registry`]), if any.
+    pub base_provenance: Option<Provenance>,
 }
 
 /// Imports with its provenance (path or URL).
@@ -665,15 +1664,18 @@ pub struct ImportsWithProvenance {
 mod tests {
     use crate::any_value::AnyValueCommonSpec;
     use crate::attribute::{
-        BasicRequirementLevelSpec, EnumEntriesSpec, Examples, RequirementLevel, ValueSpec,
+        AttributeRole, BasicRequirementLevelSpec, EnumEntriesSpec, Examples, RequirementLevel,
+        TemplateTypeSpec, ValueSpec,
     };
     use crate::deprecated::Deprecated;
     use crate::Error::{
-        CompoundError, InvalidAttributeWarning, InvalidExampleWarning, InvalidGroup,
-        InvalidGroupMissingExtendsOrAttributes, InvalidGroupMissingType, InvalidGroupStability,
-        InvalidGroupUsesPrefix, InvalidMetric, InvalidSpanMissingSpanKind,
+        CompoundError, InvalidAttribute, InvalidAttributeWarning, InvalidExampleWarning,
+        InvalidGroup, InvalidGroupMissingExtendsOrAttributes, InvalidGroupMissingType,
+        InvalidGroupStability, InvalidGroupUnusedPrefix, InvalidGroupUsesPrefix,
+        InvalidGroupWarning, InvalidMetric, InvalidMetricWarning, InvalidSpanMissingSpanKind,
         UnstructuredDeprecatedProperty,
     };
+    use arbitrary::Unstructured;
 
     use super::*;
 
@@ -681,6 +1683,7 @@ mod tests {
     fn test_validate_group() {
         let mut group = GroupSpec {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: GroupType::Span,
             brief: "test".to_owned(),
             note: "test".to_owned(),
@@ -693,6 +1696,7 @@ mod tests {
             }),
             attributes: vec![AttributeSpec::Id {
                 id: "test".to_owned(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: None,
                 stability: Some(Stability::Development),
@@ -704,8 +1708,10 @@ mod tests {
                 requirement_level: Default::default(),
                 sampling_relevant: None,
                 note: "".to_owned(),
+                examples_note: None,
                 annotations: None,
                 role: Default::default(),
+                scope: None,
             }],
             span_kind: Some(SpanKindSpec::Client),
             events: vec!["event".to_owned()],
@@ -831,7 +1837,7 @@ mod tests {
         group.span_kind = None;
         group.events = vec![];
         group.deprecated = Some(Deprecated::Unspecified {
-            note: "note".to_owned(),
+            note: "Removed, no replacement.".to_owned(),
         });
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
@@ -845,9 +1851,11 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_validate_attribute() {
         let mut group = GroupSpec {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: GroupType::Span,
             brief: "test".to_owned(),
             note: "test".to_owned(),
@@ -860,6 +1868,7 @@ mod tests {
             }),
             attributes: vec![AttributeSpec::Id {
                 id: "test".to_owned(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: None,
                 stability: Some(Stability::Development),
@@ -871,8 +1880,10 @@ mod tests {
                 requirement_level: Default::default(),
                 sampling_relevant: None,
                 note: "".to_owned(),
+                examples_note: None,
                 annotations: None,
                 role: Default::default(),
+                scope: None,
             }],
             span_kind: Some(SpanKindSpec::Client),
             events: vec!["event".to_owned()],
@@ -894,6 +1905,7 @@ mod tests {
         // Examples are mandatory for string attributes.
         group.attributes = vec![AttributeSpec::Id {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: None,
             stability: Some(Stability::Development),
@@ -905,8 +1917,10 @@ mod tests {
             requirement_level: Default::default(),
             sampling_relevant: None,
             note: "".to_owned(),
+            examples_note: None,
             annotations: None,
             role: Default::default(),
+            scope: None,
         }];
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
@@ -916,6 +1930,7 @@ mod tests {
                 attribute_id: "test".to_owned(),
                 error: "This attribute is a string but it does not contain any examples."
                     .to_owned(),
+                path: "/attributes/0".into(),
             },),
             result
         );
@@ -923,6 +1938,7 @@ mod tests {
         // Examples are mandatory for strings attributes.
         group.attributes = vec![AttributeSpec::Id {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Strings),
             brief: None,
             stability: Some(Stability::Development),
@@ -934,8 +1950,10 @@ mod tests {
             requirement_level: Default::default(),
             sampling_relevant: None,
             note: "".to_owned(),
+            examples_note: None,
             annotations: None,
             role: Default::default(),
+            scope: None,
         }];
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
@@ -945,6 +1963,7 @@ mod tests {
                 attribute_id: "test".to_owned(),
                 error: "This attribute is a string array but it does not contain any examples."
                     .to_owned(),
+                path: "/attributes/0".into(),
             },),
             result
         );
@@ -952,6 +1971,7 @@ mod tests {
         // Stability is missing.
         group.attributes = vec![AttributeSpec::Id {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: None,
             stability: None,
@@ -963,8 +1983,10 @@ mod tests {
             requirement_level: Default::default(),
             sampling_relevant: None,
             note: "".to_owned(),
+            examples_note: None,
             annotations: None,
             role: Default::default(),
+            scope: None,
         }];
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
@@ -973,6 +1995,7 @@ mod tests {
                 group_id: "test".to_owned(),
                 attribute_id: "test".to_owned(),
                 error: "Missing stability field.".to_owned(),
+                path: "/attributes/0".into(),
             },),
             result
         );
@@ -980,6 +2003,7 @@ mod tests {
         // Stability is set to deprecated.
         group.attributes = vec![AttributeSpec::Id {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: None,
             stability: Some(Stability::Deprecated),
@@ -991,8 +2015,10 @@ mod tests {
             requirement_level: Default::default(),
             sampling_relevant: None,
             note: "".to_owned(),
+            examples_note: None,
             annotations: None,
             role: Default::default(),
+            scope: None,
         }];
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
@@ -1002,6 +2028,7 @@ mod tests {
                 attribute_id: "test".to_owned(),
                 error: "Attribute stability is set to 'deprecated' which is no longer supported."
                     .to_owned(),
+                path: "/attributes/0/stability".into(),
             },),
             result
         );
@@ -1009,6 +2036,7 @@ mod tests {
         // Stability is missing on enum member.
         group.attributes = vec![AttributeSpec::Id {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::Enum {
                 members: vec![EnumEntriesSpec {
                     id: "member_id".to_owned(),
@@ -1025,13 +2053,15 @@ mod tests {
             deprecated: Some(Deprecated::Obsoleted {
                 note: "".to_owned(),
             }),
-            examples: Some(Examples::String("test".to_owned())),
+            examples: Some(Examples::String("member_value".to_owned())),
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
             note: "".to_owned(),
+            examples_note: None,
             annotations: None,
             role: Default::default(),
+            scope: None,
         }];
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
@@ -1040,6 +2070,7 @@ mod tests {
                 group_id: "test".to_owned(),
                 attribute_id: "test".to_owned(),
                 error: "Missing stability field on enum member member_id.".to_owned(),
+                path: "/attributes/0/type/members/0".into(),
             },),
             result
         );
@@ -1047,6 +2078,7 @@ mod tests {
         // Stability is set to deprecated on enum member.
         group.attributes = vec![AttributeSpec::Id {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::Enum {
                 members: vec![EnumEntriesSpec {
                     id: "member_id".to_owned(),
@@ -1063,13 +2095,15 @@ mod tests {
             deprecated: Some(Deprecated::Obsoleted {
                 note: "".to_owned(),
             }),
-            examples: Some(Examples::String("test".to_owned())),
+            examples: Some(Examples::String("member_value".to_owned())),
             tag: None,
             requirement_level: Default::default(),
             sampling_relevant: None,
             note: "".to_owned(),
+            examples_note: None,
             annotations: None,
             role: Default::default(),
+            scope: None,
         }];
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
@@ -1078,6 +2112,7 @@ mod tests {
                 group_id: "test".to_owned(),
                 attribute_id: "test".to_owned(),
                 error: "Member member_id stability is set to 'deprecated' which is no longer supported.".to_owned(),
+                path: "/attributes/0/type/members/0/stability".into(),
             }, ),
             result
         );
@@ -1086,22 +2121,26 @@ mod tests {
         group.attributes = vec![
             AttributeSpec::Id {
                 id: "test".to_owned(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: Some("brief".to_owned()),
                 stability: Some(Stability::Stable),
                 deprecated: Some(Deprecated::Unspecified {
-                    note: "note".to_owned(),
+                    note: "Removed, no replacement.".to_owned(),
                 }),
                 examples: Some(Examples::String("test".to_owned())),
                 tag: None,
                 requirement_level: Default::default(),
                 sampling_relevant: None,
                 note: "".to_owned(),
+                examples_note: None,
                 annotations: None,
                 role: Default::default(),
+                scope: None,
             },
             AttributeSpec::Id {
                 id: "test_enum".to_owned(),
+                renamed_from: None,
                 r#type: AttributeType::Enum {
                     members: vec![EnumEntriesSpec {
                         id: "member_id".to_owned(),
@@ -1110,7 +2149,7 @@ mod tests {
                         note: None,
                         stability: Some(Stability::Stable),
                         deprecated: Some(Deprecated::Unspecified {
-                            note: "note".to_owned(),
+                            note: "Removed, no replacement.".to_owned(),
                         }),
                         annotations: None,
                     }],
@@ -1118,13 +2157,15 @@ mod tests {
                 brief: Some("brief".to_owned()),
                 stability: Some(Stability::Stable),
                 deprecated: None,
-                examples: Some(Examples::String("test".to_owned())),
+                examples: Some(Examples::String("member_value".to_owned())),
                 tag: None,
                 requirement_level: Default::default(),
                 sampling_relevant: None,
                 note: "".to_owned(),
+                examples_note: None,
                 annotations: None,
                 role: Default::default(),
+                scope: None,
             },
         ];
         let result = group.validate("<test>").into_result_failing_non_fatal();
@@ -1151,6 +2192,7 @@ mod tests {
     fn test_validate_event() {
         let mut group = GroupSpec {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: GroupType::Event,
             name: Some("test_event".to_owned()),
             brief: "test".to_owned(),
@@ -1367,73 +2409,434 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_event_stability() {
-        let mut group = GroupSpec {
-            id: "test".to_owned(),
-            r#type: GroupType::Event,
-            name: Some("test_event".to_owned()),
-            brief: "test".to_owned(),
-            note: "test".to_owned(),
-            prefix: "".to_owned(),
-            extends: None,
-            include_groups: vec![],
-            stability: Some(Stability::Stable),
-            deprecated: None,
-            span_kind: None,
-            events: vec![],
-            metric_name: None,
-            instrument: None,
-            unit: None,
-            display_name: None,
-            attributes: vec![],
-            body: Some(AnyValueSpec::String {
-                common: AnyValueCommonSpec {
-                    id: "id".to_owned(),
-                    brief: "brief".to_owned(),
-                    note: "note".to_owned(),
-                    stability: Some(Stability::Stable),
-                    examples: Some(Examples::String("test".to_owned())),
-                    requirement_level: RequirementLevel::Basic(
-                        BasicRequirementLevelSpec::Recommended,
-                    ),
-                },
-            }),
-            annotations: None,
-            entity_associations: Vec::new(),
-            visibility: None,
-        };
+    fn test_validate_event_body() {
+        let yaml = r#"
+id: event.session.start
+type: event
+name: session.start
+brief: A session start event.
+stability: development
+body:
+  id: session.start.body
+  type: map
+  stability: development
+  requirement_level: recommended
+  fields:
+    - id: session.id
+      type: string
+      stability: development
+      requirement_level: recommended
+      examples: [abc]
+    - id: session.previous
+      type: map[]
+      stability: development
+      requirement_level: recommended
+      fields:
+        - id: count
+          type: int
+          stability: development
+          requirement_level: recommended
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
         assert!(group
             .validate("<test>")
             .into_result_failing_non_fatal()
             .is_ok());
 
-        // Stability is required for all types of values.
-        group.body = Some(AnyValueSpec::String {
-            common: AnyValueCommonSpec {
-                id: "string_id".to_owned(),
-                brief: "brief".to_owned(),
-                note: "note".to_owned(),
-                stability: None,
-                examples: Some(Examples::String("test".to_owned())),
-                requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended),
-            },
-        });
-
+        // Fields nested in a map array must have an id.
+        if let Some(AnyValueSpec::Map { fields, .. }) = &mut group.body {
+            if let AnyValueSpec::Maps { fields, .. } = &mut fields[1] {
+                if let AnyValueSpec::Int { common } = &mut fields[0] {
+                    common.id = String::new();
+                }
+            }
+        }
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
             Err(Error::InvalidAnyValue {
                 path_or_url: "<test>".to_owned(),
-                group_id: "test".to_owned(),
-                value_id: "string_id".to_owned(),
-                error: "Missing stability field.".to_owned(),
-            },),
+                group_id: "event.session.start".to_owned(),
+                value_id: String::new(),
+                error: "Missing id field.".to_owned(),
+            }),
             result
         );
 
-        // Stability is required for nested values.
-        group.body = Some(AnyValueSpec::Map {
-            common: AnyValueCommonSpec {
-                id: "map_id".to_owned(),
+        // The body is only valid on event groups.
+        let yaml = r#"
+id: span.session
+type: span
+brief: A session span.
+stability: development
+span_kind: internal
+attributes:
+  - ref: session.id
+body:
+  id: session.body
+  type: int
+  stability: development
+  requirement_level: recommended
+"#;
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "span.session".to_owned(),
+                error: "This group contains a body field but the type is not set to event."
+                    .to_owned(),
+            }),
+            result
+        );
+
+        // A body field without a type is rejected when parsing.
+        let yaml = r#"
+id: event.session.start
+type: event
+name: session.start
+brief: A session start event.
+stability: development
+body:
+  id: session.start.body
+  type: map
+  stability: development
+  requirement_level: recommended
+  fields:
+    - id: session.id
+      stability: development
+      requirement_level: recommended
+"#;
+        assert!(serde_yaml::from_str::<GroupSpec>(yaml).is_err());
+    }
+
+    #[test]
+    fn test_validate_deprecated_required_attribute() {
+        let yaml = r#"
+id: registry.http
+type: attribute_group
+brief: HTTP attributes.
+attributes:
+  - id: http.method
+    type: string
+    brief: The HTTP method.
+    stability: development
+    deprecated:
+      reason: renamed
+      renamed_to: http.request.method
+    requirement_level: required
+    examples: [GET]
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.http".to_owned(),
+                attribute_id: "http.method".to_owned(),
+                error: "This attribute is deprecated but its requirement level is `required`."
+                    .to_owned(),
+                path: "/attributes/0/requirement_level".into(),
+            }),
+            group.validate("<test>").into_result_failing_non_fatal()
+        );
+
+        let AttributeSpec::Id {
+            requirement_level, ..
+        } = &mut group.attributes[0]
+        else {
+            panic!("Expected an attribute definition");
+        };
+        *requirement_level = RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended);
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_examples_note() {
+        let yaml = r#"
+id: registry.http
+type: attribute_group
+brief: HTTP attributes.
+attributes:
+  - id: http.request.header.accept
+    type: string[]
+    brief: The accepted media types.
+    stability: stable
+    examples: [["text/html"]]
+    examples_note: "Accept: text/html"
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.http".to_owned(),
+                attribute_id: "http.request.header.accept".to_owned(),
+                error: "This attribute defines an examples_note but no note.".to_owned(),
+                path: "/attributes/0/examples_note".into(),
+            }),
+            group.validate("<test>").into_result_failing_non_fatal()
+        );
+
+        let AttributeSpec::Id { note, .. } = &mut group.attributes[0] else {
+            panic!("Expected an attribute definition");
+        };
+        *note = "The media types the client accepts.".to_owned();
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_examples() {
+        let yaml = r#"
+id: registry.http
+type: attribute_group
+brief: HTTP attributes.
+attributes:
+  - id: http.request.header.accept
+    type: string[]
+    brief: The accepted media types.
+    stability: stable
+    examples: [["text/html", "application/json"]]
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        let AttributeSpec::Id { examples, .. } = &mut group.attributes[0] else {
+            panic!("Expected an attribute definition");
+        };
+        *examples = Some(Examples::String("text/html".to_owned()));
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.http".to_owned(),
+                attribute_id: "http.request.header.accept".to_owned(),
+                error: "This attribute is of type `string[]` but its example is a scalar, each example must be an array (e.g. `examples: [[a, b]]`).".to_owned(),
+                path: "/attributes/0/examples".into(),
+            }),
+            group.validate("<test>").into_result_failing_non_fatal()
+        );
+    }
+
+    #[test]
+    fn test_validate_enum_examples() {
+        let yaml = r#"
+id: registry.http
+type: attribute_group
+brief: HTTP attributes.
+attributes:
+  - id: http.request.method
+    type:
+      members:
+        - id: get
+          value: GET
+          brief: GET method.
+          stability: stable
+        - id: post
+          value: POST
+          brief: POST method.
+          stability: stable
+    brief: The HTTP method.
+    stability: stable
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        // A closed enum doesn't need examples.
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        let AttributeSpec::Id { r#type, .. } = &mut group.attributes[0] else {
+            panic!("Expected an attribute definition");
+        };
+        let AttributeType::Enum { members } = r#type else {
+            panic!("Expected an enum");
+        };
+        members.push(EnumEntriesSpec {
+            id: "other".to_owned(),
+            value: ValueSpec::String("_OTHER".to_owned()),
+            brief: Some("Any other method.".to_owned()),
+            note: None,
+            stability: Some(Stability::Stable),
+            deprecated: None,
+            annotations: None,
+        });
+        assert_eq!(
+            Err(InvalidExampleWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.http".to_owned(),
+                attribute_id: "http.request.method".to_owned(),
+                error: "This attribute is an open enum but it does not contain any examples."
+                    .to_owned(),
+                path: "/attributes/0".into(),
+            }),
+            group.validate("<test>").into_result_failing_non_fatal()
+        );
+    }
+
+    #[test]
+    fn test_validate_event_name_and_prefix() {
+        let yaml = r#"
+id: event.session.start
+type: event
+brief: A session started.
+stability: stable
+name: session.start
+attributes:
+  - id: id
+    type: string
+    brief: The session id.
+    stability: stable
+    examples: ["00112233-4455-6677-8899-aabbccddeeff"]
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let uses_prefix = InvalidGroupUsesPrefix {
+            path_or_url: "<test>".to_owned(),
+            group_id: "event.session.start".to_owned(),
+        };
+
+        // Name only.
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        // Prefix only, or both with the same value.
+        group.name = None;
+        group.prefix = "session.start".to_owned();
+        assert_eq!(
+            Err(uses_prefix.clone()),
+            group.validate("<test>").into_result_failing_non_fatal()
+        );
+        group.name = Some("session.start".to_owned());
+        assert_eq!(
+            Err(uses_prefix.clone()),
+            group.validate("<test>").into_result_failing_non_fatal()
+        );
+
+        // Both with conflicting values.
+        group.prefix = "session".to_owned();
+        assert_eq!(
+            Err(CompoundError(vec![
+                uses_prefix,
+                InvalidGroupWarning {
+                    path_or_url: "<test>".to_owned(),
+                    group_id: "event.session.start".to_owned(),
+                    error: "This event group sets both the name `session.start` and the prefix `session`, only the name is used as the event name.".to_owned(),
+                },
+            ])),
+            group.validate("<test>").into_result_failing_non_fatal()
+        );
+    }
+
+    #[test]
+    fn test_validate_event_name() {
+        let yaml = r#"
+id: event.test
+type: event
+brief: A test event.
+stability: stable
+name: exception
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        for name in ["exception", "rpc.message"] {
+            group.name = Some(name.to_owned());
+            assert!(
+                group
+                    .validate("<test>")
+                    .into_result_failing_non_fatal()
+                    .is_ok(),
+                "{name}"
+            );
+        }
+
+        for name in ["My Event", "rpc..message"] {
+            group.name = Some(name.to_owned());
+            assert_eq!(
+                Err(InvalidGroup {
+                    path_or_url: "<test>".to_owned(),
+                    group_id: "event.test".to_owned(),
+                    error: format!("The event name `{name}` is not made of dot-separated lowercase segments (e.g. `rpc.message`)."),
+                }),
+                group.validate("<test>").into_result_failing_non_fatal()
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_event_stability() {
+        let mut group = GroupSpec {
+            id: "test".to_owned(),
+            renamed_from: None,
+            r#type: GroupType::Event,
+            name: Some("test_event".to_owned()),
+            brief: "test".to_owned(),
+            note: "test".to_owned(),
+            prefix: "".to_owned(),
+            extends: None,
+            include_groups: vec![],
+            stability: Some(Stability::Stable),
+            deprecated: None,
+            span_kind: None,
+            events: vec![],
+            metric_name: None,
+            instrument: None,
+            unit: None,
+            display_name: None,
+            attributes: vec![],
+            body: Some(AnyValueSpec::String {
+                common: AnyValueCommonSpec {
+                    id: "id".to_owned(),
+                    brief: "brief".to_owned(),
+                    note: "note".to_owned(),
+                    stability: Some(Stability::Stable),
+                    examples: Some(Examples::String("test".to_owned())),
+                    requirement_level: RequirementLevel::Basic(
+                        BasicRequirementLevelSpec::Recommended,
+                    ),
+                },
+            }),
+            annotations: None,
+            entity_associations: Vec::new(),
+            visibility: None,
+        };
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        // Stability is required for all types of values.
+        group.body = Some(AnyValueSpec::String {
+            common: AnyValueCommonSpec {
+                id: "string_id".to_owned(),
+                brief: "brief".to_owned(),
+                note: "note".to_owned(),
+                stability: None,
+                examples: Some(Examples::String("test".to_owned())),
+                requirement_level: RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended),
+            },
+        });
+
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(Error::InvalidAnyValue {
+                path_or_url: "<test>".to_owned(),
+                group_id: "test".to_owned(),
+                value_id: "string_id".to_owned(),
+                error: "Missing stability field.".to_owned(),
+            },),
+            result
+        );
+
+        // Stability is required for nested values.
+        group.body = Some(AnyValueSpec::Map {
+            common: AnyValueCommonSpec {
+                id: "map_id".to_owned(),
                 brief: "brief".to_owned(),
                 note: "note".to_owned(),
                 stability: Some(Stability::Stable),
@@ -1509,9 +2912,11 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_validate_group_stability() {
         let mut group = GroupSpec {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: GroupType::AttributeGroup,
             brief: "test".to_owned(),
             note: "test".to_owned(),
@@ -1522,6 +2927,7 @@ mod tests {
             deprecated: None,
             attributes: vec![AttributeSpec::Id {
                 id: "test".to_owned(),
+                renamed_from: None,
                 r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
                 brief: None,
                 stability: Some(Stability::Development),
@@ -1533,8 +2939,10 @@ mod tests {
                 requirement_level: Default::default(),
                 sampling_relevant: None,
                 note: "".to_owned(),
+                examples_note: None,
                 annotations: None,
                 role: Default::default(),
+                scope: None,
             }],
             span_kind: None,
             events: vec![],
@@ -1632,6 +3040,9 @@ mod tests {
 
         group.r#type = GroupType::Event;
         group.name = Some("test".to_owned());
+        group.metric_name = None;
+        group.instrument = None;
+        group.unit = None;
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
             Err(InvalidGroupStability {
@@ -1683,6 +3094,7 @@ mod tests {
     fn test_validate_extends_or_attributes() {
         let attributes = vec![AttributeSpec::Id {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
             brief: None,
             stability: Some(Stability::Development),
@@ -1694,11 +3106,14 @@ mod tests {
             requirement_level: Default::default(),
             sampling_relevant: None,
             note: "".to_owned(),
+            examples_note: None,
             annotations: None,
             role: Default::default(),
+            scope: None,
         }];
         let mut group = GroupSpec {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: GroupType::AttributeGroup,
             brief: "test".to_owned(),
             note: "test".to_owned(),
@@ -1813,6 +3228,9 @@ mod tests {
         // Events DO NOT need extends or attributes.
         group.r#type = GroupType::Event;
         group.name = Some("test".to_owned());
+        group.metric_name = None;
+        group.instrument = None;
+        group.unit = None;
         assert!(group
             .validate("<test>")
             .into_result_failing_non_fatal()
@@ -1830,11 +3248,13 @@ mod tests {
                 requirement_level: None,
                 sampling_relevant: None,
                 note: None,
+                examples_note: None,
                 stability: None,
                 deprecated: None,
                 prefix: false,
                 annotations: None,
                 role: Default::default(),
+                scope: None,
             },
             AttributeSpec::Ref {
                 r#ref: "attribute".to_owned(),
@@ -1844,15 +3264,18 @@ mod tests {
                 requirement_level: None,
                 sampling_relevant: None,
                 note: None,
+                examples_note: None,
                 stability: None,
                 deprecated: None,
                 prefix: false,
                 annotations: None,
                 role: Default::default(),
+                scope: None,
             },
         ];
         let mut group = GroupSpec {
             id: "test".to_owned(),
+            renamed_from: None,
             r#type: GroupType::AttributeGroup,
             brief: "test".to_owned(),
             note: "test".to_owned(),
@@ -1897,70 +3320,214 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_entity_associations() {
-        let mut group = GroupSpec {
-            id: "test".to_owned(),
-            r#type: GroupType::Metric,
-            brief: "test".to_owned(),
-            note: "test".to_owned(),
-            prefix: "".to_owned(),
-            extends: None,
-            include_groups: vec![],
-            stability: Some(Stability::Stable),
-            deprecated: None,
-            attributes: vec![AttributeSpec::Id {
-                id: "test".to_owned(),
-                r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
-                brief: None,
-                stability: Some(Stability::Development),
-                deprecated: Some(Deprecated::Obsoleted {
-                    note: "".to_owned(),
-                }),
-                examples: Some(Examples::String("test".to_owned())),
-                tag: None,
-                requirement_level: Default::default(),
-                sampling_relevant: None,
-                note: "".to_owned(),
-                annotations: None,
-                role: Default::default(),
-            }],
-            span_kind: None,
-            events: vec![],
-            metric_name: Some("metric".to_owned()),
-            instrument: Some(Gauge),
-            unit: Some("{thing}".to_owned()),
-            name: None,
-            display_name: None,
-            body: None,
-            annotations: None,
-            entity_associations: vec!["test".to_owned()],
-            visibility: None,
-        };
+    fn test_validate_entity() {
+        let yaml = r#"
+id: entity.host
+type: resource
+name: host
+brief: A host.
+stability: development
+attributes:
+  - ref: host.id
+    role: identifying
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert_eq!(group.r#type, GroupType::Entity);
         assert!(group
             .validate("<test>")
             .into_result_failing_non_fatal()
             .is_ok());
 
-        // Span should allow associations.
-        group.r#type = GroupType::Span;
-        group.metric_name = None;
-        group.instrument = None;
-        group.unit = None;
-        group.span_kind = Some(SpanKindSpec::Client);
+        group.instrument = Some(Counter);
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "entity.host".to_owned(),
+                error: "This group contains the instrument field but the type is set to entity (resource).".to_owned(),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_scope() {
+        let yaml = r#"
+id: scope.test
+type: scope
+brief: A test scope.
+stability: development
+attributes:
+  - ref: test.name
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
         assert!(group
             .validate("<test>")
             .into_result_failing_non_fatal()
             .is_ok());
-        // Event should allow associations.
-        group.r#type = GroupType::Event;
-        group.span_kind = None;
-        group.name = Some("event".to_owned());
+
+        group.instrument = Some(Counter);
+        group.span_kind = Some(SpanKindSpec::Internal);
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(CompoundError(vec![
+                InvalidGroup {
+                    path_or_url: "<test>".to_owned(),
+                    group_id: "scope.test".to_owned(),
+                    error: "This group contains a span_kind field but the type is not set to span."
+                        .to_owned(),
+                },
+                InvalidGroup {
+                    path_or_url: "<test>".to_owned(),
+                    group_id: "scope.test".to_owned(),
+                    error: "This group contains the instrument field but the type is set to scope."
+                        .to_owned(),
+                },
+            ])),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_metric_fields_on_span() {
+        let yaml = r#"
+id: span.test
+type: span
+brief: A test span.
+stability: development
+span_kind: client
+attributes:
+  - ref: test.name
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        group.unit = Some("s".to_owned());
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "span.test".to_owned(),
+                error: "This group contains the unit field but the type is set to span.".to_owned(),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_signal_fields_on_attribute_group() {
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: Test attributes.
+attributes:
+  - ref: test.name
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        group.span_kind = Some(SpanKindSpec::Client);
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                error: "This group contains a span_kind field but the type is not set to span."
+                    .to_owned(),
+            }),
+            result
+        );
+
+        group.span_kind = None;
+        group.name = Some("test".to_owned());
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                error: "This group contains the name field but the type is set to attribute_group."
+                    .to_owned(),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_entity_associations() {
+        let mut group = GroupSpec {
+            id: "test".to_owned(),
+            renamed_from: None,
+            r#type: GroupType::Metric,
+            brief: "test".to_owned(),
+            note: "test".to_owned(),
+            prefix: "".to_owned(),
+            extends: None,
+            include_groups: vec![],
+            stability: Some(Stability::Stable),
+            deprecated: None,
+            attributes: vec![AttributeSpec::Id {
+                id: "test".to_owned(),
+                renamed_from: None,
+                r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::String),
+                brief: None,
+                stability: Some(Stability::Development),
+                deprecated: Some(Deprecated::Obsoleted {
+                    note: "".to_owned(),
+                }),
+                examples: Some(Examples::String("test".to_owned())),
+                tag: None,
+                requirement_level: Default::default(),
+                sampling_relevant: None,
+                note: "".to_owned(),
+                examples_note: None,
+                annotations: None,
+                role: Default::default(),
+                scope: None,
+            }],
+            span_kind: None,
+            events: vec![],
+            metric_name: Some("metric".to_owned()),
+            instrument: Some(Gauge),
+            unit: Some("{thing}".to_owned()),
+            name: None,
+            display_name: None,
+            body: None,
+            annotations: None,
+            entity_associations: vec!["test".to_owned()],
+            visibility: None,
+        };
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        // Span should allow associations.
+        group.r#type = GroupType::Span;
+        group.metric_name = None;
+        group.instrument = None;
+        group.unit = None;
+        group.span_kind = Some(SpanKindSpec::Client);
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+        // Event should allow associations.
+        group.r#type = GroupType::Event;
+        group.span_kind = None;
+        group.name = Some("event".to_owned());
         assert!(group
             .validate("<test>")
             .into_result_failing_non_fatal()
             .is_ok());
         // All other types should not allow associations
         group.r#type = GroupType::AttributeGroup;
+        group.name = None;
         let result = group.validate("<test>").into_result_failing_non_fatal();
         assert_eq!(
             Err(InvalidGroup {
@@ -1971,4 +3538,1566 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_validate_brief() {
+        let mut group = GroupSpec {
+            id: "test".to_owned(),
+            renamed_from: None,
+            r#type: GroupType::AttributeGroup,
+            brief: "".to_owned(),
+            note: "".to_owned(),
+            prefix: "".to_owned(),
+            extends: None,
+            include_groups: vec![],
+            stability: None,
+            deprecated: None,
+            attributes: vec![AttributeSpec::Id {
+                id: "test".to_owned(),
+                renamed_from: None,
+                r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
+                brief: Some("test".to_owned()),
+                stability: Some(Stability::Stable),
+                deprecated: None,
+                examples: None,
+                tag: None,
+                requirement_level: Default::default(),
+                sampling_relevant: None,
+                note: "".to_owned(),
+                examples_note: None,
+                annotations: None,
+                role: Default::default(),
+                scope: None,
+            }],
+            span_kind: None,
+            events: vec![],
+            metric_name: None,
+            instrument: None,
+            unit: None,
+            name: None,
+            display_name: None,
+            body: None,
+            annotations: None,
+            entity_associations: vec![],
+            visibility: None,
+        };
+
+        // Empty group brief.
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "test".to_owned(),
+                error: "This group contains an empty brief field.".to_owned(),
+            }),
+            result
+        );
+
+        // A single line break terminating a YAML block scalar is fine.
+        group.brief = "test\n".to_owned();
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        // Trailing whitespace on the group brief.
+        group.brief = "test  ".to_owned();
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroupWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "test".to_owned(),
+                error: "The brief field ends with trailing whitespace.".to_owned(),
+            }),
+            result
+        );
+
+        // Whitespace-only attribute brief.
+        group.brief = "test".to_owned();
+        if let AttributeSpec::Id { brief, .. } = &mut group.attributes[0] {
+            *brief = Some(" \t ".to_owned());
+        }
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "test".to_owned(),
+                attribute_id: "test".to_owned(),
+                error: "This attribute is not deprecated and contains an empty brief field."
+                    .to_owned(),
+                path: "/attributes/0/brief".into(),
+            }),
+            result
+        );
+
+        // Trailing whitespace on the attribute brief.
+        if let AttributeSpec::Id { brief, .. } = &mut group.attributes[0] {
+            *brief = Some("test \n".to_owned());
+        }
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttributeWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "test".to_owned(),
+                attribute_id: "test".to_owned(),
+                error: "The brief field ends with trailing whitespace.".to_owned(),
+                path: "/attributes/0/brief".into(),
+            }),
+            result
+        );
+
+        // Trailing whitespace is removed from the briefs.
+        group.brief = "test \t".to_owned();
+        group.trim_briefs();
+        assert_eq!(group.brief, "test");
+        assert_eq!(group.attributes[0].brief(), "test\n");
+    }
+
+    #[test]
+    fn test_parse_group_eq() {
+        let yaml = r#"
+id: span.http.client
+type: span
+brief: HTTP client span.
+stability: stable
+span_kind: client
+attributes:
+  - ref: server.address
+    requirement_level: required
+  - id: http.retry_count
+    type: int
+    brief: The retry count.
+    stability: stable
+"#;
+        let expected = GroupSpec {
+            id: "span.http.client".to_owned(),
+            r#type: GroupType::Span,
+            brief: "HTTP client span.".to_owned(),
+            stability: Some(Stability::Stable),
+            span_kind: Some(SpanKindSpec::Client),
+            attributes: vec![
+                AttributeSpec::Ref {
+                    r#ref: "server.address".to_owned(),
+                    brief: None,
+                    examples: None,
+                    tag: None,
+                    requirement_level: Some(RequirementLevel::Basic(
+                        BasicRequirementLevelSpec::Required,
+                    )),
+                    sampling_relevant: None,
+                    note: None,
+                    examples_note: None,
+                    stability: None,
+                    deprecated: None,
+                    prefix: false,
+                    annotations: None,
+                    role: None,
+                    scope: None,
+                },
+                AttributeSpec::Id {
+                    id: "http.retry_count".to_owned(),
+                    renamed_from: None,
+                    r#type: AttributeType::PrimitiveOrArray(PrimitiveOrArrayTypeSpec::Int),
+                    brief: Some("The retry count.".to_owned()),
+                    examples: None,
+                    tag: None,
+                    requirement_level: Default::default(),
+                    sampling_relevant: None,
+                    note: "".to_owned(),
+                    examples_note: None,
+                    stability: Some(Stability::Stable),
+                    deprecated: None,
+                    annotations: None,
+                    role: Default::default(),
+                    scope: None,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert_eq!(group, expected);
+
+        // The serialized group parses back to the same value.
+        let serialized = serde_yaml::to_string(&group).expect("Failed to serialize group");
+        let reparsed: GroupSpec =
+            serde_yaml::from_str(&serialized).expect("Failed to parse serialized group");
+        assert_eq!(reparsed, expected);
+    }
+
+    #[test]
+    fn test_metric_signature() {
+        let yaml = r#"
+id: metric.test.duration
+type: metric
+metric_name: test.duration
+brief: A test duration.
+stability: development
+instrument: histogram
+unit: s
+prefix: test
+attributes:
+  - ref: test.name
+  - id: kind
+    type: string
+    brief: The kind.
+    stability: development
+    examples: [foo]
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let signature = group.metric_signature().expect("A metric has a signature");
+        assert_eq!(signature.metric_name, "test.duration");
+        assert_eq!(signature.instrument, Some(Histogram));
+        assert_eq!(signature.unit.as_deref(), Some("s"));
+        assert_eq!(
+            signature.attribute_ids.iter().collect::<Vec<_>>(),
+            vec!["test.kind", "test.name"]
+        );
+        assert!(signature.diverging_parts(&signature).is_empty());
+
+        let other = MetricSignature {
+            unit: Some("ms".to_owned()),
+            ..signature.clone()
+        };
+        assert_eq!(signature.diverging_parts(&other), vec!["unit"]);
+
+        group.r#type = GroupType::Span;
+        assert!(group.metric_signature().is_none());
+    }
+
+    #[test]
+    fn test_content_key() {
+        let group: GroupSpec = serde_yaml::from_str(
+            r#"
+id: registry.test
+type: attribute_group
+brief: Test attributes.
+annotations:
+  code_generation:
+    exclude: true
+  docs:
+    hidden: false
+attributes:
+  - id: test.name
+    type: string
+    brief: The name.
+    stability: stable
+    examples: [foo]
+"#,
+        )
+        .expect("Failed to parse group");
+        let same_group: GroupSpec = serde_yaml::from_str(
+            r#"
+type: attribute_group
+attributes:
+  - brief: The name.
+    examples: [foo]
+    id: test.name
+    stability: stable
+    type: string
+annotations:
+  docs:
+    hidden: false
+  code_generation:
+    exclude: true
+brief: Test attributes.
+id: registry.test
+"#,
+        )
+        .expect("Failed to parse group");
+        assert_eq!(group.content_key(), same_group.content_key());
+
+        let other_group = GroupSpec {
+            brief: "Other test attributes.".to_owned(),
+            ..group.clone()
+        };
+        assert_ne!(group.content_key(), other_group.content_key());
+
+        let keys: HashSet<_> = [&group, &same_group, &other_group]
+            .iter()
+            .map(|group| group.content_key())
+            .collect();
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_deprecated_note() {
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+attributes:
+  - id: test.old_name
+    type: string
+    brief: The old name.
+    stability: development
+    examples: [foo]
+    deprecated:
+      reason: obsoleted
+      note: "true"
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                attribute_id: "test.old_name".to_owned(),
+                error: "The deprecation note of this attribute doesn't explain why it is deprecated or what to use instead.".to_owned(),
+                path: "/attributes/0/deprecated".into(),
+            }),
+            result
+        );
+
+        // An empty note is valid on structured deprecations.
+        if let AttributeSpec::Id { deprecated, .. } = &mut group.attributes[0] {
+            *deprecated = Some(Deprecated::Obsoleted {
+                note: String::new(),
+            });
+        }
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        // An empty unstructured deprecation doesn't explain anything.
+        group.deprecated = Some(Deprecated::Unspecified {
+            note: String::new(),
+        });
+        let result = group.validate("<test>").into_result_with_non_fatal();
+        assert!(result.is_ok_and(|(_, errors)| errors.contains(&InvalidGroup {
+            path_or_url: "<test>".to_owned(),
+            group_id: "registry.test".to_owned(),
+            error: "The deprecation note of this group doesn't explain why it is deprecated or what to use instead.".to_owned(),
+        })));
+
+        // A real reason.
+        group.deprecated = Some(Deprecated::Uncategorized {
+            note: "Split into `test.first_name` and `test.last_name`.".to_owned(),
+        });
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        // Enum members are checked the same way.
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+attributes:
+  - id: test.state
+    type:
+      members:
+        - id: idle
+          value: idle
+          stability: development
+          deprecated:
+            reason: uncategorized
+            note: "yes"
+    brief: The state.
+    stability: development
+    examples: [idle]
+"#;
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                attribute_id: "test.state".to_owned(),
+                error: "The deprecation note of enum member idle doesn't explain why it is deprecated or what to use instead.".to_owned(),
+                path: "/attributes/0/type/members/0/deprecated".into(),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_deprecated_self_reference() {
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+attributes:
+  - id: test.old_name
+    type: int
+    brief: The old name.
+    stability: development
+    deprecated:
+      reason: renamed
+      renamed_to: test.new_name
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        // The deprecated attribute points at itself.
+        if let AttributeSpec::Id { deprecated, .. } = &mut group.attributes[0] {
+            *deprecated = Some(Deprecated::Renamed {
+                renamed_to: "test.old_name".to_owned(),
+                note: "Replaced by `test.old_name`.".to_owned(),
+            });
+        }
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                attribute_id: "test.old_name".to_owned(),
+                error: "This attribute is deprecated in favor of `test.old_name` which is the attribute itself.".to_owned(),
+                path: "/attributes/0/deprecated".into(),
+            }),
+            result
+        );
+
+        // The same applies to the replacement extracted from a note.
+        group.attributes.clear();
+        group.r#type = GroupType::Metric;
+        group.stability = Some(Stability::Development);
+        group.metric_name = Some("test.requests".to_owned());
+        group.instrument = Some(Counter);
+        group.unit = Some("{request}".to_owned());
+        group.deprecated = Some(Deprecated::Uncategorized {
+            note: "Use `test.requests` instead.".to_owned(),
+        });
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                error: "This group is deprecated in favor of `test.requests` which is the group itself.".to_owned(),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_requirement_level_text() {
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+attributes:
+  - ref: error.type
+    requirement_level:
+      conditionally_required: If and only if the operation failed.
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+        if let AttributeSpec::Ref {
+            requirement_level: Some(requirement_level),
+            ..
+        } = &group.attributes[0]
+        {
+            assert_eq!(
+                requirement_level.text(),
+                Some("If and only if the operation failed.")
+            );
+        }
+
+        if let AttributeSpec::Ref {
+            requirement_level, ..
+        } = &mut group.attributes[0]
+        {
+            *requirement_level = Some(RequirementLevel::ConditionallyRequired {
+                text: " ".to_owned(),
+                condition: None,
+            });
+        }
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                attribute_id: "error.type".to_owned(),
+                error: "The requirement level contains an empty description.".to_owned(),
+                path: "/attributes/0/requirement_level".into(),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_sampling_relevant() {
+        let yaml = r#"
+id: span.http.client
+type: span
+brief: An HTTP client span.
+stability: stable
+span_kind: client
+attributes:
+  - ref: server.address
+    sampling_relevant: true
+"#;
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        let yaml = r#"
+id: metric.http.client.request.duration
+type: metric
+brief: Duration of HTTP client requests.
+stability: stable
+metric_name: http.client.request.duration
+instrument: histogram
+unit: s
+attributes:
+  - ref: server.address
+    sampling_relevant: false
+"#;
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "metric.http.client.request.duration".to_owned(),
+                attribute_id: "server.address".to_owned(),
+                error: "This attribute contains a sampling_relevant field but the group type is not set to span.".to_owned(),
+                path: "/attributes/0/sampling_relevant".into(),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_metric_attribute_redundancy() {
+        let yaml = r#"
+id: metric.http.server.request.duration
+type: metric
+brief: Duration of HTTP server requests.
+stability: stable
+metric_name: http.server.request.duration
+instrument: histogram
+unit: s
+attributes:
+  - ref: http.request.method
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        group.attributes.push(AttributeSpec::Ref {
+            r#ref: "http.server.request.duration.unit".to_owned(),
+            brief: None,
+            examples: None,
+            tag: None,
+            requirement_level: None,
+            sampling_relevant: None,
+            note: None,
+            examples_note: None,
+            stability: None,
+            deprecated: None,
+            prefix: false,
+            annotations: None,
+            role: None,
+            scope: None,
+        });
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttributeWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "metric.http.server.request.duration".to_owned(),
+                attribute_id: "http.server.request.duration.unit".to_owned(),
+                error: "This attribute duplicates the name of the metric `http.server.request.duration`.".to_owned(),
+                path: "/attributes/1".into(),
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_dimensionless_unit() {
+        let yaml = r#"
+id: metric.test.requests
+type: metric
+brief: The number of requests.
+stability: stable
+metric_name: test.requests
+instrument: counter
+unit: "1"
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        for unit in ["", "count"] {
+            group.unit = Some(unit.to_owned());
+            let result = group.validate("<test>").into_result_failing_non_fatal();
+            assert_eq!(
+                Err(InvalidMetric {
+                    path_or_url: "<test>".to_owned(),
+                    group_id: "metric.test.requests".to_owned(),
+                    error: format!(
+                        "The unit `{unit}` is not a valid dimensionless unit, use \"1\" instead."
+                    ),
+                }),
+                result
+            );
+
+            group.normalize_unit();
+            assert_eq!(group.unit.as_deref(), Some("1"));
+        }
+
+        // Other units are left untouched.
+        group.unit = Some("{request}".to_owned());
+        group.normalize_unit();
+        assert_eq!(group.unit.as_deref(), Some("{request}"));
+    }
+
+    #[test]
+    fn test_validation_matrix() {
+        let yaml = r#"
+- id: registry.test
+  type: attribute_group
+  brief: A valid group.
+  attributes:
+    - id: test.name
+      type: string
+      brief: The name.
+      stability: stable
+      examples: [foo]
+- id: metric.test.requests
+  type: metric
+  brief: A metric without unit.
+  stability: stable
+  metric_name: test.requests
+  instrument: counter
+  prefix: test
+- id: span.test
+  type: span
+  brief: A span with a poorly defined attribute.
+  stability: development
+  span_kind: client
+  attributes:
+    - id: test.tags
+      type: string[]
+      brief: The tags.
+      stability: stable
+    - id: test.count
+      type: int
+      brief: The count.
+      stability: deprecated
+"#;
+        let groups: Vec<GroupSpec> = serde_yaml::from_str(yaml).expect("Failed to parse groups");
+        let row = |group_id: &str, naming, stability, metrics, attributes| GroupValidationRow {
+            group_id: group_id.to_owned(),
+            naming,
+            stability,
+            metrics,
+            attributes,
+            structure: true,
+        };
+
+        // Warnings (prefix, missing examples and deprecated stability) are ignored.
+        let lenient = validation_matrix(&groups, &ValidationOptions::default());
+        assert_eq!(
+            lenient,
+            vec![
+                row("registry.test", true, true, true, true),
+                row("metric.test.requests", true, true, false, true),
+                row("span.test", true, true, true, true),
+            ]
+        );
+
+        let strict = validation_matrix(
+            &groups,
+            &ValidationOptions {
+                strict: true,
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            strict,
+            vec![
+                row("registry.test", true, true, true, true),
+                row("metric.test.requests", false, true, false, true),
+                row("span.test", true, false, true, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_with_options() {
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+attributes:
+  - id: test.tags
+    type: string[]
+    brief: The tags.
+    stability: stable
+"#;
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+
+        // The missing example is only a warning.
+        let lenient = ValidationOptions::default();
+        assert!(group.validate_with_options("<test>", &lenient).is_ok());
+
+        let strict = ValidationOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            Err(InvalidExampleWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                attribute_id: "test.tags".to_owned(),
+                error: "This attribute is a string array but it does not contain any examples."
+                    .to_owned(),
+                path: "/attributes/0".into(),
+            }),
+            group.validate_with_options("<test>", &strict)
+        );
+    }
+
+    #[test]
+    fn test_validate_with_rules() {
+        /// Requires an `owner` annotation on every group.
+        struct OwnerRule;
+
+        impl GroupRule for OwnerRule {
+            fn check(&self, group: &GroupSpec, path_or_url: &str) -> Vec<Error> {
+                let has_owner = group
+                    .annotations
+                    .as_ref()
+                    .is_some_and(|annotations| annotations.contains_key("owner"));
+                if has_owner {
+                    return vec![];
+                }
+                vec![InvalidGroup {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: group.id.clone(),
+                    error: "This group has no `owner` annotation.".to_owned(),
+                }]
+            }
+        }
+
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+attributes:
+  - id: test.tags
+    type: string[]
+    brief: The tags.
+    stability: stable
+    examples: [[a, b]]
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let rules: Vec<Box<dyn GroupRule>> = vec![Box::new(OwnerRule)];
+        let options = ValidationOptions::default();
+        assert!(group.validate_with_rules("<test>", &options, &[]).is_ok());
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                error: "This group has no `owner` annotation.".to_owned(),
+            }),
+            group.validate_with_rules("<test>", &options, &rules)
+        );
+
+        // The findings of the custom rules are merged with the built-in ones.
+        let AttributeSpec::Id { examples, .. } = &mut group.attributes[0] else {
+            panic!("Expected an attribute definition");
+        };
+        *examples = Some(Examples::String("a".to_owned()));
+        match group.validate_with_rules("<test>", &options, &rules) {
+            Err(CompoundError(errors)) => assert_eq!(errors.len(), 2),
+            other => panic!("Expected a CompoundError, got {other:?}"),
+        }
+
+        group.annotations = Some(BTreeMap::from([(
+            "owner".to_owned(),
+            YamlValue(serde_yaml::Value::String("team-a".to_owned())),
+        )]));
+        let AttributeSpec::Id { examples, .. } = &mut group.attributes[0] else {
+            panic!("Expected an attribute definition");
+        };
+        *examples = Some(Examples::ListOfStrings(vec![vec!["a".to_owned()]]));
+        assert!(group
+            .validate_with_rules("<test>", &options, &rules)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_metric_enum_members() {
+        let yaml = r#"
+id: metric.test.requests
+type: metric
+brief: The number of requests.
+stability: stable
+metric_name: test.requests
+instrument: counter
+unit: "{request}"
+attributes:
+  - id: test.outcome
+    type:
+      members:
+        - id: success
+          value: success
+          stability: stable
+        - id: failure
+          value: failure
+          stability: stable
+        - id: timeout
+          value: timeout
+          stability: stable
+    brief: The outcome of the request.
+    stability: stable
+"#;
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+
+        // The check is disabled by default.
+        let options = ValidationOptions::default();
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+
+        // Below the threshold.
+        let options = ValidationOptions {
+            strict: true,
+            max_metric_enum_members: Some(3),
+            ..Default::default()
+        };
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+
+        // Above the threshold, only reported as an error in strict mode.
+        let options = ValidationOptions {
+            strict: true,
+            max_metric_enum_members: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            Err(InvalidAttributeWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "metric.test.requests".to_owned(),
+                attribute_id: "test.outcome".to_owned(),
+                error: "This enum attribute defines 3 members, more than the maximum of 2 allowed on metric attributes.".to_owned(),
+                path: "/attributes/0/type/members".into(),
+            }),
+            group.validate_with_options("<test>", &options)
+        );
+        let options = ValidationOptions {
+            strict: false,
+            max_metric_enum_members: Some(2),
+            ..Default::default()
+        };
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+    }
+
+    #[test]
+    fn test_validate_metric_naming() {
+        let yaml = r#"
+id: metric.http.server.requests.total
+type: metric
+brief: The number of requests.
+stability: stable
+metric_name: http.server.requests.total
+instrument: counter
+unit: "{request}"
+attributes:
+  - ref: http.request.method
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let options = ValidationOptions {
+            strict: true,
+            check_counter_suffixes: true,
+            check_duration_units: true,
+            ..Default::default()
+        };
+
+        // The rules are disabled by default.
+        assert!(group
+            .validate_with_options("<test>", &ValidationOptions::default())
+            .is_ok());
+        assert_eq!(
+            Err(InvalidMetricWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "metric.http.server.requests.total".to_owned(),
+                error: "The metric name `http.server.requests.total` of this counter ends with the discouraged suffix `.total`.".to_owned(),
+            }),
+            group.validate_with_options("<test>", &options)
+        );
+
+        // Each rule can be toggled individually.
+        let suffixes_disabled = ValidationOptions {
+            check_counter_suffixes: false,
+            ..options.clone()
+        };
+        assert!(group
+            .validate_with_options("<test>", &suffixes_disabled)
+            .is_ok());
+
+        // A duration histogram with a unit of time.
+        group.id = "metric.http.server.request.duration".to_owned();
+        group.metric_name = Some("http.server.request.duration".to_owned());
+        group.instrument = Some(Histogram);
+        group.unit = Some("ms".to_owned());
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+
+        // A duration histogram with a unit of information.
+        group.unit = Some("By".to_owned());
+        assert_eq!(
+            Err(InvalidMetricWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "metric.http.server.request.duration".to_owned(),
+                error: "The unit `By` of the duration histogram `http.server.request.duration` is not a unit of time.".to_owned(),
+            }),
+            group.validate_with_options("<test>", &options)
+        );
+        let units_disabled = ValidationOptions {
+            check_duration_units: false,
+            ..options
+        };
+        assert!(group
+            .validate_with_options("<test>", &units_disabled)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_group_ids() {
+        let yaml = r#"
+id: metric.http.server.request.duration
+type: metric
+brief: The duration of HTTP server requests.
+stability: stable
+metric_name: http.server.request.duration
+instrument: histogram
+unit: s
+attributes:
+  - ref: http.request.method
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let options = ValidationOptions {
+            check_group_ids: true,
+            ..Default::default()
+        };
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+
+        group.id = "HTTP Server Duration".to_owned();
+        // The rule is disabled by default.
+        assert!(group
+            .validate_with_options("<test>", &ValidationOptions::default())
+            .is_ok());
+        assert_eq!(
+            Err(InvalidGroup {
+                path_or_url: "<test>".to_owned(),
+                group_id: "HTTP Server Duration".to_owned(),
+                error: "The id `HTTP Server Duration` is not made of dot-separated lowercase segments (e.g. `metric.http.server.request.duration`).".to_owned(),
+            }),
+            group.validate_with_options("<test>", &options)
+        );
+    }
+
+    #[test]
+    fn test_validate_gauge_units() {
+        let yaml = r#"
+id: metric.system.network.io
+type: metric
+brief: The network throughput.
+stability: stable
+metric_name: system.network.io
+instrument: gauge
+unit: By/s
+attributes:
+  - ref: network.io.direction
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let options = ValidationOptions {
+            strict: true,
+            check_gauge_units: true,
+            ..Default::default()
+        };
+
+        // The rule is disabled by default.
+        assert!(group
+            .validate_with_options("<test>", &ValidationOptions::default())
+            .is_ok());
+        assert_eq!(
+            Err(InvalidMetricWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "metric.system.network.io".to_owned(),
+                error: "The unit `By/s` of the gauge `system.network.io` is a rate, consider using a counter instead.".to_owned(),
+            }),
+            group.validate_with_options("<test>", &options)
+        );
+
+        // A gauge with a unit of information.
+        group.unit = Some("By".to_owned());
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+
+        // Counters are exempt.
+        group.unit = Some("By/s".to_owned());
+        group.instrument = Some(Counter);
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+        group.instrument = Some(UpDownCounter);
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+    }
+
+    #[test]
+    fn test_validate_allowed_tags() {
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+attributes:
+  - id: test.secret
+    type: string
+    brief: A secret.
+    stability: stable
+    examples: [foo]
+    tag: sensative
+  - id: test.name
+    type: string
+    brief: A name.
+    stability: stable
+    examples: [foo]
+    tag: experimental-telemetry
+"#;
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+
+        // The check is skipped when no taxonomy is configured.
+        let no_taxonomy = ValidationOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(group.validate_with_options("<test>", &no_taxonomy).is_ok());
+
+        let options = ValidationOptions {
+            allowed_tags: ["sensitive", "experimental-telemetry"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            ..no_taxonomy
+        };
+        assert_eq!(
+            Err(InvalidAttributeWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                attribute_id: "test.secret".to_owned(),
+                error: "The tag `sensative` is not one of the allowed tags: `experimental-telemetry`, `sensitive`.".to_owned(),
+                path: "/attributes/0/tag".into(),
+            }),
+            group.validate_with_options("<test>", &options)
+        );
+
+        // The disallowed tag is only a warning.
+        let lenient = ValidationOptions {
+            strict: false,
+            ..options.clone()
+        };
+        assert!(group.validate_with_options("<test>", &lenient).is_ok());
+
+        // Both tags are allowed.
+        let mut group = group;
+        if let AttributeSpec::Id { tag, .. } = &mut group.attributes[0] {
+            *tag = Some("sensitive".to_owned());
+        }
+        assert!(group.validate_with_options("<test>", &options).is_ok());
+    }
+
+    #[test]
+    fn test_validate_max_examples() {
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+attributes:
+  - id: test.method
+    type: string
+    brief: A method.
+    stability: stable
+    examples: [GET, POST, PUT]
+  - id: test.name
+    type: string
+    brief: A name.
+    stability: stable
+    examples: foo
+"#;
+        let group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+
+        // The number of examples is unlimited by default.
+        let unlimited = ValidationOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(group.validate_with_options("<test>", &unlimited).is_ok());
+
+        let under_limit = ValidationOptions {
+            max_examples: Some(3),
+            ..unlimited.clone()
+        };
+        assert!(group.validate_with_options("<test>", &under_limit).is_ok());
+
+        // The scalar example of `test.name` is never flagged.
+        let over_limit = ValidationOptions {
+            max_examples: Some(0),
+            ..unlimited
+        };
+        assert_eq!(
+            Err(InvalidAttributeWarning {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+                attribute_id: "test.method".to_owned(),
+                error: "This attribute defines 3 examples, more than the maximum of 0 allowed."
+                    .to_owned(),
+                path: "/attributes/0/examples".into(),
+            }),
+            group.validate_with_options("<test>", &over_limit)
+        );
+    }
+
+    #[test]
+    fn test_instrument_from_str() {
+        for instrument in [UpDownCounter, Counter, Gauge, Histogram] {
+            assert_eq!(instrument.to_string().parse(), Ok(instrument));
+        }
+        assert_eq!("updowncounter".parse(), Ok(UpDownCounter));
+        assert_eq!(
+            "up_down_counter".parse::<InstrumentSpec>(),
+            Err(Error::UnknownInstrument {
+                instrument: "up_down_counter".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_unused_prefix() {
+        let yaml = r#"
+id: registry.test
+type: attribute_group
+brief: A test group.
+prefix: test
+attributes:
+  - id: test.retry
+    type: int
+    brief: The retry count.
+    stability: stable
+  - ref: server.address
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+
+        // The prefix is used by the `Id` attribute.
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidGroupUsesPrefix {
+                path_or_url: "<test>".to_owned(),
+                group_id: "registry.test".to_owned(),
+            }),
+            result
+        );
+
+        // Only `Ref` attributes remain, the prefix is unused.
+        _ = group.attributes.remove(0);
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(CompoundError(vec![
+                InvalidGroupUsesPrefix {
+                    path_or_url: "<test>".to_owned(),
+                    group_id: "registry.test".to_owned(),
+                },
+                InvalidGroupUnusedPrefix {
+                    path_or_url: "<test>".to_owned(),
+                    group_id: "registry.test".to_owned(),
+                },
+            ])),
+            result
+        );
+    }
+
+    #[test]
+    fn test_validate_prefix_empty_segment() {
+        let yaml = r#"
+id: registry.http
+type: attribute_group
+brief: HTTP attributes.
+prefix: http
+attributes:
+  - id: method
+    type: string
+    brief: The HTTP method.
+    stability: stable
+    examples: [GET]
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        let prefix_warning = InvalidGroupUsesPrefix {
+            path_or_url: "<test>".to_owned(),
+            group_id: "registry.http".to_owned(),
+        };
+
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(Err(prefix_warning.clone()), result);
+
+        for (prefix, id, name) in [
+            ("http.", "method", "http..method"),
+            ("http", ".method", "http..method"),
+        ] {
+            group.prefix = prefix.to_owned();
+            let AttributeSpec::Id { id: attr_id, .. } = &mut group.attributes[0] else {
+                panic!("Expected an attribute definition");
+            };
+            *attr_id = id.to_owned();
+            let result = group.validate("<test>").into_result_failing_non_fatal();
+            assert_eq!(
+                Err(CompoundError(vec![
+                    prefix_warning.clone(),
+                    InvalidAttribute {
+                        path_or_url: "<test>".to_owned(),
+                        group_id: "registry.http".to_owned(),
+                        attribute_id: id.to_owned(),
+                        error: format!(
+                            "The effective name `{name}` of this attribute (prefix `{prefix}` + id `{id}`) contains an empty segment."
+                        ),
+                        path: "/attributes/0/id".into(),
+                    },
+                ])),
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_attributes_by_scope() {
+        let yaml = r#"
+id: span.test
+type: span
+brief: A test span.
+stability: stable
+span_kind: client
+attributes:
+  - ref: server.address
+  - ref: exception.type
+    scope: event
+  - ref: messaging.message.id
+    scope: link
+  - id: test.retry
+    type: int
+    brief: The retry count.
+    stability: stable
+    scope: span
+"#;
+        let mut group: GroupSpec = serde_yaml::from_str(yaml).expect("Failed to parse group");
+        assert!(group
+            .validate("<test>")
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        let scopes = group.attributes_by_scope();
+        let ids = |attrs: &[&AttributeSpec]| attrs.iter().map(|a| a.id()).collect::<Vec<_>>();
+        assert_eq!(ids(&scopes.span), vec!["server.address", "test.retry"]);
+        assert_eq!(ids(&scopes.event), vec!["exception.type"]);
+        assert_eq!(ids(&scopes.link), vec!["messaging.message.id"]);
+
+        // The scope field is only valid on span groups.
+        group.r#type = GroupType::AttributeGroup;
+        group.span_kind = None;
+        group.attributes.truncate(2);
+        let result = group.validate("<test>").into_result_failing_non_fatal();
+        assert_eq!(
+            Err(InvalidAttribute {
+                path_or_url: "<test>".to_owned(),
+                group_id: "span.test".to_owned(),
+                attribute_id: "exception.type".to_owned(),
+                error:
+                    "This attribute contains a scope field but the group type is not set to span."
+                        .to_owned(),
+                path: "/attributes/1/scope".into(),
+            }),
+            result
+        );
+    }
+
+    /// Tricky texts for YAML, mixed with arbitrary ones by the generators.
+    const TRICKY_TEXTS: &[&str] = &[
+        "",
+        "true",
+        "null",
+        "~",
+        "1.0",
+        "0x10",
+        "- item",
+        "key: value",
+        "# comment",
+        " leading and trailing space ",
+        "multi\nline\n",
+        "'quoted'",
+    ];
+
+    fn arbitrary_text(u: &mut Unstructured<'_>) -> arbitrary::Result<String> {
+        if u.arbitrary()? {
+            Ok((*u.choose(TRICKY_TEXTS)?).to_owned())
+        } else {
+            u.arbitrary()
+        }
+    }
+
+    fn arbitrary_option<T>(
+        u: &mut Unstructured<'_>,
+        f: impl FnOnce(&mut Unstructured<'_>) -> arbitrary::Result<T>,
+    ) -> arbitrary::Result<Option<T>> {
+        Ok(if u.arbitrary()? { Some(f(u)?) } else { None })
+    }
+
+    fn arbitrary_vec<T>(
+        u: &mut Unstructured<'_>,
+        mut f: impl FnMut(&mut Unstructured<'_>) -> arbitrary::Result<T>,
+    ) -> arbitrary::Result<Vec<T>> {
+        (0..u.int_in_range(0..=3)?).map(|_| f(u)).collect()
+    }
+
+    #[allow(deprecated)]
+    fn arbitrary_stability(u: &mut Unstructured<'_>) -> arbitrary::Result<Stability> {
+        Ok(u.choose(&[
+            Stability::Deprecated,
+            Stability::Stable,
+            Stability::Development,
+            Stability::Alpha,
+            Stability::Beta,
+            Stability::ReleaseCandidate,
+        ])?
+        .clone())
+    }
+
+    fn arbitrary_deprecated(u: &mut Unstructured<'_>) -> arbitrary::Result<Deprecated> {
+        let note = arbitrary_text(u)?;
+        Ok(match u.int_in_range(0..=3)? {
+            0 => Deprecated::Renamed {
+                renamed_to: arbitrary_text(u)?,
+                note,
+            },
+            1 => Deprecated::Obsoleted { note },
+            2 => Deprecated::Uncategorized { note },
+            _ => Deprecated::Unspecified { note },
+        })
+    }
+
+    fn arbitrary_requirement_level(
+        u: &mut Unstructured<'_>,
+    ) -> arbitrary::Result<RequirementLevel> {
+        Ok(match u.int_in_range(0..=5)? {
+            0 => RequirementLevel::Basic(BasicRequirementLevelSpec::Required),
+            1 => RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended),
+            2 => RequirementLevel::Basic(BasicRequirementLevelSpec::OptIn),
+            3 => RequirementLevel::ConditionallyRequired {
+                text: arbitrary_text(u)?,
+                condition: None,
+            },
+            4 => RequirementLevel::Recommended {
+                text: arbitrary_text(u)?,
+            },
+            _ => RequirementLevel::OptIn {
+                text: arbitrary_text(u)?,
+            },
+        })
+    }
+
+    fn arbitrary_examples(u: &mut Unstructured<'_>) -> arbitrary::Result<Examples> {
+        Ok(match u.int_in_range(0..=3)? {
+            0 => Examples::Bool(u.arbitrary()?),
+            1 => Examples::Int(u.arbitrary()?),
+            2 => Examples::String(arbitrary_text(u)?),
+            // An empty sequence of examples is parsed as integers.
+            _ => Examples::Strings(
+                std::iter::once(arbitrary_text(u)?)
+                    .chain(arbitrary_vec(u, arbitrary_text)?)
+                    .collect(),
+            ),
+        })
+    }
+
+    fn arbitrary_annotations(
+        u: &mut Unstructured<'_>,
+    ) -> arbitrary::Result<BTreeMap<String, YamlValue>> {
+        arbitrary_vec(u, |u| {
+            let value = YamlValue(serde_yaml::Value::String(arbitrary_text(u)?));
+            Ok((arbitrary_text(u)?, value))
+        })
+        .map(|entries| entries.into_iter().collect())
+    }
+
+    fn arbitrary_attribute(u: &mut Unstructured<'_>) -> arbitrary::Result<AttributeSpec> {
+        let role = arbitrary_option(u, |u| {
+            Ok(
+                u.choose(&[AttributeRole::Identifying, AttributeRole::Descriptive])?
+                    .clone(),
+            )
+        })?;
+        let scope = arbitrary_option(u, |u| {
+            Ok(u.choose(&[
+                AttributeScope::Span,
+                AttributeScope::Event,
+                AttributeScope::Link,
+            ])?
+            .clone())
+        })?;
+        Ok(if u.arbitrary()? {
+            AttributeSpec::Ref {
+                r#ref: arbitrary_text(u)?,
+                brief: arbitrary_option(u, arbitrary_text)?,
+                examples: arbitrary_option(u, arbitrary_examples)?,
+                tag: arbitrary_option(u, arbitrary_text)?,
+                requirement_level: arbitrary_option(u, arbitrary_requirement_level)?,
+                sampling_relevant: u.arbitrary()?,
+                note: arbitrary_option(u, arbitrary_text)?,
+                examples_note: None,
+                stability: arbitrary_option(u, arbitrary_stability)?,
+                deprecated: arbitrary_option(u, arbitrary_deprecated)?,
+                prefix: u.arbitrary()?,
+                annotations: arbitrary_option(u, arbitrary_annotations)?,
+                role,
+                scope,
+            }
+        } else {
+            let r#type = match u.int_in_range(0..=2)? {
+                0 => AttributeType::PrimitiveOrArray(
+                    u.choose(&[
+                        PrimitiveOrArrayTypeSpec::Boolean,
+                        PrimitiveOrArrayTypeSpec::Int,
+                        PrimitiveOrArrayTypeSpec::Double,
+                        PrimitiveOrArrayTypeSpec::String,
+                        PrimitiveOrArrayTypeSpec::Any,
+                        PrimitiveOrArrayTypeSpec::Strings,
+                        PrimitiveOrArrayTypeSpec::Ints,
+                        PrimitiveOrArrayTypeSpec::Doubles,
+                        PrimitiveOrArrayTypeSpec::Booleans,
+                    ])?
+                    .clone(),
+                ),
+                1 => AttributeType::Template(TemplateTypeSpec::String),
+                _ => AttributeType::Enum {
+                    members: arbitrary_vec(u, |u| {
+                        Ok(EnumEntriesSpec {
+                            id: arbitrary_text(u)?,
+                            value: ValueSpec::String(arbitrary_text(u)?),
+                            brief: arbitrary_option(u, arbitrary_text)?,
+                            note: arbitrary_option(u, arbitrary_text)?,
+                            stability: arbitrary_option(u, arbitrary_stability)?,
+                            deprecated: arbitrary_option(u, arbitrary_deprecated)?,
+                            annotations: None,
+                        })
+                    })?,
+                },
+            };
+            AttributeSpec::Id {
+                id: arbitrary_text(u)?,
+                renamed_from: None,
+                r#type,
+                brief: arbitrary_option(u, arbitrary_text)?,
+                examples: arbitrary_option(u, arbitrary_examples)?,
+                tag: arbitrary_option(u, arbitrary_text)?,
+                requirement_level: arbitrary_requirement_level(u)?,
+                sampling_relevant: u.arbitrary()?,
+                note: arbitrary_text(u)?,
+                examples_note: None,
+                stability: arbitrary_option(u, arbitrary_stability)?,
+                deprecated: arbitrary_option(u, arbitrary_deprecated)?,
+                annotations: arbitrary_option(u, arbitrary_annotations)?,
+                role,
+                scope,
+            }
+        })
+    }
+
+    /// Generates an arbitrary group, the fields that are never serialized are
+    /// left empty.
+    fn arbitrary_group(u: &mut Unstructured<'_>) -> arbitrary::Result<GroupSpec> {
+        Ok(GroupSpec {
+            id: arbitrary_text(u)?,
+            renamed_from: None,
+            r#type: u
+                .choose(&[
+                    GroupType::AttributeGroup,
+                    GroupType::Span,
+                    GroupType::Event,
+                    GroupType::Metric,
+                    GroupType::MetricGroup,
+                    GroupType::Entity,
+                    GroupType::Scope,
+                    GroupType::Undefined,
+                ])?
+                .clone(),
+            brief: arbitrary_text(u)?,
+            note: arbitrary_text(u)?,
+            prefix: arbitrary_text(u)?,
+            extends: arbitrary_option(u, arbitrary_text)?,
+            stability: arbitrary_option(u, arbitrary_stability)?,
+            deprecated: arbitrary_option(u, arbitrary_deprecated)?,
+            attributes: arbitrary_vec(u, arbitrary_attribute)?,
+            span_kind: arbitrary_option(u, |u| {
+                Ok(u.choose(&[
+                    SpanKindSpec::Internal,
+                    SpanKindSpec::Client,
+                    SpanKindSpec::Server,
+                    SpanKindSpec::Producer,
+                    SpanKindSpec::Consumer,
+                ])?
+                .clone())
+            })?,
+            events: arbitrary_vec(u, arbitrary_text)?,
+            metric_name: arbitrary_option(u, arbitrary_text)?,
+            instrument: arbitrary_option(u, |u| {
+                Ok(u.choose(&[UpDownCounter, Counter, Gauge, Histogram])?
+                    .clone())
+            })?,
+            unit: arbitrary_option(u, arbitrary_text)?,
+            name: arbitrary_option(u, arbitrary_text)?,
+            display_name: arbitrary_option(u, arbitrary_text)?,
+            body: arbitrary_option(u, |u| {
+                Ok(AnyValueSpec::String {
+                    common: AnyValueCommonSpec {
+                        id: arbitrary_text(u)?,
+                        brief: arbitrary_text(u)?,
+                        note: arbitrary_text(u)?,
+                        stability: arbitrary_option(u, arbitrary_stability)?,
+                        examples: arbitrary_option(u, arbitrary_examples)?,
+                        requirement_level: arbitrary_requirement_level(u)?,
+                    },
+                })
+            })?,
+            annotations: arbitrary_option(u, arbitrary_annotations)?,
+            entity_associations: arbitrary_vec(u, arbitrary_text)?,
+            include_groups: vec![],
+            visibility: None,
+        })
+    }
+
+    #[test]
+    fn test_group_round_trip() {
+        // A deterministic xorshift generator feeds the arbitrary groups.
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut bytes = vec![0u8; 1024];
+        for _ in 0..256 {
+            for byte in &mut bytes {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                *byte = (state >> 56) as u8;
+            }
+            let group = arbitrary_group(&mut Unstructured::new(&bytes))
+                .expect("Failed to generate a group");
+            if let Err(e) = group.assert_round_trip() {
+                panic!("{e}");
+            }
+        }
+
+        // The fields that are never serialized are ignored.
+        let group = GroupSpec {
+            id: "test".to_owned(),
+            include_groups: vec!["other".to_owned()],
+            visibility: Some(AttributeGroupVisibilitySpec::Public),
+            ..Default::default()
+        };
+        assert!(group.assert_round_trip().is_ok());
+    }
 }
@@ -0,0 +1,337 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! A streaming loader of the groups of a semantic convention file.
+//!
+//! Very large semantic convention files are not parsed as a whole. The block
+//! sequence of the top-level `groups` field is split on its item boundaries and
+//! each group is parsed on its own, so only one group is held in memory at a
+//! time.
+//!
+//! Note: Only the block style (`- id: ...`) of the `groups` sequence of the
+//! unversioned format is supported, the other top-level fields are ignored.
+
+use crate::group::{GroupSpec, GroupSpecWithProvenance};
+use crate::provenance::Provenance;
+use crate::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+use std::path::Path;
+
+/// Returns an iterator over the groups defined in a semantic convention file.
+///
+/// The groups are yielded in the order of the file and are not validated. An
+/// error is yielded if the file can't be read or a group can't be parsed, the
+/// iteration stops after an error.
+pub fn stream_groups<P: AsRef<Path>>(
+    registry_id: &str,
+    path: P,
+) -> impl Iterator<Item = Result<GroupSpecWithProvenance, Error>> {
+    let path = path.as_ref().display().to_string();
+    let provenance = Provenance::new(registry_id, &path);
+    let (lines, pending_error) = match File::open(&path) {
+        Ok(file) => (Some(BufReader::new(file).lines()), None),
+        Err(e) => (
+            None,
+            Some(Error::RegistryNotFound {
+                path_or_url: path,
+                error: e.to_string(),
+            }),
+        ),
+    };
+    GroupStream {
+        lines,
+        pending_error,
+        provenance,
+        state: StreamState::BeforeGroups,
+        item_indent: None,
+        chunk: String::new(),
+        chunk_line: 0,
+        line_number: 0,
+    }
+}
+
+/// The position of the reader in the semantic convention file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamState {
+    /// The `groups` field has not been found yet.
+    BeforeGroups,
+    /// The reader is within the `groups` sequence.
+    InGroups,
+    /// The `groups` sequence has been read.
+    Done,
+}
+
+/// An iterator over the groups of a semantic convention file.
+struct GroupStream<R: BufRead> {
+    /// The lines of the file, `None` if the file can't be read.
+    lines: Option<Lines<R>>,
+    /// An error to yield on the next call.
+    pending_error: Option<Error>,
+    /// The provenance of the groups.
+    provenance: Provenance,
+    /// The position of the reader.
+    state: StreamState,
+    /// The indentation of the items of the `groups` sequence.
+    item_indent: Option<usize>,
+    /// The lines of the group being read.
+    chunk: String,
+    /// The line number (1-based) of the first line of the chunk.
+    chunk_line: usize,
+    /// The number of lines read so far.
+    line_number: usize,
+}
+
+impl<R: BufRead> GroupStream<R> {
+    /// Parses the current chunk into a group.
+    fn take_group(&mut self) -> Option<Result<GroupSpecWithProvenance, Error>> {
+        if self.chunk.trim().is_empty() {
+            return None;
+        }
+        let chunk = std::mem::take(&mut self.chunk);
+        let result = serde_yaml::from_str::<Vec<GroupSpec>>(&chunk)
+            .map_err(|e| (e.to_string(), Some(e.into())))
+            .and_then(|mut groups| match (groups.pop(), groups.is_empty()) {
+                (Some(group), true) => Ok(group),
+                _ => Err(("Expected a single group.".to_owned(), None)),
+            });
+        Some(match result {
+            Ok(spec) => Ok(GroupSpecWithProvenance {
+                spec,
+                provenance: self.provenance.clone(),
+                base_provenance: None,
+            }),
+            Err((error, source)) => {
+                self.stop();
+                Err(Error::DeserializationError {
+                    path_or_url: self.provenance.path.clone(),
+                    error: format!("{error} (group starting at line {})", self.chunk_line),
+                    source,
+                })
+            }
+        })
+    }
+
+    /// Stops reading the file.
+    fn stop(&mut self) {
+        self.state = StreamState::Done;
+        self.lines = None;
+    }
+
+    /// Processes a line of the `groups` sequence, returns a group when the line
+    /// starts a new item or ends the sequence.
+    fn process_group_line(
+        &mut self,
+        line: String,
+    ) -> Option<Result<GroupSpecWithProvenance, Error>> {
+        let content = line.trim_start_matches(' ');
+        let trimmed = content.trim_end();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            if !self.chunk.is_empty() {
+                self.chunk.push_str(&line);
+                self.chunk.push('\n');
+            }
+            return None;
+        }
+
+        let indent = line.len() - content.len();
+        let is_item = trimmed == "-" || content.starts_with("- ");
+        let item_indent = match self.item_indent {
+            Some(item_indent) => item_indent,
+            None if is_item => *self.item_indent.insert(indent),
+            None => {
+                self.stop();
+                return Some(Err(Error::DeserializationError {
+                    path_or_url: self.provenance.path.clone(),
+                    error: format!(
+                        "Expected a block sequence of groups at line {}.",
+                        self.line_number
+                    ),
+                    source: None,
+                }));
+            }
+        };
+
+        if indent < item_indent || (indent == item_indent && !is_item) {
+            // End of the `groups` sequence.
+            self.stop();
+            return self.take_group();
+        }
+
+        let group = if indent == item_indent {
+            let group = self.take_group();
+            self.chunk_line = self.line_number;
+            group
+        } else {
+            None
+        };
+        self.chunk.push_str(&line);
+        self.chunk.push('\n');
+        group
+    }
+}
+
+impl<R: BufRead> Iterator for GroupStream<R> {
+    type Item = Result<GroupSpecWithProvenance, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(error) = self.pending_error.take() {
+            return Some(Err(error));
+        }
+
+        while self.state != StreamState::Done {
+            let line = match self.lines.as_mut().and_then(Iterator::next) {
+                Some(Ok(line)) => line,
+                Some(Err(e)) => {
+                    self.stop();
+                    return Some(Err(Error::DeserializationError {
+                        path_or_url: self.provenance.path.clone(),
+                        error: e.to_string(),
+                        source: None,
+                    }));
+                }
+                None => {
+                    self.state = StreamState::Done;
+                    return self.take_group();
+                }
+            };
+            self.line_number += 1;
+
+            match self.state {
+                StreamState::BeforeGroups => {
+                    if let Some(rest) = line.strip_prefix("groups:") {
+                        let rest = rest.trim();
+                        if !rest.is_empty() && !rest.starts_with('#') {
+                            self.stop();
+                            return Some(Err(Error::DeserializationError {
+                                path_or_url: self.provenance.path.clone(),
+                                error: format!(
+                                    "Expected a block sequence of groups at line {}.",
+                                    self.line_number
+                                ),
+                                source: None,
+                            }));
+                        }
+                        self.state = StreamState::InGroups;
+                    }
+                }
+                StreamState::InGroups => {
+                    if let Some(group) = self.process_group_line(line) {
+                        return Some(group);
+                    }
+                }
+                StreamState::Done => {}
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json_schema::JsonSchemaValidator;
+    use crate::semconv::SemConvSpecWithProvenance;
+    use std::fs;
+
+    #[test]
+    fn test_stream_groups() {
+        let dir = tempfile::tempdir().expect("Failed to create a temp dir");
+        let path = dir.path().join("groups.yaml");
+        fs::write(
+            &path,
+            r#"# A generated registry.
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: >
+      Session attributes.
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: development
+        examples: [abc]
+
+  # A span.
+  - id: span.session
+    type: span
+    brief: |
+      A session span.
+      - not an item
+    span_kind: internal
+    attributes:
+      - ref: session.id
+imports:
+  metrics:
+    - db.*
+"#,
+        )
+        .expect("Failed to write the file");
+
+        let mut groups = stream_groups("test", &path);
+        let group = groups.next().unwrap().expect("Failed to parse the group");
+        assert_eq!(group.spec.id, "registry.session");
+        assert_eq!(group.spec.brief, "Session attributes.\n");
+        assert_eq!(group.spec.attributes.len(), 1);
+        assert_eq!(
+            group.provenance,
+            Provenance::new("test", &path.display().to_string())
+        );
+        let group = groups.next().unwrap().expect("Failed to parse the group");
+        assert_eq!(group.spec.id, "span.session");
+        assert_eq!(group.spec.brief, "A session span.\n- not an item\n");
+        assert_eq!(group.provenance.registry_id.as_ref(), "test");
+        assert!(groups.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_groups_matches_full_parse() {
+        let spec = SemConvSpecWithProvenance::from_file(
+            "test",
+            "data/http.yaml",
+            &JsonSchemaValidator::new_unversioned(),
+            &JsonSchemaValidator::new_versioned(),
+        )
+        .into_result_failing_non_fatal()
+        .expect("Failed to load the spec");
+        let expected: Vec<_> = spec
+            .into_v1()
+            .spec
+            .groups
+            .into_iter()
+            .map(|g| g.id)
+            .collect();
+        let observed: Vec<_> = stream_groups("test", "data/http.yaml")
+            .map(|g| g.expect("Failed to parse the group").spec.id)
+            .collect();
+        assert!(!observed.is_empty());
+        assert_eq!(observed, expected);
+    }
+
+    #[test]
+    fn test_stream_groups_errors() {
+        let mut groups = stream_groups("test", "data/does-not-exist.yaml");
+        assert!(matches!(
+            groups.next(),
+            Some(Err(Error::RegistryNotFound { .. }))
+        ));
+        assert!(groups.next().is_none());
+
+        let dir = tempfile::tempdir().expect("Failed to create a temp dir");
+        let path = dir.path().join("groups.yaml");
+        fs::write(
+            &path,
+            "groups:\n  - id: first\n    type: [span]\n  - id: second\n    type: span\n",
+        )
+        .expect("Failed to write the file");
+        let mut groups = stream_groups("test", &path);
+        match groups.next() {
+            Some(Err(Error::DeserializationError { error, .. })) => {
+                assert!(error.ends_with("(group starting at line 2)"));
+            }
+            other => panic!("Expected a deserialization error, got {other:?}"),
+        }
+        assert!(groups.next().is_none());
+    }
+}
@@ -4,7 +4,7 @@
 
 use crate::semconv::{SemConvSpec, SemConvSpecV1, Versioned};
 use crate::Error::{CompoundError, InvalidSemConvSpec, InvalidXPath};
-use crate::{Error, InvalidSemConvSpecError};
+use crate::{Error, InvalidSemConvSpecError, YamlErrorSource};
 use itertools::Itertools;
 use jsonschema::error::{TypeKind, ValidationErrorKind};
 use jsonschema::{JsonType, JsonTypeSet};
@@ -70,17 +70,14 @@ impl JsonSchemaValidator {
         &self,
         yaml_value: serde_yaml::Value,
         provenance: &str,
-        serde_error: serde_yaml::Error,
+        serde_error: impl Into<YamlErrorSource>,
     ) -> Result<(), Error> {
         // Convert YAML value to JSON value as the validator works with JSON
         let json = match serde_json::to_value(yaml_value) {
             Ok(v) => v,
             Err(_) => {
                 // Fallback to original serde error
-                return Err(Error::DeserializationError {
-                    path_or_url: provenance.to_owned(),
-                    error: serde_error.to_string(),
-                });
+                return Err(Error::deserialization(provenance, serde_error));
             }
         };
 
@@ -390,6 +387,7 @@ impl YamlDoc {
             std::fs::read_to_string(path_or_url).map_err(|e| Error::DeserializationError {
                 path_or_url: path_or_url.to_owned(),
                 error: e.to_string(),
+                source: None,
             })?;
 
         Self::try_from_string(&raw_yaml, path_or_url)
@@ -403,11 +401,13 @@ impl YamlDoc {
             MarkedYaml::load_from_str(yaml).map_err(|e| Error::DeserializationError {
                 path_or_url: path_or_url.to_owned(),
                 error: e.to_string(),
+                source: None,
             })?;
         if yaml_docs.len() != 1 {
             return Err(Error::DeserializationError {
                 path_or_url: path_or_url.to_owned(),
                 error: "Expected exactly one YAML document".to_owned(),
+                source: None,
             });
         }
 
@@ -9,21 +9,30 @@ use schemars::{JsonSchema, SchemaGenerator};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::hash::Hasher;
+use std::ops::Deref;
 use std::path::PathBuf;
+use std::sync::Arc;
 use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
 use weaver_common::error::{format_errors, WeaverError};
 
 pub mod any_value;
 pub mod attribute;
+pub mod attribute_catalog;
+pub mod condition;
 pub mod deprecated;
+pub mod deprecation_report;
 pub mod group;
+pub mod group_stream;
 pub mod json_schema;
 pub mod manifest;
 pub mod metric;
+pub mod overlay;
 pub mod provenance;
 pub mod registry;
 pub mod registry_repo;
 pub mod semconv;
+pub mod source_map;
+pub mod spec_cache;
 pub mod stability;
 pub mod stats;
 pub mod v2;
@@ -69,6 +78,18 @@ pub enum Error {
         path_or_url: String,
         /// The error that occurred.
         error: String,
+        /// The underlying YAML error, if any.
+        #[serde(skip)]
+        source: Option<YamlErrorSource>,
+    },
+
+    /// A standalone attribute definition contains a field that is not part of
+    /// the attribute specification.
+    #[error("The attribute definition contains the unknown field `{field}`.")]
+    #[diagnostic(severity(Error))]
+    UnknownAttributeField {
+        /// The name of the unknown field.
+        field: String,
     },
 
     /// The semantic convention spec is invalid.
@@ -99,6 +120,19 @@ pub enum Error {
         error: String,
     },
 
+    /// The semantic convention spec contains a group definition that doesn't follow
+    /// the recommended conventions.
+    #[error("Invalid group '{group_id}' detected while resolving '{path_or_url:?}'. {error}")]
+    #[diagnostic(severity(Warning))]
+    InvalidGroupWarning {
+        /// The path or URL of the semantic convention asset.
+        path_or_url: String,
+        /// The group id.
+        group_id: String,
+        /// The reason of the warning.
+        error: String,
+    },
+
     /// The semantic convention spec contains a group with duplicate attribute references.
     #[error("Duplicate attribute refs for '{attribute_ref}' found on group '{group_id}' detected while resolving '{path_or_url:?}'.")]
     #[diagnostic(severity(Warning))]
@@ -170,6 +204,11 @@ pub enum Error {
         attribute_id: String,
         /// The reason of the error.
         error: String,
+        /// The JSON Pointer to the invalid element within the semantic convention
+        /// asset (e.g. `/groups/3/attributes/7/examples`).
+        ///
+        /// Note: A boxed str is used to keep the main `Error` type under the 128 bytes limit.
+        path: Box<str>,
     },
 
     /// The semantic convention asset contains an invalid attribute definition.
@@ -184,6 +223,9 @@ pub enum Error {
         attribute_id: String,
         /// The reason of the error.
         error: String,
+        /// The JSON Pointer to the invalid element within the semantic convention
+        /// asset (e.g. `/groups/3/attributes/7/examples`).
+        path: Box<str>,
     },
 
     /// This error occurs when a semantic convention asset contains an invalid example.
@@ -199,6 +241,9 @@ pub enum Error {
         attribute_id: String,
         /// The reason of the error.
         error: String,
+        /// The JSON Pointer to the invalid element within the semantic convention
+        /// asset (e.g. `/groups/3/attributes/7/examples`).
+        path: Box<str>,
     },
 
     /// This warning indicates that a semantic convention asset contains an invalid example.
@@ -215,6 +260,9 @@ pub enum Error {
         attribute_id: String,
         /// The reason of the error.
         error: String,
+        /// The JSON Pointer to the invalid element within the semantic convention
+        /// asset (e.g. `/groups/3/attributes/7/examples`).
+        path: Box<str>,
     },
 
     /// This warning indicates usage of `prefix` on a group.
@@ -228,6 +276,17 @@ pub enum Error {
         group_id: String,
     },
 
+    /// This warning indicates a group defines a `prefix` but has no attribute
+    /// definitions (`id`) that would use it.
+    #[error("The group `{group_id}` defines a prefix but no attribute that would use it.\nProvenance: {path_or_url:?}")]
+    #[diagnostic(severity(Warning))]
+    InvalidGroupUnusedPrefix {
+        /// The path or URL of the semantic convention asset.
+        path_or_url: String,
+        /// The group id of the attribute.
+        group_id: String,
+    },
+
     /// The semantic convention asset contains an invalid metric definition.
     #[error("Invalid metric definition in {path_or_url:?}.\ngroup_id=`{group_id}`. {error}")]
     InvalidMetric {
@@ -239,6 +298,27 @@ pub enum Error {
         error: String,
     },
 
+    /// The instrument is not one of the supported metric instruments.
+    #[error("The instrument `{instrument}` is unknown, expected one of `counter`, `updowncounter`, `gauge` or `histogram`.")]
+    #[diagnostic(severity(Error))]
+    UnknownInstrument {
+        /// The unknown instrument.
+        instrument: String,
+    },
+
+    /// The semantic convention asset contains a metric definition not following
+    /// the naming conventions.
+    #[error("Invalid metric definition in {path_or_url:?}.\ngroup_id=`{group_id}`. {error}")]
+    #[diagnostic(severity(Warning))]
+    InvalidMetricWarning {
+        /// The path or URL of the semantic convention asset.
+        path_or_url: String,
+        /// The group id of the metric.
+        group_id: String,
+        /// The reason of the warning.
+        error: String,
+    },
+
     /// This indicates that any_value is invalid.
     #[error("The value `{value_id}` in the group `{group_id}` is invalid. {error}\nProvenance: {path_or_url:?}")]
     #[diagnostic(severity(Warning))]
@@ -356,6 +436,69 @@ pub struct InvalidSemConvSpecError {
     pub advice: Option<String>,
 }
 
+/// A YAML (de)serialization error kept as the source of an [`Error`], so the
+/// callers can walk the error chain down to the [`serde_yaml::Error`].
+///
+/// The error is shared to keep [`Error`] cloneable, two sources are equal when
+/// their messages are equal.
+#[derive(Debug, Clone)]
+pub struct YamlErrorSource(Arc<serde_yaml::Error>);
+
+impl From<serde_yaml::Error> for YamlErrorSource {
+    fn from(error: serde_yaml::Error) -> Self {
+        YamlErrorSource(Arc::new(error))
+    }
+}
+
+/// Dereferences to the YAML error, which is returned as is by
+/// [`std::error::Error::source`].
+impl Deref for YamlErrorSource {
+    type Target = serde_yaml::Error;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl PartialEq for YamlErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Error {
+    /// Creates a [`Error::DeserializationError`] from the given YAML error.
+    #[must_use]
+    pub fn deserialization(path_or_url: &str, error: impl Into<YamlErrorSource>) -> Self {
+        let source = error.into();
+        Error::DeserializationError {
+            path_or_url: path_or_url.to_owned(),
+            error: source.to_string(),
+            source: Some(source),
+        }
+    }
+
+    /// Prefixes the JSON Pointer path of this error (if any) with the given pointer
+    /// (e.g. `/groups/3`). Compound errors are prefixed recursively.
+    #[must_use]
+    pub(crate) fn with_path_prefix(mut self, prefix: &str) -> Self {
+        match &mut self {
+            Error::InvalidAttribute { path, .. }
+            | Error::InvalidAttributeWarning { path, .. }
+            | Error::InvalidExampleError { path, .. }
+            | Error::InvalidExampleWarning { path, .. } => *path = format!("{prefix}{path}").into(),
+            CompoundError(errors) => {
+                *errors = std::mem::take(errors)
+                    .into_iter()
+                    .map(|e| e.with_path_prefix(prefix))
+                    .collect();
+            }
+            _ => {}
+        }
+        self
+    }
+}
+
 impl WeaverError<Error> for Error {
     fn compound(errors: Vec<Error>) -> Error {
         CompoundError(
@@ -385,6 +528,77 @@ impl From<Error> for DiagnosticMessages {
     }
 }
 
+/// A validation finding in a flat form suited to machine consumption (e.g. to
+/// post inline annotations from a CI job).
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ErrorReport {
+    /// The severity of the finding (`error`, `warning` or `advice`).
+    pub severity: &'static str,
+    /// The kind of the finding, i.e. the name of the error variant (e.g.
+    /// `InvalidGroup`).
+    pub kind: String,
+    /// The path or URL of the semantic convention asset (if known).
+    pub path_or_url: Option<String>,
+    /// The id of the group (if known).
+    pub group_id: Option<String>,
+    /// The id of the attribute (if any).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_id: Option<String>,
+    /// The human readable message of the finding.
+    pub message: String,
+    /// The JSON Pointer to the invalid element within the semantic convention
+    /// asset (if known).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl Error {
+    /// Returns the reports of this error, one per finding. Compound errors are
+    /// flattened recursively.
+    #[must_use]
+    pub fn reports(&self) -> Vec<ErrorReport> {
+        if let CompoundError(errors) = self {
+            return errors.iter().flat_map(Error::reports).collect();
+        }
+
+        // The externally tagged serialization of the error gives the name of the
+        // variant and its fields.
+        let (kind, fields) = match serde_json::to_value(self) {
+            Ok(serde_json::Value::Object(map)) => map.into_iter().next().unwrap_or_default(),
+            Ok(serde_json::Value::String(kind)) => (kind, serde_json::Value::Null),
+            _ => (String::new(), serde_json::Value::Null),
+        };
+        let field = |name: &str| {
+            fields
+                .get(name)
+                .and_then(serde_json::Value::as_str)
+                .map(ToOwned::to_owned)
+        };
+
+        vec![ErrorReport {
+            severity: match self.severity() {
+                Some(miette::Severity::Advice) => "advice",
+                Some(miette::Severity::Warning) => "warning",
+                Some(miette::Severity::Error) | None => "error",
+            },
+            path_or_url: field("path_or_url"),
+            group_id: field("group_id"),
+            attribute_id: field("attribute_id"),
+            message: self.to_string(),
+            location: field("path"),
+            kind,
+        }]
+    }
+}
+
+/// Serializes the findings of the given errors as a JSON array of
+/// [`ErrorReport`]s, compound errors are flattened.
+#[must_use]
+pub fn errors_to_json(errors: &[Error]) -> serde_json::Value {
+    let reports: Vec<_> = errors.iter().flat_map(Error::reports).collect();
+    serde_json::to_value(reports).unwrap_or_default()
+}
+
 /// Create a newtype wrapper for serde_yaml::value::Value in order to implement
 /// JsonSchema for it.
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -542,6 +756,9 @@ impl std::hash::Hash for YamlValue {
 
 #[cfg(test)]
 mod tests {
+    use crate::errors_to_json;
+    use crate::group::GroupSpec;
+    use crate::provenance::Provenance;
     use crate::registry::SemConvRegistry;
     use std::vec;
     use weaver_common::diagnostic::DiagnosticMessages;
@@ -570,4 +787,57 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_errors_to_json() {
+        let group: GroupSpec = serde_yaml::from_str(
+            r#"
+id: span.test
+type: span
+brief: A test span.
+stability: development
+unit: s
+attributes:
+  - ref: test.name
+"#,
+        )
+        .expect("Failed to parse group");
+        let error = group
+            .validate("test.yaml")
+            .into_result_failing_non_fatal()
+            .expect_err("The group should be invalid");
+
+        let json = errors_to_json(&[error]);
+        let reports = json.as_array().expect("An array of reports is expected");
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0]["kind"], "InvalidSpanMissingSpanKind");
+        assert_eq!(reports[0]["severity"], "warning");
+        assert_eq!(reports[1]["kind"], "InvalidGroup");
+        assert_eq!(reports[1]["severity"], "error");
+        for report in reports {
+            assert_eq!(report["path_or_url"], "test.yaml");
+            assert_eq!(report["group_id"], "span.test");
+            assert!(report.get("attribute_id").is_none());
+            assert!(report["message"].as_str().is_some_and(|m| !m.is_empty()));
+        }
+    }
+
+    #[test]
+    fn test_deserialization_error_source() {
+        let mut registry = SemConvRegistry::new("test");
+        let error = registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "test.yaml"),
+                "groups:\n  - id: [not, a, string]\n",
+            )
+            .into_result_failing_non_fatal()
+            .expect_err("The spec should be invalid");
+
+        let source = std::error::Error::source(&error).expect("A source is expected");
+        let serde_error = source
+            .downcast_ref::<serde_yaml::Error>()
+            .expect("The source should be the serde error");
+        assert_eq!(error.to_string(), serde_error.to_string());
+        assert_eq!(error.clone(), error);
+    }
 }
@@ -8,11 +8,15 @@
 //! In the future, this struct may be extended to include additional information
 //! such as the registry's owner, maintainers, and dependencies.
 
+use crate::group::GroupSpecWithProvenance;
+use crate::json_schema::JsonSchemaValidator;
+use crate::semconv::{SemConvSpecV1WithProvenance, SemConvSpecWithProvenance};
 use crate::Error;
 use crate::Error::{InvalidRegistryManifest, RegistryManifestNotFound};
+use miette::Diagnostic;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use weaver_common::error::handle_errors;
 use weaver_common::vdir::VirtualDirectoryPath;
 
@@ -42,6 +46,12 @@ pub struct RegistryManifest {
     /// Note: In the current phase, we only support zero or one dependency.
     /// See this GH issue for more details: <https://github.com/open-telemetry/weaver/issues/604>
     pub dependencies: Option<Vec<Dependency>>,
+
+    /// The semantic convention files of the registry, as paths or glob patterns
+    /// relative to the manifest (see [`load_registry_from_manifest`]).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
 }
 
 /// Represents a dependency of a semantic convention registry.
@@ -57,7 +67,7 @@ impl RegistryManifest {
     /// Attempts to load a registry manifest from a file.
     ///
     /// The expected file format is YAML.
-    pub fn try_from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self, Error> {
+    pub fn try_from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
         let manifest_path_buf = path.as_ref().to_path_buf();
 
         if !manifest_path_buf.exists() {
@@ -112,6 +122,85 @@ impl RegistryManifest {
     }
 }
 
+/// Loads the groups of the semantic convention files listed in the `files`
+/// field of a registry manifest, with their provenance.
+///
+/// The paths and glob patterns are resolved relative to the manifest and the
+/// name of the registry is used as the registry id of the provenance. A listed
+/// file that doesn't exist, or a pattern matching no file, is reported with an
+/// [`Error::InvalidRegistryManifest`] naming the manifest.
+pub fn load_registry_from_manifest<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<GroupSpecWithProvenance>, Error> {
+    let manifest_path = path.as_ref().to_path_buf();
+    let manifest = RegistryManifest::try_from_file(&manifest_path)?;
+    let base_dir = manifest_path.parent().unwrap_or(Path::new(""));
+
+    let mut errors = vec![];
+    let mut files = vec![];
+    for entry in &manifest.files {
+        let file = base_dir.join(entry);
+        if !entry.contains(['*', '?', '[']) {
+            if file.is_file() {
+                files.push(file);
+            } else {
+                errors.push(InvalidRegistryManifest {
+                    path: manifest_path.clone(),
+                    error: format!(
+                        "The file `{}` listed in the manifest does not exist.",
+                        file.display()
+                    ),
+                });
+            }
+            continue;
+        }
+        let matches: Vec<_> = glob::glob(&file.display().to_string())
+            .map_err(|e| InvalidRegistryManifest {
+                path: manifest_path.clone(),
+                error: format!("The pattern `{entry}` listed in the manifest is invalid. {e}"),
+            })?
+            .filter_map(Result::ok)
+            .collect();
+        if matches.is_empty() {
+            errors.push(InvalidRegistryManifest {
+                path: manifest_path.clone(),
+                error: format!(
+                    "The pattern `{}` listed in the manifest matches no file.",
+                    file.display()
+                ),
+            });
+        }
+        files.extend(matches);
+    }
+    handle_errors(errors)?;
+
+    let versioned_validator = JsonSchemaValidator::new_versioned();
+    let unversioned_validator = JsonSchemaValidator::new_unversioned();
+    let mut groups = vec![];
+    for file in files {
+        let SemConvSpecV1WithProvenance { spec, provenance } =
+            SemConvSpecWithProvenance::from_file(
+                &manifest.name,
+                &file,
+                &unversioned_validator,
+                &versioned_validator,
+            )
+            .ignore(|e| matches!(e.severity(), Some(miette::Severity::Warning)))
+            .into_result_failing_non_fatal()?
+            .into_v1();
+        groups.extend(
+            spec.groups
+                .into_iter()
+                .map(|group| GroupSpecWithProvenance {
+                    spec: group,
+                    provenance: provenance.clone(),
+                    base_provenance: None,
+                }),
+        );
+    }
+    Ok(groups)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,4 +262,52 @@ mod tests {
             panic!("Expected an error, but got a result.");
         }
     }
+
+    #[test]
+    fn test_load_registry_from_manifest() {
+        let groups =
+            load_registry_from_manifest("tests/test_data/manifest_registry/registry_manifest.yaml")
+                .expect("Failed to load the registry from its manifest.");
+        let groups: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                (
+                    group.spec.id.as_str(),
+                    group.provenance.registry_id.as_ref(),
+                    group.provenance.path.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                (
+                    "registry.acme.http",
+                    "acme",
+                    "tests/test_data/manifest_registry/http.yaml"
+                ),
+                (
+                    "metric.acme.http.server.duration",
+                    "acme",
+                    "tests/test_data/manifest_registry/metrics/http.yaml"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_registry_from_manifest_missing_file() {
+        let result = load_registry_from_manifest(
+            "tests/test_data/manifest_registry/registry_manifest_missing_file.yaml",
+        );
+        assert_eq!(
+            result.map(|groups| groups.len()),
+            Err(InvalidRegistryManifest {
+                path: PathBuf::from(
+                    "tests/test_data/manifest_registry/registry_manifest_missing_file.yaml"
+                ),
+                error: "The file `tests/test_data/manifest_registry/missing.yaml` listed in the manifest does not exist.".to_owned(),
+            })
+        );
+    }
 }
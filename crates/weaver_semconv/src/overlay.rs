@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Layering of a local overlay registry on top of a base registry, e.g. to
+//! add vendor specific groups to the upstream semantic conventions without
+//! forking them.
+
+use crate::group::GroupSpecWithProvenance;
+use crate::Error;
+use std::collections::HashMap;
+use weaver_common::error::handle_errors;
+
+/// How an overlay group is combined with the base group having the same id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlayMode {
+    /// The overlay group replaces the base group.
+    #[default]
+    Replace,
+    /// The attributes and included groups of the overlay group are merged into
+    /// the base group, an overlay attribute replaces the base attribute with
+    /// the same id. The other fields of the base group are kept.
+    Extend,
+}
+
+/// Layers the overlay groups on the base groups.
+///
+/// The groups of the base keep their order, the overlay groups with a new id
+/// are appended. An overlay group with the id of a base group is combined
+/// with it according to `mode` and keeps the provenance of the base group in
+/// [`GroupSpecWithProvenance::base_provenance`].
+///
+/// Returns an [`Error::InvalidGroup`] for each overlay group whose type
+/// differs from the type of the base group.
+pub fn overlay_registry(
+    base: &[GroupSpecWithProvenance],
+    overlay: &[GroupSpecWithProvenance],
+    mode: OverlayMode,
+) -> Result<Vec<GroupSpecWithProvenance>, Error> {
+    let mut groups = base.to_vec();
+    let index_by_id: HashMap<String, usize> = groups
+        .iter()
+        .enumerate()
+        .map(|(index, group)| (group.spec.id.clone(), index))
+        .collect();
+    let mut errors = vec![];
+
+    for overlay_group in overlay {
+        let Some(&index) = index_by_id.get(&overlay_group.spec.id) else {
+            groups.push(overlay_group.clone());
+            continue;
+        };
+        let base_group = &mut groups[index];
+        if base_group.spec.r#type != overlay_group.spec.r#type {
+            errors.push(Error::InvalidGroup {
+                path_or_url: overlay_group.provenance.path.clone(),
+                group_id: overlay_group.spec.id.clone(),
+                error: format!(
+                    "This group is of type `{}` but overlays a group of type `{}` defined in {}.",
+                    overlay_group.spec.r#type, base_group.spec.r#type, base_group.provenance
+                ),
+            });
+            continue;
+        }
+
+        let base_provenance = Some(base_group.provenance.clone());
+        match mode {
+            OverlayMode::Replace => {
+                *base_group = GroupSpecWithProvenance {
+                    base_provenance,
+                    ..overlay_group.clone()
+                };
+            }
+            OverlayMode::Extend => {
+                let spec = &mut base_group.spec;
+                for attribute in &overlay_group.spec.attributes {
+                    match spec
+                        .attributes
+                        .iter_mut()
+                        .find(|base_attribute| base_attribute.id() == attribute.id())
+                    {
+                        Some(base_attribute) => *base_attribute = attribute.clone(),
+                        None => spec.attributes.push(attribute.clone()),
+                    }
+                }
+                for include_group in &overlay_group.spec.include_groups {
+                    if !spec.include_groups.contains(include_group) {
+                        spec.include_groups.push(include_group.clone());
+                    }
+                }
+                base_group.provenance = overlay_group.provenance.clone();
+                base_group.base_provenance = base_provenance;
+            }
+        }
+    }
+
+    handle_errors(errors)?;
+    Ok(groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provenance::Provenance;
+    use crate::registry::SemConvRegistry;
+
+    fn groups(path: &str, yaml: &str) -> Vec<GroupSpecWithProvenance> {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(Provenance::new("test", path), yaml)
+            .into_result_failing_non_fatal()
+            .expect("Failed to add the spec");
+        registry.unresolved_group_with_provenance_iter().collect()
+    }
+
+    fn base() -> Vec<GroupSpecWithProvenance> {
+        groups(
+            "base.yaml",
+            r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: http.request.method
+"#,
+        )
+    }
+
+    #[test]
+    fn test_overlay_new_group() {
+        let overlay = groups(
+            "overlay.yaml",
+            r#"
+groups:
+  - id: registry.acme
+    type: attribute_group
+    brief: ACME attributes.
+    attributes:
+      - id: acme.tenant
+        type: string
+        brief: The tenant.
+        stability: development
+        examples: [blue]
+"#,
+        );
+
+        let groups = overlay_registry(&base(), &overlay, OverlayMode::Replace).unwrap();
+        let ids: Vec<_> = groups.iter().map(|group| group.spec.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["registry.http", "span.http.client", "registry.acme"]
+        );
+        assert_eq!(
+            groups[2].provenance,
+            Provenance::new("test", "overlay.yaml")
+        );
+        assert_eq!(groups[2].base_provenance, None);
+    }
+
+    #[test]
+    fn test_overlay_existing_group() {
+        let overlay = groups(
+            "overlay.yaml",
+            r#"
+groups:
+  - id: span.http.client
+    type: span
+    brief: An ACME HTTP client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: http.request.method
+        requirement_level: required
+      - ref: acme.tenant
+"#,
+        );
+
+        let replaced = overlay_registry(&base(), &overlay, OverlayMode::Replace).unwrap();
+        assert_eq!(replaced.len(), 2);
+        assert_eq!(replaced[1].spec, overlay[0].spec);
+        assert_eq!(
+            replaced[1].provenance,
+            Provenance::new("test", "overlay.yaml")
+        );
+        assert_eq!(
+            replaced[1].base_provenance,
+            Some(Provenance::new("test", "base.yaml"))
+        );
+
+        let mut base = base();
+        base[1]
+            .spec
+            .attributes
+            .push(crate::attribute::parse_attribute("ref: http.response.status_code").unwrap());
+        let extended = overlay_registry(&base, &overlay, OverlayMode::Extend).unwrap();
+        let span = &extended[1];
+        assert_eq!(span.spec.brief, "An HTTP client span.");
+        let attributes: Vec<_> = span
+            .spec
+            .attributes
+            .iter()
+            .map(|attribute| (attribute.id(), attribute.requirement_level().to_string()))
+            .collect();
+        assert_eq!(
+            attributes,
+            vec![
+                ("http.request.method".to_owned(), "required".to_owned()),
+                (
+                    "http.response.status_code".to_owned(),
+                    "recommended".to_owned()
+                ),
+                ("acme.tenant".to_owned(), "recommended".to_owned()),
+            ]
+        );
+        assert_eq!(span.provenance, Provenance::new("test", "overlay.yaml"));
+        assert_eq!(
+            span.base_provenance,
+            Some(Provenance::new("test", "base.yaml"))
+        );
+    }
+
+    #[test]
+    fn test_overlay_type_conflict() {
+        let overlay = groups(
+            "overlay.yaml",
+            r#"
+groups:
+  - id: span.http.client
+    type: event
+    name: http.client
+    brief: An HTTP client event.
+    stability: stable
+"#,
+        );
+
+        for mode in [OverlayMode::Replace, OverlayMode::Extend] {
+            assert_eq!(
+                overlay_registry(&base(), &overlay, mode).unwrap_err(),
+                Error::InvalidGroup {
+                    path_or_url: "overlay.yaml".to_owned(),
+                    group_id: "span.http.client".to_owned(),
+                    error: "This group is of type `event` but overlays a group of type `span` defined in test:base.yaml.".to_owned(),
+                }
+            );
+        }
+    }
+}
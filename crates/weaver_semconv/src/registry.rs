@@ -2,22 +2,36 @@
 
 //! Semantic Convention Registry.
 
-use crate::attribute::AttributeSpecWithProvenance;
-use crate::group::{GroupSpecWithProvenance, ImportsWithProvenance};
+use crate::attribute::{AttributeSpec, AttributeType, RequirementLevel};
+use crate::attribute_catalog::{
+    build_attribute_catalog, effective_attribute_id, find_unreferenced_groups,
+};
+use crate::group::{GroupSpecWithProvenance, GroupType, ImportsWithProvenance, MetricSignature};
 use crate::json_schema::JsonSchemaValidator;
 use crate::manifest::RegistryManifest;
-use crate::metric::MetricSpecWithProvenance;
 use crate::provenance::Provenance;
 use crate::registry_repo::RegistryRepo;
 use crate::semconv::{SemConvSpecV1WithProvenance, SemConvSpecWithProvenance};
+use crate::spec_cache::to_hex;
+use crate::stability::Stability;
 use crate::stats::Stats;
 use crate::Error;
+use miette::{Diagnostic, Severity};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use regex::Regex;
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 use std::sync::LazyLock;
 use weaver_common::result::WResult;
 
+/// The pattern of the references to other telemetry objects of the registry
+/// used in notes, e.g. `{{attr:http.request.method}}` or `{{group:registry.http}}`.
+///
+/// The first capture group is the kind of the target (`attr` or `group`), the
+/// second one is the id of the target.
+pub const NOTE_REFERENCE_PATTERN: &str = r"\{\{\s*(attr|group):\s*([^}\s]+)\s*\}\}";
+
 /// A semantic convention registry is a collection of semantic convention
 /// specifications indexed by group id.
 #[derive(Default, Debug)]
@@ -32,21 +46,28 @@ pub struct SemConvRegistry {
     /// A collection of semantic convention specifications loaded in the semantic convention registry.
     specs: Vec<SemConvSpecV1WithProvenance>,
 
-    /// Attributes indexed by their respective id independently of their
-    /// semantic convention group.
-    ///
-    /// This collection contains all the attributes defined in the semantic convention registry.
-    attributes: HashMap<String, AttributeSpecWithProvenance>,
-
-    /// Metrics indexed by their respective id.
-    ///
-    /// This collection contains all the metrics defined in the semantic convention registry.
-    metrics: HashMap<String, MetricSpecWithProvenance>,
-
     /// The manifest of the semantic convention registry.
     manifest: Option<RegistryManifest>,
 }
 
+/// The fields that the definitions of an attribute shared by several groups
+/// must agree on: type, stability and requirement level.
+type DefinitionFields<'a> = (
+    &'a AttributeType,
+    &'a Option<Stability>,
+    &'a RequirementLevel,
+);
+
+/// The number of attribute ids rewritten by
+/// [`SemConvRegistry::rewrite_attribute_namespace`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NamespaceRewriteReport {
+    /// The number of attribute definitions (`id` fields) rewritten.
+    pub definitions: usize,
+    /// The number of attribute references (`ref` fields) rewritten.
+    pub references: usize,
+}
+
 impl SemConvRegistry {
     /// Create a new semantic convention registry.
     ///
@@ -81,25 +102,16 @@ impl SemConvRegistry {
             non_fatal_errors: &mut Vec<Error>,
         ) -> Result<SemConvRegistry, Error> {
             let mut registry = SemConvRegistry::new(registry_id);
-            let versioned_validator = JsonSchemaValidator::new_versioned();
-            let unversioned_validator = JsonSchemaValidator::new_unversioned();
-            for sc_entry in
+            let entries =
                 glob::glob(path_pattern).map_err(|e| Error::InvalidRegistryPathPattern {
                     path_pattern: path_pattern.to_owned(),
                     error: e.to_string(),
-                })?
-            {
-                let path_buf = sc_entry.map_err(|e| Error::InvalidRegistryPathPattern {
-                    path_pattern: path_pattern.to_owned(),
-                    error: e.to_string(),
                 })?;
-                let (semconv_spec, nfes) = SemConvSpecWithProvenance::from_file(
-                    registry_id,
-                    path_buf.as_path(),
-                    &unversioned_validator,
-                    &versioned_validator,
-                )
-                .into_result_with_non_fatal()?;
+            // The first failure, in the order of the files, is the one reported.
+            for result in
+                SemConvRegistry::semconv_specs_from_glob(registry_id, path_pattern, entries)
+            {
+                let (semconv_spec, nfes) = result.into_result_with_non_fatal()?;
                 registry.add_semconv_spec(semconv_spec);
                 non_fatal_errors.extend(nfes);
             }
@@ -109,11 +121,105 @@ impl SemConvRegistry {
         let mut non_fatal_errors = vec![];
 
         match create_registry_or_fatal(registry_id, path_pattern, &mut non_fatal_errors) {
-            Ok(registry) => WResult::with_non_fatal_errors(registry, non_fatal_errors),
+            Ok(registry) => {
+                if let WResult::OkWithNFEs(_, errors) = registry.check_event_name_uniqueness() {
+                    non_fatal_errors.extend(errors);
+                }
+                if let WResult::OkWithNFEs(_, errors) = registry.check_note_references() {
+                    non_fatal_errors.extend(errors);
+                }
+                WResult::with_non_fatal_errors(registry, non_fatal_errors)
+            }
             Err(e) => WResult::FatalErr(e),
         }
     }
 
+    /// Creates a semantic convention registry from the given path pattern and
+    /// runs every registry check, collecting all the findings instead of
+    /// stopping at the first file failing to load.
+    ///
+    /// Unlike [`Self::try_from_path_pattern`], the files that can't be loaded
+    /// are reported and skipped, and all the registry checks run: event name
+    /// uniqueness, note references, unused groups, metric signatures and
+    /// attribute definition consistency. The findings are returned as non-fatal
+    /// errors, each one carrying the path of the file it comes from.
+    ///
+    /// # Errors
+    ///
+    /// If the registry path pattern is invalid.
+    pub fn validate_exhaustive(registry_id: &str, path_pattern: &str) -> WResult<Self, Error> {
+        let invalid_pattern = |error: String| Error::InvalidRegistryPathPattern {
+            path_pattern: path_pattern.to_owned(),
+            error,
+        };
+        let entries = match glob::glob(path_pattern) {
+            Ok(entries) => entries,
+            Err(e) => return WResult::FatalErr(invalid_pattern(e.to_string())),
+        };
+
+        let mut registry = SemConvRegistry::new(registry_id);
+        let mut errors = vec![];
+        for result in Self::semconv_specs_from_glob(registry_id, path_pattern, entries) {
+            match result {
+                WResult::Ok(semconv_spec) => registry.add_semconv_spec(semconv_spec),
+                WResult::OkWithNFEs(semconv_spec, nfes) => {
+                    registry.add_semconv_spec(semconv_spec);
+                    errors.extend(nfes);
+                }
+                WResult::FatalErr(Error::CompoundError(fatal_errors)) => {
+                    errors.extend(fatal_errors);
+                }
+                WResult::FatalErr(e) => errors.push(e),
+            }
+        }
+
+        let checks: [fn(&Self) -> WResult<(), Error>; 5] = [
+            Self::check_event_name_uniqueness,
+            Self::check_note_references,
+            Self::check_unreferenced_groups,
+            Self::check_metric_signatures,
+            Self::check_attribute_definition_consistency,
+        ];
+        for check in checks {
+            match check(&registry) {
+                WResult::Ok(()) => {}
+                WResult::OkWithNFEs((), nfes) => errors.extend(nfes),
+                WResult::FatalErr(e) => errors.push(e),
+            }
+        }
+
+        WResult::with_non_fatal_errors(registry, errors)
+    }
+
+    /// Loads the semantic convention files matched by a glob pattern, parsing
+    /// and validating them in parallel. The results are returned in the order
+    /// of the glob entries, so the registry content doesn't depend on the
+    /// scheduling of the threads.
+    fn semconv_specs_from_glob(
+        registry_id: &str,
+        path_pattern: &str,
+        entries: glob::Paths,
+    ) -> Vec<WResult<SemConvSpecWithProvenance, Error>> {
+        let versioned_validator = JsonSchemaValidator::new_versioned();
+        let unversioned_validator = JsonSchemaValidator::new_unversioned();
+        entries
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|entry| match entry {
+                Ok(path_buf) => SemConvSpecWithProvenance::from_file(
+                    registry_id,
+                    path_buf.as_path(),
+                    &unversioned_validator,
+                    &versioned_validator,
+                ),
+                Err(e) => WResult::FatalErr(Error::InvalidRegistryPathPattern {
+                    path_pattern: path_pattern.to_owned(),
+                    error: e.to_string(),
+                }),
+            })
+            .collect()
+    }
+
     /// Creates a semantic convention registry from the given list of
     /// semantic convention specs.
     ///
@@ -154,6 +260,7 @@ impl SemConvRegistry {
                 semconv_version,
                 schema_base_url: "".to_owned(),
                 dependencies: None,
+                files: vec![],
             });
         } else {
             registry.manifest = registry_repo.manifest().cloned();
@@ -222,6 +329,29 @@ impl SemConvRegistry {
         )
     }
 
+    /// Returns the SHA-256 hash (in hexadecimal) of the content of the
+    /// semantic convention registry: its id, manifest and specs along with
+    /// their provenance.
+    ///
+    /// Two registries with the same hash resolve to the same telemetry schema,
+    /// e.g. to cache the resolution across runs.
+    #[must_use]
+    pub fn content_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        let mut update = |data: &str| {
+            // The length prefix keeps the boundaries between the fields.
+            hasher.update(data.len().to_le_bytes());
+            hasher.update(data.as_bytes());
+        };
+        update(&self.id);
+        update(&serde_json::to_string(&self.manifest).unwrap_or_default());
+        for SemConvSpecV1WithProvenance { spec, provenance } in &self.specs {
+            update(&provenance.to_string());
+            update(&serde_json::to_string(spec).unwrap_or_default());
+        }
+        to_hex(&hasher.finalize())
+    }
+
     /// Returns the number of semantic convention specs added in the semantic
     /// convention registry.
     #[must_use]
@@ -242,6 +372,7 @@ impl SemConvRegistry {
                 spec.groups.iter().map(|group| GroupSpecWithProvenance {
                     spec: group.clone(),
                     provenance: provenance.clone(),
+                    base_provenance: None,
                 })
             })
     }
@@ -259,8 +390,522 @@ impl SemConvRegistry {
             })
     }
 
+    /// Checks that the event groups of the semantic convention registry have
+    /// distinct event names.
+    ///
+    /// The event name of a group is its `name` field or, when not set, its `prefix`.
+    /// An [`Error::InvalidGroup`] is reported for each event group reusing the
+    /// event name of a previously loaded group.
+    pub fn check_event_name_uniqueness(&self) -> WResult<(), Error> {
+        let mut event_names: HashMap<&str, (&str, &Provenance)> = HashMap::new();
+        let mut errors = vec![];
+
+        for SemConvSpecV1WithProvenance { spec, provenance } in &self.specs {
+            for group in spec.groups.iter().filter(|g| g.r#type == GroupType::Event) {
+                let event_name = match group.name.as_deref() {
+                    Some(name) => name,
+                    None if !group.prefix.is_empty() => group.prefix.as_str(),
+                    None => continue,
+                };
+                match event_names.get(event_name) {
+                    Some((other_group_id, other_provenance)) => {
+                        errors.push(Error::InvalidGroup {
+                            path_or_url: provenance.path.clone(),
+                            group_id: group.id.clone(),
+                            error: format!(
+                                "The event name `{event_name}` is already used by the group `{other_group_id}` defined in {other_provenance}."
+                            ),
+                        });
+                    }
+                    None => {
+                        _ = event_names.insert(event_name, (group.id.as_str(), provenance));
+                    }
+                }
+            }
+        }
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Checks that the references to attributes and groups used in the notes
+    /// of the groups and attributes (see [`NOTE_REFERENCE_PATTERN`]) target an
+    /// id defined in the semantic convention registry.
+    ///
+    /// An [`Error::InvalidGroupWarning`] or an [`Error::InvalidAttributeWarning`]
+    /// is reported for each dead reference.
+    pub fn check_note_references(&self) -> WResult<(), Error> {
+        static NOTE_REFERENCE_REGEX: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(NOTE_REFERENCE_PATTERN).expect("Invalid regex"));
+
+        let groups: Vec<_> = self.unresolved_group_with_provenance_iter().collect();
+        let catalog = build_attribute_catalog(&groups);
+        let group_ids: HashSet<&str> = groups.iter().map(|g| g.spec.id.as_str()).collect();
+        let dead_references = |note: &str| -> Vec<String> {
+            NOTE_REFERENCE_REGEX
+                .captures_iter(note)
+                .filter(|captures| {
+                    let id = &captures[2];
+                    match &captures[1] {
+                        "attr" => catalog.definitions(id).next().is_none(),
+                        _ => !group_ids.contains(id),
+                    }
+                })
+                .map(|captures| captures[0].to_owned())
+                .collect()
+        };
+
+        let mut errors = vec![];
+        for SemConvSpecV1WithProvenance { spec, provenance } in &self.specs {
+            for (group_index, group) in spec.groups.iter().enumerate() {
+                for reference in dead_references(&group.note) {
+                    errors.push(Error::InvalidGroupWarning {
+                        path_or_url: provenance.path.clone(),
+                        group_id: group.id.clone(),
+                        error: format!(
+                            "The note contains the reference `{reference}` to an unknown id."
+                        ),
+                    });
+                }
+                for (attribute_index, attribute) in group.attributes.iter().enumerate() {
+                    for reference in dead_references(&attribute.note()) {
+                        errors.push(Error::InvalidAttributeWarning {
+                            path_or_url: provenance.path.clone(),
+                            group_id: group.id.clone(),
+                            attribute_id: attribute.id(),
+                            error: format!(
+                                "The note contains the reference `{reference}` to an unknown id."
+                            ),
+                            path: format!(
+                                "/groups/{group_index}/attributes/{attribute_index}/note"
+                            )
+                            .into(),
+                        });
+                    }
+                }
+            }
+        }
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Checks that the attribute groups and metric groups of the semantic
+    /// convention registry are used, see [`find_unreferenced_groups`].
+    ///
+    /// An [`Error::InvalidGroupWarning`] is reported for each unused group, the
+    /// shared library definitions not used yet are legitimate.
+    pub fn check_unreferenced_groups(&self) -> WResult<(), Error> {
+        let unreferenced_group_ids: HashSet<String> =
+            find_unreferenced_groups(self).into_iter().collect();
+        let errors = self
+            .specs
+            .iter()
+            .flat_map(|SemConvSpecV1WithProvenance { spec, provenance }| {
+                spec.groups
+                    .iter()
+                    .filter(|group| unreferenced_group_ids.contains(&group.id))
+                    .map(|group| Error::InvalidGroupWarning {
+                        path_or_url: provenance.path.clone(),
+                        group_id: group.id.clone(),
+                        error: "This group is unused, its attributes are never referenced and no group extends or includes it.".to_owned(),
+                    })
+            })
+            .collect();
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Checks that the metric groups sharing a metric name share the same
+    /// signature (instrument, unit and attributes), see
+    /// [`GroupSpec::metric_signature`].
+    ///
+    /// An [`Error::InvalidGroup`] is reported for each metric group whose
+    /// signature diverges from the one of the first group defining the metric.
+    pub fn check_metric_signatures(&self) -> WResult<(), Error> {
+        let mut signatures: HashMap<String, (MetricSignature, &str, &Provenance)> = HashMap::new();
+        let mut errors = vec![];
+
+        for SemConvSpecV1WithProvenance { spec, provenance } in &self.specs {
+            for group in &spec.groups {
+                let Some(signature) = group.metric_signature() else {
+                    continue;
+                };
+                match signatures.get(&signature.metric_name) {
+                    Some((other_signature, other_group_id, other_provenance)) => {
+                        let diverging_parts = signature.diverging_parts(other_signature);
+                        if !diverging_parts.is_empty() {
+                            errors.push(Error::InvalidGroup {
+                                path_or_url: provenance.path.clone(),
+                                group_id: group.id.clone(),
+                                error: format!(
+                                    "The metric `{}` is also defined by the group `{other_group_id}` in {other_provenance} with a different signature (diverging parts: {}).",
+                                    signature.metric_name,
+                                    diverging_parts.join(", ")
+                                ),
+                            });
+                        }
+                    }
+                    None => {
+                        _ = signatures.insert(
+                            signature.metric_name.clone(),
+                            (signature, group.id.as_str(), provenance),
+                        );
+                    }
+                }
+            }
+        }
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Checks that the metric groups sharing a metric name have the same unit
+    /// and instrument, a divergence corrupting the aggregation of the metric.
+    ///
+    /// An [`Error::InvalidMetric`] is reported for each metric group whose unit
+    /// or instrument diverges from the one of the first group defining the
+    /// metric. Unlike [`Self::check_metric_signatures`] the attributes are not
+    /// compared.
+    pub fn check_metric_units(&self) -> WResult<(), Error> {
+        let mut first_definitions: HashMap<String, (MetricSignature, &str, &Provenance)> =
+            HashMap::new();
+        let mut errors = vec![];
+
+        for SemConvSpecV1WithProvenance { spec, provenance } in &self.specs {
+            for group in &spec.groups {
+                let Some(signature) = group.metric_signature() else {
+                    continue;
+                };
+                let Some((other, other_group_id, other_provenance)) =
+                    first_definitions.get(&signature.metric_name)
+                else {
+                    _ = first_definitions.insert(
+                        signature.metric_name.clone(),
+                        (signature, group.id.as_str(), provenance),
+                    );
+                    continue;
+                };
+                let describe = |value: Option<String>| value.unwrap_or_else(|| "none".to_owned());
+                let differences: Vec<_> = [
+                    (
+                        "unit",
+                        describe(signature.unit.clone()),
+                        describe(other.unit.clone()),
+                    ),
+                    (
+                        "instrument",
+                        describe(signature.instrument.as_ref().map(ToString::to_string)),
+                        describe(other.instrument.as_ref().map(ToString::to_string)),
+                    ),
+                ]
+                .into_iter()
+                .filter(|(_, value, other_value)| value != other_value)
+                .map(|(part, value, other_value)| format!("{part} `{value}` vs `{other_value}`"))
+                .collect();
+                if !differences.is_empty() {
+                    errors.push(Error::InvalidMetric {
+                        path_or_url: provenance.path.clone(),
+                        group_id: group.id.clone(),
+                        error: format!(
+                            "The metric `{}` conflicts with its definition by the group `{other_group_id}` in {other_provenance}: {}. Metrics sharing a name must have the same unit and instrument.",
+                            signature.metric_name,
+                            differences.join(", ")
+                        ),
+                    });
+                }
+            }
+        }
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Checks that the attributes defined by several groups (see
+    /// [`build_attribute_catalog`]) are defined with the same type, stability
+    /// and requirement level.
+    ///
+    /// An [`Error::InvalidAttribute`] is reported for each definition diverging
+    /// from the first definition of the attribute, identical redefinitions are
+    /// accepted.
+    pub fn check_attribute_definition_consistency(&self) -> WResult<(), Error> {
+        let groups: Vec<_> = self.unresolved_group_with_provenance_iter().collect();
+        let catalog = build_attribute_catalog(&groups);
+        let mut first_definitions: HashMap<String, (DefinitionFields<'_>, &str, &Provenance)> =
+            HashMap::new();
+        let mut errors = vec![];
+
+        for SemConvSpecV1WithProvenance { spec, provenance } in &self.specs {
+            for (group_index, group) in spec.groups.iter().enumerate() {
+                for (attribute_index, attribute) in group.attributes.iter().enumerate() {
+                    let AttributeSpec::Id {
+                        r#type,
+                        stability,
+                        requirement_level,
+                        ..
+                    } = attribute
+                    else {
+                        continue;
+                    };
+                    let (attribute_id, _) = effective_attribute_id(&group.prefix, attribute);
+                    if catalog.definitions(&attribute_id).nth(1).is_none() {
+                        continue;
+                    }
+                    let fields = (r#type, stability, requirement_level);
+                    let Some((first_fields, first_group_id, first_provenance)) =
+                        first_definitions.get(&attribute_id)
+                    else {
+                        _ = first_definitions
+                            .insert(attribute_id, (fields, group.id.as_str(), provenance));
+                        continue;
+                    };
+                    let diverging_fields: Vec<_> = [
+                        ("type", fields.0 != first_fields.0),
+                        ("stability", fields.1 != first_fields.1),
+                        ("requirement_level", fields.2 != first_fields.2),
+                    ]
+                    .into_iter()
+                    .filter_map(|(field, diverges)| diverges.then_some(field))
+                    .collect();
+                    if !diverging_fields.is_empty() {
+                        errors.push(Error::InvalidAttribute {
+                            path_or_url: provenance.path.clone(),
+                            group_id: group.id.clone(),
+                            attribute_id,
+                            error: format!(
+                                "This attribute is also defined by the group `{first_group_id}` in {first_provenance} with a different {}.",
+                                diverging_fields.join(", ")
+                            ),
+                            path: format!("/groups/{group_index}/attributes/{attribute_index}")
+                                .into(),
+                        });
+                    }
+                }
+            }
+        }
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Merges the attribute groups sharing the same id into a single group.
+    ///
+    /// The groups are merged in the order of their provenance (registry id then
+    /// path), the merged group replaces the first one and the other ones are
+    /// removed from the registry. The attribute lists are unioned, an
+    /// [`Error::InvalidGroup`] is reported for each attribute defined differently
+    /// by several groups (the first definition is kept). Groups of other types
+    /// are left untouched and remain subject to the duplicate id rule.
+    ///
+    /// Note: This method is opt-in and must be called before the resolution.
+    pub fn merge_attribute_groups(&mut self) -> WResult<(), Error> {
+        let mut locations: BTreeMap<String, Vec<(usize, usize)>> = BTreeMap::new();
+        for (spec_index, SemConvSpecV1WithProvenance { spec, .. }) in self.specs.iter().enumerate()
+        {
+            for (group_index, group) in spec.groups.iter().enumerate() {
+                if group.r#type == GroupType::AttributeGroup {
+                    locations
+                        .entry(group.id.clone())
+                        .or_default()
+                        .push((spec_index, group_index));
+                }
+            }
+        }
+
+        let mut errors = vec![];
+        let mut merged_locations = HashSet::new();
+        for (group_id, mut group_locations) in locations {
+            if group_locations.len() < 2 {
+                continue;
+            }
+            group_locations.sort_by(|(a, _), (b, _)| {
+                let (a, b) = (&self.specs[*a].provenance, &self.specs[*b].provenance);
+                (&a.registry_id, &a.path).cmp(&(&b.registry_id, &b.path))
+            });
+
+            let (target_spec, target_group) = group_locations[0];
+            let mut attributes = self.specs[target_spec].spec.groups[target_group]
+                .attributes
+                .clone();
+            for &(spec_index, group_index) in &group_locations[1..] {
+                let SemConvSpecV1WithProvenance { spec, provenance } = &self.specs[spec_index];
+                for attribute in &spec.groups[group_index].attributes {
+                    let attribute_id = attribute.id();
+                    match attributes.iter().find(|a| a.id() == attribute_id) {
+                        Some(existing) if existing == attribute => {}
+                        Some(_) => errors.push(Error::InvalidGroup {
+                            path_or_url: provenance.path.clone(),
+                            group_id: group_id.clone(),
+                            error: format!(
+                                "The attribute `{attribute_id}` conflicts with another definition in the attribute group `{group_id}`."
+                            ),
+                        }),
+                        None => attributes.push(attribute.clone()),
+                    }
+                }
+                _ = merged_locations.insert((spec_index, group_index));
+            }
+            self.specs[target_spec].spec.groups[target_group].attributes = attributes;
+        }
+
+        for (spec_index, SemConvSpecV1WithProvenance { spec, .. }) in
+            self.specs.iter_mut().enumerate()
+        {
+            let mut group_index = 0;
+            spec.groups.retain(|_| {
+                let keep = !merged_locations.contains(&(spec_index, group_index));
+                group_index += 1;
+                keep
+            });
+        }
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Applies an overlay registry (e.g. vendor extensions) on top of this
+    /// registry. The overlay takes precedence over this registry, so applying
+    /// several overlays in order gives the precedence to the last one:
+    /// - A group with a new id is added to the registry.
+    /// - A group with the id of an existing group of the same type overrides
+    ///   the brief, the note (when not empty), the stability and the
+    ///   deprecation (when specified) of the existing group. Its attributes are
+    ///   added to the existing group, the references to an attribute of the
+    ///   existing group override the fields they specify (e.g. the requirement
+    ///   level), see [`AttributeSpec::apply_overlay`].
+    ///
+    /// An [`Error::InvalidGroup`] is reported for each conflict, i.e. a group of
+    /// another type or an attribute of the existing group defined again, the
+    /// conflicting group or attribute is ignored.
+    ///
+    /// The files of the overlay become files of this registry, the files of
+    /// its dependencies keep their registry id.
+    ///
+    /// Note: This method must be called before the resolution.
+    pub fn apply_overlay(&mut self, overlay: SemConvRegistry) -> WResult<(), Error> {
+        let mut locations: HashMap<String, (usize, usize)> = HashMap::new();
+        for (spec_index, SemConvSpecV1WithProvenance { spec, .. }) in self.specs.iter().enumerate()
+        {
+            for (group_index, group) in spec.groups.iter().enumerate() {
+                _ = locations
+                    .entry(group.id.clone())
+                    .or_insert((spec_index, group_index));
+            }
+        }
+
+        let mut errors = vec![];
+        for SemConvSpecV1WithProvenance {
+            mut spec,
+            mut provenance,
+        } in overlay.specs
+        {
+            if *provenance.registry_id == *overlay.id {
+                provenance.registry_id = self.id.as_str().into();
+            }
+            let groups = std::mem::take(&mut spec.groups);
+            for group in groups {
+                let Some(&(spec_index, group_index)) = locations.get(&group.id) else {
+                    spec.groups.push(group);
+                    continue;
+                };
+                let existing = &mut self.specs[spec_index].spec.groups[group_index];
+                let conflict = |error: String| Error::InvalidGroup {
+                    path_or_url: provenance.path.clone(),
+                    group_id: group.id.clone(),
+                    error,
+                };
+                if existing.r#type != group.r#type {
+                    errors.push(conflict(format!(
+                        "The overlay group of type `{}` conflicts with the group of type `{}`.",
+                        group.r#type, existing.r#type
+                    )));
+                    continue;
+                }
+                existing.brief.clone_from(&group.brief);
+                if !group.note.is_empty() {
+                    existing.note.clone_from(&group.note);
+                }
+                if group.stability.is_some() {
+                    existing.stability.clone_from(&group.stability);
+                }
+                if group.deprecated.is_some() {
+                    existing.deprecated.clone_from(&group.deprecated);
+                }
+                for attribute in &group.attributes {
+                    let attribute_id = attribute.id();
+                    match existing
+                        .attributes
+                        .iter_mut()
+                        .find(|existing| existing.id() == attribute_id)
+                    {
+                        Some(existing) => {
+                            if let Err(error) = existing.apply_overlay(attribute.clone()) {
+                                errors.push(conflict(error));
+                            }
+                        }
+                        None => existing.attributes.push(attribute.clone()),
+                    }
+                }
+            }
+            if !spec.groups.is_empty() || spec.imports.is_some() {
+                self.specs
+                    .push(SemConvSpecV1WithProvenance { spec, provenance });
+                self.semconv_spec_count += 1;
+            }
+        }
+
+        WResult::with_non_fatal_errors((), errors)
+    }
+
+    /// Moves the attributes of the namespace `from_prefix` to the namespace
+    /// `to_prefix` (e.g. `messaging` to `messaging.operation`), rewriting both
+    /// the attribute definitions and the references so they still resolve.
+    ///
+    /// An id matches when it is equal to `from_prefix` or starts with
+    /// `from_prefix` followed by a dot. The ids already in the `to_prefix`
+    /// namespace are left untouched, so the rewrite can be applied to a nested
+    /// target namespace and can be repeated. The group prefixes are not
+    /// rewritten.
+    ///
+    /// Note: This method must be called before the resolution.
+    pub fn rewrite_attribute_namespace(
+        &mut self,
+        from_prefix: &str,
+        to_prefix: &str,
+    ) -> NamespaceRewriteReport {
+        let rewrite = |id: &mut String| {
+            let in_namespace = |namespace: &str| {
+                id.strip_prefix(namespace)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            };
+            if !in_namespace(from_prefix) || in_namespace(to_prefix) {
+                return false;
+            }
+            *id = format!("{to_prefix}{}", &id[from_prefix.len()..]);
+            true
+        };
+
+        let mut report = NamespaceRewriteReport::default();
+        let attributes = self
+            .specs
+            .iter_mut()
+            .flat_map(|SemConvSpecV1WithProvenance { spec, .. }| spec.groups.iter_mut())
+            .flat_map(|group| group.attributes.iter_mut());
+        for attribute in attributes {
+            match attribute {
+                AttributeSpec::Id { id, .. } => {
+                    if rewrite(id) {
+                        report.definitions += 1;
+                    }
+                }
+                AttributeSpec::Ref { r#ref, .. } => {
+                    if rewrite(r#ref) {
+                        report.references += 1;
+                    }
+                }
+            }
+        }
+        report
+    }
+
     /// Returns a set of stats about the semantic convention registry.
     pub fn stats(&self) -> Stats {
+        let groups: Vec<_> = self.unresolved_group_with_provenance_iter().collect();
+        let catalog = build_attribute_catalog(&groups);
         Stats {
             file_count: self.specs.len(),
             group_count: self.specs.iter().map(|sc| sc.spec.groups.len()).sum(),
@@ -272,8 +917,41 @@ impl SemConvRegistry {
                     *acc.entry(group_type).or_insert(0) += 1;
                     acc
                 }),
-            attribute_count: self.attributes.len(),
-            metric_count: self.metrics.len(),
+            stability_breakdown: self
+                .specs
+                .iter()
+                .flat_map(|sc| sc.spec.groups.iter())
+                .map(|group| match (&group.deprecated, &group.stability) {
+                    (Some(_), _) => "deprecated".to_owned(),
+                    (None, Some(stability)) => stability.to_string(),
+                    (None, None) => "unspecified".to_owned(),
+                })
+                .fold(BTreeMap::new(), |mut acc, stability| {
+                    *acc.entry(stability).or_insert(0) += 1;
+                    acc
+                }),
+            attribute_count: catalog
+                .attribute_ids()
+                .filter(|id| catalog.definitions(id).next().is_some())
+                .count(),
+            metric_count: groups
+                .iter()
+                .filter(|group| group.spec.r#type == GroupType::Metric)
+                .count(),
+            warning_count: self
+                .specs
+                .iter()
+                .flat_map(|sc| sc.spec.groups.iter().map(|group| (group, &sc.provenance)))
+                .map(
+                    |(group, provenance)| match group.validate(&provenance.path) {
+                        WResult::OkWithNFEs(_, errors) => errors
+                            .iter()
+                            .filter(|error| error.severity() == Some(Severity::Warning))
+                            .count(),
+                        WResult::Ok(_) | WResult::FatalErr(_) => 0,
+                    },
+                )
+                .sum(),
         }
     }
 }
@@ -283,11 +961,14 @@ mod tests {
     use crate::attribute::{AttributeSpec, AttributeType, PrimitiveOrArrayTypeSpec};
     use crate::group::{GroupSpec, GroupType};
     use crate::provenance::Provenance;
-    use crate::registry::SemConvRegistry;
+    use crate::registry::{NamespaceRewriteReport, SemConvRegistry};
     use crate::registry_repo::RegistryRepo;
     use crate::semconv::{SemConvSpec, SemConvSpecV1, SemConvSpecWithProvenance};
     use crate::Error;
+    use miette::Diagnostic;
+    use std::collections::BTreeMap;
 
+    use weaver_common::result::WResult;
     use weaver_common::vdir::VirtualDirectoryPath;
 
     #[test]
@@ -298,6 +979,20 @@ mod tests {
             .unwrap();
         assert_eq!(registry.id(), "test");
         assert_eq!(registry.semconv_spec_count(), 3);
+        // The files are parsed in parallel but added in the glob order.
+        let paths: Vec<_> = registry
+            .specs
+            .iter()
+            .map(|spec| spec.provenance.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "data/client.yaml",
+                "data/cloud.yaml",
+                "data/cloudevents.yaml"
+            ]
+        );
 
         // Test with an invalid path pattern
         let registry = SemConvRegistry::try_from_path_pattern("test", "data/c***.yml")
@@ -309,6 +1004,110 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_validate_exhaustive() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |file_name: &str, content: &str| {
+            std::fs::write(dir.path().join(file_name), content).unwrap();
+        };
+        write("a.yaml", "groups: [");
+        write(
+            "b.yaml",
+            r#"
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: Session attributes.
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: stable
+        examples: [abc]
+  - id: event.session.start
+    type: event
+    name: session.start
+    brief: A session started.
+    stability: development
+"#,
+        );
+        write(
+            "c.yaml",
+            r#"
+groups:
+  - id: event.session.started
+    type: event
+    name: session.start
+    brief: A session started (again).
+    stability: development
+"#,
+        );
+        let path_pattern = format!("{}/*.yaml", dir.path().display());
+
+        // The first invalid file stops the loading.
+        let result = SemConvRegistry::try_from_path_pattern("test", &path_pattern);
+        assert!(matches!(
+            result,
+            WResult::FatalErr(Error::DeserializationError { .. })
+        ));
+
+        let WResult::OkWithNFEs(registry, errors) =
+            SemConvRegistry::validate_exhaustive("test", &path_pattern)
+        else {
+            panic!("The findings should be reported as non-fatal errors");
+        };
+        assert_eq!(registry.semconv_spec_count(), 2);
+        assert_eq!(errors.len(), 3, "{errors:#?}");
+        assert!(
+            matches!(&errors[0], Error::DeserializationError { path_or_url, .. } if path_or_url.ends_with("a.yaml"))
+        );
+        assert!(
+            matches!(&errors[1], Error::InvalidGroup { group_id, .. } if group_id == "event.session.started")
+        );
+        assert!(
+            matches!(&errors[2], Error::InvalidGroupWarning { group_id, .. } if group_id == "registry.session")
+        );
+
+        // An invalid path pattern is fatal.
+        assert!(matches!(
+            SemConvRegistry::validate_exhaustive("test", "data/c***.yml"),
+            WResult::FatalErr(Error::InvalidRegistryPathPattern { .. })
+        ));
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let registry = |brief: &str| {
+            let mut registry = SemConvRegistry::new("test");
+            registry
+                .add_semconv_spec_from_string(
+                    Provenance::new("test", "session.yaml"),
+                    &format!(
+                        r#"
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: {brief}
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: stable
+        examples: [abc]
+"#
+                    ),
+                )
+                .into_result_failing_non_fatal()
+                .expect("Failed to add the spec");
+            registry
+        };
+
+        let hash = registry("Session attributes.").content_hash();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(registry("Session attributes.").content_hash(), hash);
+        assert_ne!(registry("The session attributes.").content_hash(), hash);
+    }
+
     #[test]
     fn test_from_semconv_specs() {
         let semconv_specs = vec![
@@ -317,9 +1116,11 @@ mod tests {
                 spec: SemConvSpec::NoVersion(SemConvSpecV1 {
                     groups: vec![GroupSpec {
                         id: "group1".to_owned(),
+                        renamed_from: None,
                         r#type: GroupType::AttributeGroup,
                         attributes: vec![AttributeSpec::Id {
                             id: "attr1".to_owned(),
+                            renamed_from: None,
                             r#type: AttributeType::PrimitiveOrArray(
                                 PrimitiveOrArrayTypeSpec::Boolean,
                             ),
@@ -329,10 +1130,12 @@ mod tests {
                             requirement_level: Default::default(),
                             sampling_relevant: None,
                             note: "note".to_owned(),
+                            examples_note: None,
                             stability: None,
                             deprecated: None,
                             annotations: None,
                             role: Default::default(),
+                            scope: None,
                         }],
                         span_kind: None,
                         prefix: "".to_owned(),
@@ -361,6 +1164,7 @@ mod tests {
                 spec: SemConvSpec::NoVersion(SemConvSpecV1 {
                     groups: vec![GroupSpec {
                         id: "group2".to_owned(),
+                        renamed_from: None,
                         r#type: GroupType::AttributeGroup,
                         attributes: vec![],
                         span_kind: None,
@@ -436,6 +1240,773 @@ mod tests {
             });
     }
 
+    #[test]
+    fn test_stats_summary() {
+        let mut registry = SemConvRegistry::new("test");
+        _ = registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "http.yaml"),
+                r#"
+groups:
+  - id: registry.http
+    type: attribute_group
+    brief: HTTP attributes.
+    attributes:
+      - id: http.request.method
+        type: string
+        brief: The HTTP method.
+        stability: stable
+        examples: [GET]
+      - id: http.route
+        type: string
+        brief: The route.
+        stability: development
+  - id: span.http.client
+    type: span
+    brief: An HTTP client span.
+    stability: stable
+    span_kind: client
+    attributes:
+      - ref: http.request.method
+  - id: span.http.legacy
+    type: span
+    brief: A legacy HTTP span.
+    stability: development
+    deprecated:
+      reason: obsoleted
+    span_kind: client
+    attributes:
+      - ref: http.request.method
+  - id: metric.http.client.request.duration
+    type: metric
+    brief: The HTTP client request duration.
+    metric_name: http.client.request.duration
+    instrument: histogram
+    unit: s
+    stability: development
+    attributes:
+      - ref: http.request.method
+"#,
+            )
+            .into_result_with_non_fatal()
+            .expect("Failed to add the spec");
+        let stats = registry.stats();
+
+        assert_eq!(stats.group_count, 4);
+        assert_eq!(
+            stats.group_breakdown.get(&GroupType::AttributeGroup),
+            Some(&1)
+        );
+        assert_eq!(stats.group_breakdown.get(&GroupType::Span), Some(&2));
+        assert_eq!(stats.group_breakdown.get(&GroupType::Metric), Some(&1));
+        assert_eq!(stats.group_breakdown.get(&GroupType::Event), None);
+        assert_eq!(
+            stats.stability_breakdown,
+            BTreeMap::from([
+                ("deprecated".to_owned(), 1),
+                ("development".to_owned(), 1),
+                ("stable".to_owned(), 1),
+                ("unspecified".to_owned(), 1),
+            ])
+        );
+        assert_eq!(stats.attribute_count, 2);
+        assert_eq!(stats.metric_count, 1);
+        assert_eq!(stats.warning_count, 1);
+        assert_eq!(
+            stats.to_string(),
+            "files                   1
+groups                  4
+  attribute_group       1
+  metric                1
+  span                  2
+stability
+  deprecated            1
+  development           1
+  stable                1
+  unspecified           1
+attributes              2
+metrics                 1
+warnings                1
+"
+        );
+    }
+
+    #[test]
+    fn test_check_event_name_uniqueness() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "events1.yaml"),
+                r#"
+groups:
+  - id: event.session.start
+    type: event
+    name: session.start
+    brief: A session started.
+    stability: development
+  - id: event.session.end
+    type: event
+    name: session.end
+    brief: A session ended.
+    stability: development
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        assert!(registry
+            .check_event_name_uniqueness()
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "events2.yaml"),
+                r#"
+groups:
+  - id: event.session.started
+    type: event
+    name: session.start
+    brief: A session started (again).
+    stability: development
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        assert_eq!(
+            registry
+                .check_event_name_uniqueness()
+                .into_result_failing_non_fatal(),
+            Err(Error::InvalidGroup {
+                path_or_url: "events2.yaml".to_owned(),
+                group_id: "event.session.started".to_owned(),
+                error: "The event name `session.start` is already used by the group `event.session.start` defined in test:events1.yaml.".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_note_references() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "notes.yaml"),
+                r#"
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: Session attributes.
+    note: No reference here, even with {{braces}}.
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        note: See {{group:registry.session}}.
+        stability: development
+        examples: [abc]
+  - id: span.session
+    type: span
+    brief: A session span.
+    note: Carries {{ attr:session.id }} and {{attr:session.previous_id}}.
+    stability: development
+    span_kind: internal
+    attributes:
+      - ref: session.id
+        note: Replaces {{group:registry.sessions}}.
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+
+        let (_, warnings) = registry
+            .check_note_references()
+            .into_result_with_non_fatal()
+            .expect("Dead references are not fatal");
+        assert_eq!(
+            warnings,
+            vec![
+                Error::InvalidGroupWarning {
+                    path_or_url: "notes.yaml".to_owned(),
+                    group_id: "span.session".to_owned(),
+                    error: "The note contains the reference `{{attr:session.previous_id}}` to an unknown id.".to_owned(),
+                },
+                Error::InvalidAttributeWarning {
+                    path_or_url: "notes.yaml".to_owned(),
+                    group_id: "span.session".to_owned(),
+                    attribute_id: "session.id".to_owned(),
+                    error: "The note contains the reference `{{group:registry.sessions}}` to an unknown id.".to_owned(),
+                    path: "/groups/1/attributes/0/note".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_unreferenced_groups() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "groups.yaml"),
+                r#"
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: Session attributes.
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: development
+        examples: [abc]
+  - id: metric_attributes.session
+    type: metric_group
+    brief: Unused session metric attributes.
+    stability: development
+    attributes:
+      - ref: session.id
+  - id: span.session
+    type: span
+    brief: A session span.
+    stability: development
+    span_kind: internal
+    attributes:
+      - ref: session.id
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+
+        let (_, warnings) = registry
+            .check_unreferenced_groups()
+            .into_result_with_non_fatal()
+            .expect("Unused groups are not fatal");
+        assert_eq!(
+            warnings,
+            vec![Error::InvalidGroupWarning {
+                path_or_url: "groups.yaml".to_owned(),
+                group_id: "metric_attributes.session".to_owned(),
+                error: "This group is unused, its attributes are never referenced and no group extends or includes it.".to_owned(),
+            }]
+        );
+        assert!(warnings
+            .iter()
+            .all(|w| w.severity() == Some(miette::Severity::Warning)));
+    }
+
+    #[test]
+    fn test_check_metric_signatures() {
+        let metric = |group_id: &str, attribute: &str| {
+            format!(
+                r#"
+  - id: {group_id}
+    type: metric
+    metric_name: session.duration
+    brief: The session duration.
+    instrument: histogram
+    unit: s
+    stability: development
+    attributes:
+      - ref: session.id
+      - ref: {attribute}
+"#
+            )
+        };
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "metrics1.yaml"),
+                &format!(
+                    "groups:{}{}",
+                    metric("metric.session.duration", "session.kind"),
+                    metric("metric.session.duration.copy", "session.kind")
+                ),
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        // Same name and same signature.
+        assert!(registry
+            .check_metric_signatures()
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "metrics2.yaml"),
+                &format!(
+                    "groups:{}",
+                    metric("metric.session.duration.other", "session.user")
+                ),
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        // Same name but different attributes.
+        assert_eq!(
+            registry
+                .check_metric_signatures()
+                .into_result_failing_non_fatal(),
+            Err(Error::InvalidGroup {
+                path_or_url: "metrics2.yaml".to_owned(),
+                group_id: "metric.session.duration.other".to_owned(),
+                error: "The metric `session.duration` is also defined by the group `metric.session.duration` in test:metrics1.yaml with a different signature (diverging parts: attributes).".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_metric_units() {
+        let metric = |group_id: &str, unit: &str| {
+            format!(
+                r#"
+  - id: {group_id}
+    type: metric
+    metric_name: session.duration
+    brief: The session duration.
+    instrument: histogram
+    unit: {unit}
+    stability: development
+"#
+            )
+        };
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "metrics1.yaml"),
+                &format!(
+                    "groups:{}{}",
+                    metric("metric.session.duration", "s"),
+                    metric("metric.session.duration.copy", "s")
+                ),
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        // Same name and same unit.
+        assert!(registry
+            .check_metric_units()
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "metrics2.yaml"),
+                &format!("groups:{}", metric("metric.session.duration.ms", "ms")),
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        // Same name but different units.
+        assert_eq!(
+            registry
+                .check_metric_units()
+                .into_result_failing_non_fatal(),
+            Err(Error::InvalidMetric {
+                path_or_url: "metrics2.yaml".to_owned(),
+                group_id: "metric.session.duration.ms".to_owned(),
+                error: "The metric `session.duration` conflicts with its definition by the group `metric.session.duration` in test:metrics1.yaml: unit `ms` vs `s`. Metrics sharing a name must have the same unit and instrument.".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_attribute_definition_consistency() {
+        let attribute_group = |group_id: &str, attribute_type: &str| {
+            format!(
+                r#"
+groups:
+  - id: {group_id}
+    type: attribute_group
+    brief: Session attributes.
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: development
+        examples: [foo]
+      - id: session.count
+        type: {attribute_type}
+        brief: The number of sessions.
+        stability: development
+"#
+            )
+        };
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "session1.yaml"),
+                &attribute_group("registry.session", "int"),
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "session2.yaml"),
+                &attribute_group("registry.session.copy", "int"),
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        // Identical redefinitions are accepted.
+        assert!(registry
+            .check_attribute_definition_consistency()
+            .into_result_failing_non_fatal()
+            .is_ok());
+
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "session3.yaml"),
+                &attribute_group("registry.session.other", "double"),
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        assert_eq!(
+            registry
+                .check_attribute_definition_consistency()
+                .into_result_failing_non_fatal(),
+            Err(Error::InvalidAttribute {
+                path_or_url: "session3.yaml".to_owned(),
+                group_id: "registry.session.other".to_owned(),
+                attribute_id: "session.count".to_owned(),
+                error: "This attribute is also defined by the group `registry.session` in test:session1.yaml with a different type.".to_owned(),
+                path: "/groups/0/attributes/1".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_rewrite_attribute_namespace() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "messaging.yaml"),
+                r#"
+groups:
+  - id: registry.messaging
+    type: attribute_group
+    brief: Messaging attributes.
+    attributes:
+      - id: messaging.name
+        type: string
+        brief: The operation name.
+        stability: development
+        examples: [send]
+      - id: messaging.operation.type
+        type: string
+        brief: The operation type.
+        stability: development
+        examples: [send]
+      - id: messagingsystem
+        type: string
+        brief: Not in the messaging namespace.
+        stability: development
+        examples: [kafka]
+  - id: span.messaging
+    type: span
+    brief: A messaging span.
+    stability: development
+    span_kind: producer
+    attributes:
+      - ref: messaging.name
+      - ref: messaging.operation.type
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+
+        assert_eq!(
+            registry.rewrite_attribute_namespace("messaging", "messaging.operation"),
+            NamespaceRewriteReport {
+                definitions: 1,
+                references: 1,
+            }
+        );
+        let attribute_ids: Vec<_> = registry
+            .unresolved_group_with_provenance_iter()
+            .flat_map(|group| group.spec.attributes)
+            .map(|attribute| attribute.id())
+            .collect();
+        assert_eq!(
+            attribute_ids,
+            vec![
+                "messaging.operation.name",
+                "messaging.operation.type",
+                "messagingsystem",
+                "messaging.operation.name",
+                "messaging.operation.type",
+            ]
+        );
+        assert_eq!(
+            registry.rewrite_attribute_namespace("messaging", "messaging.operation"),
+            NamespaceRewriteReport::default()
+        );
+    }
+
+    #[test]
+    fn test_merge_attribute_groups() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "b.yaml"),
+                r#"
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: Session attributes.
+    attributes:
+      - id: session.previous_id
+        type: string
+        brief: The previous session id.
+        stability: development
+        examples: [abc]
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: development
+        examples: [abc]
+  - id: span.session
+    type: span
+    brief: A session span.
+    stability: development
+    span_kind: internal
+    attributes:
+      - ref: session.id
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "a.yaml"),
+                r#"
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: Session attributes.
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: development
+        examples: [abc]
+  - id: span.session
+    type: span
+    brief: A session span.
+    stability: development
+    span_kind: internal
+    attributes:
+      - ref: session.id
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+
+        // Clean union, the merged group replaces the group of `a.yaml`.
+        assert!(registry
+            .merge_attribute_groups()
+            .into_result_failing_non_fatal()
+            .is_ok());
+        let groups: Vec<_> = registry
+            .unresolved_group_with_provenance_iter()
+            .map(|g| (g.spec.id, g.provenance.path, g.spec.attributes.len()))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                ("span.session".to_owned(), "b.yaml".to_owned(), 1),
+                ("registry.session".to_owned(), "a.yaml".to_owned(), 2),
+                ("span.session".to_owned(), "a.yaml".to_owned(), 1),
+            ]
+        );
+        let attribute_ids: Vec<_> = registry
+            .unresolved_group_with_provenance_iter()
+            .find(|g| g.spec.id == "registry.session")
+            .unwrap()
+            .spec
+            .attributes
+            .iter()
+            .map(|a| a.id())
+            .collect();
+        assert_eq!(attribute_ids, vec!["session.id", "session.previous_id"]);
+
+        // Conflicting definitions of the same attribute.
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "c.yaml"),
+                r#"
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: Session attributes.
+    attributes:
+      - id: session.id
+        type: int
+        brief: The session id.
+        stability: development
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        assert_eq!(
+            registry
+                .merge_attribute_groups()
+                .into_result_failing_non_fatal(),
+            Err(Error::InvalidGroup {
+                path_or_url: "c.yaml".to_owned(),
+                group_id: "registry.session".to_owned(),
+                error: "The attribute `session.id` conflicts with another definition in the attribute group `registry.session`.".to_owned(),
+            })
+        );
+        assert_eq!(
+            registry
+                .unresolved_group_with_provenance_iter()
+                .filter(|g| g.spec.id == "registry.session")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_apply_overlay() {
+        let mut registry = SemConvRegistry::new("test");
+        registry
+            .add_semconv_spec_from_string(
+                Provenance::new("test", "registry.yaml"),
+                r#"
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: Session attributes.
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: development
+        examples: [abc]
+  - id: span.session
+    type: span
+    brief: A session span.
+    stability: development
+    span_kind: internal
+    attributes:
+      - ref: session.id
+"#,
+            )
+            .into_result_failing_non_fatal()
+            .unwrap();
+        let overlay = |yaml: &str| {
+            let mut overlay = SemConvRegistry::new("vendor");
+            overlay
+                .add_semconv_spec_from_string(Provenance::new("vendor", "vendor.yaml"), yaml)
+                .into_result_failing_non_fatal()
+                .unwrap();
+            overlay
+        };
+
+        registry
+            .apply_overlay(overlay(
+                r#"
+groups:
+  - id: span.session
+    type: span
+    brief: A vendor session span.
+    stability: development
+    span_kind: internal
+    attributes:
+      - ref: session.id
+        requirement_level: required
+      - ref: vendor.tenant
+  - id: registry.vendor
+    type: attribute_group
+    brief: Vendor attributes.
+    attributes:
+      - id: vendor.tenant
+        type: string
+        brief: The tenant.
+        stability: development
+        examples: [acme]
+"#,
+            ))
+            .into_result_failing_non_fatal()
+            .unwrap();
+        // The last overlay has the highest precedence.
+        registry
+            .apply_overlay(overlay(
+                r#"
+groups:
+  - id: span.session
+    type: span
+    brief: An ACME session span.
+    stability: development
+    span_kind: internal
+    attributes:
+      - ref: session.id
+        requirement_level: opt_in
+"#,
+            ))
+            .into_result_failing_non_fatal()
+            .unwrap();
+
+        let groups: BTreeMap<_, _> = registry
+            .unresolved_group_with_provenance_iter()
+            .map(|g| (g.spec.id.clone(), g))
+            .collect();
+        assert_eq!(groups.len(), 3);
+        let span = &groups["span.session"];
+        assert_eq!(span.provenance.path, "registry.yaml");
+        assert_eq!(span.spec.brief, "An ACME session span.");
+        assert_eq!(
+            span.spec.stability,
+            Some(crate::stability::Stability::Development)
+        );
+        assert_eq!(
+            span.spec
+                .attributes
+                .iter()
+                .map(|a| (a.id(), a.requirement_level().to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("session.id".to_owned(), "opt-in".to_owned()),
+                ("vendor.tenant".to_owned(), "recommended".to_owned()),
+            ]
+        );
+        // The overlay groups become groups of the registry.
+        assert_eq!(&*groups["registry.vendor"].provenance.registry_id, "test");
+
+        // Conflicts: a group of another type and a redefined attribute.
+        let WResult::OkWithNFEs((), errors) = registry.apply_overlay(overlay(
+            r#"
+groups:
+  - id: span.session
+    type: event
+    name: session.event
+    brief: A session event.
+    stability: development
+  - id: registry.session
+    type: attribute_group
+    brief: Vendor session attributes.
+    attributes:
+      - id: session.id
+        type: int
+        brief: The session id.
+        stability: development
+        examples: [1]
+"#,
+        )) else {
+            panic!("The conflicts should be reported");
+        };
+        assert_eq!(errors.len(), 2, "{errors:#?}");
+        assert!(errors
+            .iter()
+            .all(|e| matches!(e, Error::InvalidGroup { .. })));
+        assert_eq!(
+            registry
+                .unresolved_group_with_provenance_iter()
+                .filter(|g| g.spec.id == "registry.session")
+                .flat_map(|g| g.spec.attributes)
+                .map(|a| match a {
+                    AttributeSpec::Id { r#type, .. } => r#type.to_string(),
+                    AttributeSpec::Ref { .. } => "ref".to_owned(),
+                })
+                .collect::<Vec<_>>(),
+            vec!["string"]
+        );
+    }
+
     #[test]
     fn test_unresolved_group_with_provenance_iter() {
         let registry = SemConvRegistry::try_from_path_pattern("test", "data/c*.yaml")
@@ -9,7 +9,7 @@ use std::sync::Arc;
 use crate::manifest::RegistryManifest;
 use crate::Error;
 use weaver_common::log_info;
-use weaver_common::vdir::{VirtualDirectory, VirtualDirectoryPath};
+use weaver_common::vdir::{LoadOptions, VirtualDirectory, VirtualDirectoryPath};
 
 /// The name of the registry manifest file.
 pub const REGISTRY_MANIFEST: &str = "registry_manifest.yaml";
@@ -36,10 +36,24 @@ impl RegistryRepo {
     pub fn try_new(
         registry_id_if_no_manifest: &str,
         registry_path: &VirtualDirectoryPath,
+    ) -> Result<Self, Error> {
+        Self::try_new_with_options(
+            registry_id_if_no_manifest,
+            registry_path,
+            &LoadOptions::default(),
+        )
+    }
+
+    /// Same as [`RegistryRepo::try_new`] but with the given options to load
+    /// the registry from a URL.
+    pub fn try_new_with_options(
+        registry_id_if_no_manifest: &str,
+        registry_path: &VirtualDirectoryPath,
+        options: &LoadOptions,
     ) -> Result<Self, Error> {
         let mut registry_repo = Self {
             id: Arc::from(registry_id_if_no_manifest),
-            registry: VirtualDirectory::try_new(registry_path)
+            registry: VirtualDirectory::try_new_with_options(registry_path, options)
                 .map_err(Error::VirtualDirectoryError)?,
             manifest: None,
         };
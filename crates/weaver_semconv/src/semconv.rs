@@ -2,15 +2,17 @@
 
 //! Semantic convention specification.
 
-use crate::group::{GroupSpec, GroupWildcard};
+use crate::group::{GroupSpec, GroupWildcard, InstrumentSpec};
 use crate::json_schema::JsonSchemaValidator;
 use crate::provenance::Provenance;
 use crate::v2::SemConvSpecV2;
-use crate::Error;
+use crate::{Error, YamlErrorSource};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::path::Path;
+use std::str::FromStr;
+use weaver_common::error::handle_errors;
 use weaver_common::result::WResult;
 
 /// A semantic convention file as defined [here](/schemas/semconv-syntax.md)
@@ -132,15 +134,20 @@ pub struct SemConvSpecV1WithProvenance {
 }
 
 impl SemConvSpecV1 {
-    fn validate(self, provenance: &str) -> WResult<Self, Error> {
+    fn validate(mut self, provenance: &str) -> WResult<Self, Error> {
         let mut errors: Vec<Error> = vec![];
 
-        for group in &self.groups {
+        for (index, group) in self.groups.iter_mut().enumerate() {
             match group.validate(provenance) {
                 WResult::Ok(_) => {}
-                WResult::OkWithNFEs(_, errs) => errors.extend(errs),
+                WResult::OkWithNFEs(_, errs) => {
+                    let group_path = format!("/groups/{index}");
+                    errors.extend(errs.into_iter().map(|e| e.with_path_prefix(&group_path)));
+                }
                 WResult::FatalErr(e) => return WResult::FatalErr(e),
             }
+            // Trailing whitespace has been reported above, keep the rendered docs clean.
+            group.trim_briefs();
         }
 
         WResult::with_non_fatal_errors(self, errors)
@@ -213,6 +220,152 @@ fn provenance_path_to_name(path: &str) -> String {
     result
 }
 
+/// Returns an error for each attribute reference (`ref`) of the given YAML
+/// semantic convention file setting a field that only belongs to attribute
+/// definitions (e.g. `type`).
+///
+/// Such a reference doesn't match any variant of [`crate::attribute::AttributeSpec`],
+/// this check explains why the deserialization failed.
+fn ref_definition_field_errors(yaml: &serde_yaml::Value, path_or_url: &str) -> Vec<Error> {
+    let definition_only_fields = crate::attribute::definition_only_attribute_fields();
+    let mut errors = vec![];
+    let groups = yaml
+        .get("groups")
+        .and_then(serde_yaml::Value::as_sequence)
+        .into_iter()
+        .flatten();
+    for (group_index, group) in groups.enumerate() {
+        let attributes = group
+            .get("attributes")
+            .and_then(serde_yaml::Value::as_sequence)
+            .into_iter()
+            .flatten();
+        for (attribute_index, attribute) in attributes.enumerate() {
+            let Some(attribute_ref) = attribute.get("ref").and_then(serde_yaml::Value::as_str)
+            else {
+                continue;
+            };
+            let fields = attribute
+                .as_mapping()
+                .into_iter()
+                .flat_map(|mapping| mapping.keys())
+                .filter_map(serde_yaml::Value::as_str)
+                .filter(|field| definition_only_fields.contains(*field));
+            for field in fields {
+                let reason = if field == "type" {
+                    "A reference can't redefine the type, it inherits the type of the referenced attribute.".to_owned()
+                } else {
+                    format!("A reference can't set the `{field}` field, it only belongs to attribute definitions.")
+                };
+                errors.push(Error::InvalidAttribute {
+                    path_or_url: path_or_url.to_owned(),
+                    group_id: group
+                        .get("id")
+                        .and_then(serde_yaml::Value::as_str)
+                        .unwrap_or_default()
+                        .to_owned(),
+                    attribute_id: attribute_ref.to_owned(),
+                    error: format!(
+                        "{reason} The fields allowed on a reference are: {}.",
+                        crate::attribute::ref_attribute_fields()
+                            .iter()
+                            .map(|field| format!("`{field}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    path: format!("/groups/{group_index}/attributes/{attribute_index}/{field}")
+                        .into(),
+                });
+            }
+        }
+    }
+    errors
+}
+
+/// Returns an error for each group of the given YAML semantic convention file
+/// whose `instrument` is not one of the canonical spellings (e.g.
+/// `UpDownCounter` instead of `updowncounter`).
+///
+/// This check replaces the opaque serde error about the unknown variant.
+fn instrument_errors(yaml: &serde_yaml::Value, path_or_url: &str) -> Vec<Error> {
+    let groups = yaml
+        .get("groups")
+        .and_then(serde_yaml::Value::as_sequence)
+        .into_iter()
+        .flatten();
+    groups
+        .filter_map(|group| {
+            let instrument = group.get("instrument")?;
+            let valid = instrument
+                .as_str()
+                .is_some_and(|instrument| InstrumentSpec::from_str(instrument).is_ok());
+            (!valid).then(|| Error::InvalidMetric {
+                path_or_url: path_or_url.to_owned(),
+                group_id: group
+                    .get("id")
+                    .and_then(serde_yaml::Value::as_str)
+                    .unwrap_or_default()
+                    .to_owned(),
+                error: format!(
+                    "The instrument `{}` is invalid, the valid instruments are: `counter`, `gauge`, `histogram`, `updowncounter`.",
+                    serde_yaml::to_string(instrument)
+                        .unwrap_or_default()
+                        .trim_end()
+                ),
+            })
+        })
+        .collect()
+}
+
+/// Returns the errors explaining why the deserialization of the given YAML
+/// semantic convention file failed, if any can be identified.
+fn deserialization_errors(yaml: &serde_yaml::Value, path_or_url: &str) -> Vec<Error> {
+    let mut errors = ref_definition_field_errors(yaml, path_or_url);
+    errors.extend(instrument_errors(yaml, path_or_url));
+    errors
+}
+
+/// Deserializes a semantic convention spec, expanding the YAML merge keys
+/// (`<<: *anchor`) that serde_yaml doesn't support natively.
+///
+/// The document is only merged when the direct deserialization fails, so the
+/// errors of the documents without merge keys keep their location.
+pub(crate) fn spec_from_str(content: &str) -> Result<SemConvSpec, serde_yaml::Error> {
+    serde_yaml::from_str(content).or_else(|e| {
+        match serde_yaml::from_str::<serde_yaml::Value>(content) {
+            Ok(value) => spec_from_merged_value(value).unwrap_or(Err(e)),
+            Err(_) => Err(e),
+        }
+    })
+}
+
+/// Deserializes the spec of a YAML document once its merge keys are expanded,
+/// returns `None` when the document has no merge keys.
+fn spec_from_merged_value(
+    mut value: serde_yaml::Value,
+) -> Option<Result<SemConvSpec, serde_yaml::Error>> {
+    if !has_merge_keys(&value) {
+        return None;
+    }
+    Some(
+        value
+            .apply_merge()
+            .and_then(|()| serde_yaml::from_value(value)),
+    )
+}
+
+/// Returns true if the YAML value contains a merge key (`<<`) at any depth.
+fn has_merge_keys(value: &serde_yaml::Value) -> bool {
+    match value {
+        serde_yaml::Value::Mapping(mapping) => mapping
+            .iter()
+            .any(|(key, value)| key.as_str() == Some("<<") || has_merge_keys(value)),
+        serde_yaml::Value::Sequence(values) => values.iter().any(has_merge_keys),
+        serde_yaml::Value::Tagged(tagged) => has_merge_keys(&tagged.value),
+        _ => false,
+    }
+}
+
 impl SemConvSpecWithProvenance {
     /// True if this specification contains V2 version.
     fn is_v2(&self) -> bool {
@@ -309,9 +462,13 @@ impl SemConvSpecWithProvenance {
                     // Rewind file for second read
                     _ = semconv_file.rewind().ok();
 
-                    let original_error = e.to_string();
+                    let source = YamlErrorSource::from(e);
                     let value: Result<Value, _> = serde_yaml::from_reader(&mut semconv_file);
                     if let Ok(yaml_value) = value {
+                        if let Some(Ok(spec)) = spec_from_merged_value(yaml_value.clone()) {
+                            return Ok(spec);
+                        }
+                        handle_errors(deserialization_errors(&yaml_value, provenance))?;
                         // TODO - Check if we should use versioned or unversioned validator.
                         if yaml_value
                             .as_mapping()
@@ -320,17 +477,22 @@ impl SemConvSpecWithProvenance {
                             .unwrap_or(false)
                         {
                             // Use versioned validator.
-                            versioned_validator.validate_yaml(yaml_value, provenance, e)?;
+                            versioned_validator.validate_yaml(
+                                yaml_value,
+                                provenance,
+                                source.clone(),
+                            )?;
                         } else {
-                            unversioned_validator.validate_yaml(yaml_value, provenance, e)?;
+                            unversioned_validator.validate_yaml(
+                                yaml_value,
+                                provenance,
+                                source.clone(),
+                            )?;
                         }
                     }
 
                     // Fallback: return original serde error
-                    Err(Error::DeserializationError {
-                        path_or_url: provenance.to_owned(),
-                        error: original_error,
-                    })
+                    Err(Error::deserialization(provenance, source))
                 }
             }
         }
@@ -394,11 +556,13 @@ impl SemConvSpecWithProvenance {
         provenance: Provenance,
         spec: &str,
     ) -> WResult<SemConvSpecWithProvenance, Error> {
-        let raw_spec = match serde_yaml::from_str::<SemConvSpec>(spec).map_err(|e| {
-            Error::DeserializationError {
-                path_or_url: "NA".to_owned(),
-                error: e.to_string(),
-            }
+        let raw_spec = match spec_from_str(spec).map_err(|e| {
+            let errors = serde_yaml::from_str(spec)
+                .map(|yaml| deserialization_errors(&yaml, &provenance.path))
+                .unwrap_or_default();
+            handle_errors(errors)
+                .err()
+                .unwrap_or_else(|| Error::deserialization("NA", e))
         }) {
             Ok(semconv_spec) => {
                 // Important note: the resolution process expects this step of validation to be done for
@@ -415,10 +579,11 @@ impl SemConvSpecWithProvenance {
 mod tests {
     use super::*;
     use crate::{
+        attribute::AttributeSpec,
         v2::{attribute::AttributeDef, CommonFields},
         Error::{
             DeserializationError, InvalidAttribute, InvalidAttributeWarning, InvalidExampleWarning,
-            InvalidGroupMissingType, InvalidGroupStability, InvalidSemConvSpec,
+            InvalidGroupMissingType, InvalidGroupStability, InvalidMetric, InvalidSemConvSpec,
             InvalidSpanMissingSpanKind, RegistryNotFound,
         },
     };
@@ -600,6 +765,7 @@ mod tests {
                         error:
                             "This attribute is not deprecated and does not contain a brief field."
                                 .to_owned(),
+                        path: "/groups/0/attributes/0".into(),
                     },
                     InvalidExampleWarning {
                         path_or_url: "<str>".to_owned(),
@@ -607,6 +773,7 @@ mod tests {
                         attribute_id: "attr1".to_owned(),
                         error: "This attribute is a string but it does not contain any examples."
                             .to_owned(),
+                        path: "/groups/0/attributes/0".into(),
                     },
                     InvalidAttribute {
                         path_or_url: "<str>".to_owned(),
@@ -615,12 +782,14 @@ mod tests {
                         error:
                             "This attribute is not deprecated and does not contain a brief field."
                                 .to_owned(),
+                        path: "/groups/1/attributes/0".into(),
                     },
                     InvalidAttributeWarning {
                         path_or_url: "<str>".to_owned(),
                         group_id: "group2".to_owned(),
                         attribute_id: "attr2".to_owned(),
                         error: "Missing stability field.".to_owned(),
+                        path: "/groups/1/attributes/0".into(),
                     },
                     InvalidGroupMissingType {
                         path_or_url: "<str>".to_owned(),
@@ -634,6 +803,136 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_semconv_spec_ref_with_type() {
+        let spec = r#"
+        groups:
+          - id: "group1"
+            type: attribute_group
+            brief: "description1"
+            attributes:
+              - ref: "attr1"
+                type: "int"
+                requirement_level: required
+        "#;
+
+        let result =
+            SemConvSpecWithProvenance::from_string(Provenance::new("registry", "test"), spec)
+                .into_result_failing_non_fatal();
+        let Err(InvalidAttribute {
+            path_or_url,
+            group_id,
+            attribute_id,
+            error,
+            path,
+        }) = result
+        else {
+            panic!("Expected an InvalidAttribute error, got {result:?}");
+        };
+        assert_eq!(path_or_url, "test");
+        assert_eq!(group_id, "group1");
+        assert_eq!(attribute_id, "attr1");
+        assert!(error.starts_with(
+            "A reference can't redefine the type, it inherits the type of the referenced attribute. The fields allowed on a reference are: "
+        ));
+        assert!(error.contains("`requirement_level`"));
+        assert_eq!(&*path, "/groups/0/attributes/0/type");
+
+        assert_eq!(
+            crate::attribute::definition_only_attribute_fields(),
+            ["id", "renamed_from", "type"]
+                .into_iter()
+                .map(String::from)
+                .collect()
+        );
+    }
+
+    #[test]
+    fn test_semconv_spec_merge_keys() {
+        let spec = r#"
+        groups:
+          - id: registry.http
+            type: attribute_group
+            brief: "HTTP attributes."
+            attributes:
+              - &http_attribute
+                id: http.request.method
+                type: string
+                brief: "The HTTP method."
+                stability: stable
+                examples: [GET]
+              - <<: *http_attribute
+                id: http.response.header
+                brief: "The HTTP response header."
+        "#;
+
+        let spec =
+            SemConvSpecWithProvenance::from_string(Provenance::new("registry", "test"), spec)
+                .into_result_failing_non_fatal()
+                .expect("Merge keys should be expanded");
+        let attributes = &spec.spec.into_v1("test").groups[0].attributes;
+        assert_eq!(attributes.len(), 2);
+        assert_eq!(attributes[1].id(), "http.response.header");
+        assert_eq!(attributes[1].brief(), "The HTTP response header.");
+        assert!(matches!(
+            &attributes[1],
+            AttributeSpec::Id {
+                examples: Some(_),
+                ..
+            }
+        ));
+
+        // A document without merge keys reports the original error.
+        let spec = "groups:\n  - id: test\n    unknown: true\n";
+        assert_eq!(
+            spec_from_str(spec).unwrap_err().to_string(),
+            serde_yaml::from_str::<SemConvSpec>(spec)
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_semconv_spec_instrument() {
+        let spec = |instrument: &str| {
+            format!(
+                r#"
+        groups:
+          - id: metric.queue.size
+            type: metric
+            metric_name: queue.size
+            brief: "The size of the queue."
+            stability: stable
+            instrument: {instrument}
+            unit: "{{item}}"
+        "#
+            )
+        };
+
+        for instrument in ["updowncounter", "counter"] {
+            let result = SemConvSpecWithProvenance::from_string(
+                Provenance::new("registry", "test"),
+                &spec(instrument),
+            )
+            .into_result_failing_non_fatal();
+            assert!(result.is_ok(), "{instrument}: {result:?}");
+        }
+
+        let result = SemConvSpecWithProvenance::from_string(
+            Provenance::new("registry", "test"),
+            &spec("UpDownCounter"),
+        )
+        .into_result_failing_non_fatal();
+        assert_eq!(
+            result.unwrap_err(),
+            InvalidMetric {
+                path_or_url: "test".to_owned(),
+                group_id: "metric.queue.size".to_owned(),
+                error: "The instrument `UpDownCounter` is invalid, the valid instruments are: `counter`, `gauge`, `histogram`, `updowncounter`.".to_owned(),
+            }
+        );
+    }
+
     #[test]
     fn test_semconv_spec_with_provenance_from_file() {
         let validator = JsonSchemaValidator::new_all_versions();
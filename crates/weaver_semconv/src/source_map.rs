@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! The line and column numbers of the groups, attributes and enum members of a
+//! semantic convention file, e.g. to point a finding at its exact location.
+//!
+//! serde_yaml doesn't expose the location of the deserialized values, so the
+//! source is scanned on its own and the elements are keyed by their ids.
+//!
+//! Note: Only the block style (`- id: ...`) of the `groups`, `attributes` and
+//! `members` sequences of the unversioned format is supported, the elements of
+//! flow sequences (`[...]`) are not located.
+
+use crate::{Error, ErrorReport};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// A position in a semantic convention file.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// The line number (1-based).
+    pub line: usize,
+    /// The column number (1-based).
+    pub column: usize,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The locations of the elements of a semantic convention file. An element is
+/// located at its `id` field (`ref` for the attribute references).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    groups: HashMap<String, SourceLocation>,
+    attributes: HashMap<(String, String), SourceLocation>,
+    enum_members: HashMap<(String, String, String), SourceLocation>,
+}
+
+impl SourceMap {
+    /// Builds the source map of a semantic convention file.
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be read.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        std::fs::read_to_string(path)
+            .map(|content| Self::from_yaml(&content))
+            .map_err(|e| Error::RegistryNotFound {
+                path_or_url: path.display().to_string(),
+                error: e.to_string(),
+            })
+    }
+
+    /// Builds the source map of the content of a semantic convention file. The
+    /// elements that can't be located are ignored.
+    #[must_use]
+    pub fn from_yaml(content: &str) -> Self {
+        let mut source_map = SourceMap::default();
+        // The block keys enclosing the current line, with their indentation.
+        // Block scalars (`|` or `>`) are tracked as `None` to skip their lines.
+        let mut parents: Vec<(usize, Option<&str>)> = vec![];
+        let mut group_id: Option<String> = None;
+        let mut attribute_id: Option<String> = None;
+
+        for (index, line) in content.lines().enumerate() {
+            let content = line.trim_start_matches(' ');
+            if content.trim_end().is_empty() || content.starts_with('#') {
+                continue;
+            }
+            let indent = line.len() - content.len();
+            let is_item = content.trim_end() == "-" || content.starts_with("- ");
+            while parents.last().is_some_and(|(parent_indent, _)| {
+                *parent_indent > indent || (*parent_indent == indent && !is_item)
+            }) {
+                _ = parents.pop();
+            }
+            if matches!(parents.last(), Some((_, None))) {
+                continue;
+            }
+
+            let entry = if is_item {
+                content[1..].trim_start_matches(' ')
+            } else {
+                content
+            };
+            let Some((key, value)) = split_key_value(entry) else {
+                continue;
+            };
+            let key_indent = line.len() - entry.len();
+            let location = SourceLocation {
+                line: index + 1,
+                column: key_indent + 1,
+            };
+            match (parents.last().and_then(|(_, parent)| *parent), key) {
+                (Some("groups"), "id") => {
+                    _ = source_map.groups.insert(value.to_owned(), location);
+                    group_id = Some(value.to_owned());
+                    attribute_id = None;
+                }
+                (Some("attributes"), "id" | "ref") => {
+                    if let Some(group_id) = &group_id {
+                        _ = source_map
+                            .attributes
+                            .insert((group_id.clone(), value.to_owned()), location);
+                    }
+                    attribute_id = Some(value.to_owned());
+                }
+                (Some("members"), "id") => {
+                    if let (Some(group_id), Some(attribute_id)) = (&group_id, &attribute_id) {
+                        _ = source_map.enum_members.insert(
+                            (group_id.clone(), attribute_id.clone(), value.to_owned()),
+                            location,
+                        );
+                    }
+                }
+                _ => {}
+            }
+            if value.is_empty() {
+                parents.push((key_indent, Some(key)));
+            } else if value.starts_with('|') || value.starts_with('>') {
+                parents.push((key_indent, None));
+            }
+        }
+        source_map
+    }
+
+    /// Returns the location of a group.
+    #[must_use]
+    pub fn group(&self, group_id: &str) -> Option<SourceLocation> {
+        self.groups.get(group_id).copied()
+    }
+
+    /// Returns the location of an attribute (or attribute reference) of a
+    /// group, the attribute id being the one written in the file.
+    #[must_use]
+    pub fn attribute(&self, group_id: &str, attribute_id: &str) -> Option<SourceLocation> {
+        self.attributes
+            .get(&(group_id.to_owned(), attribute_id.to_owned()))
+            .copied()
+    }
+
+    /// Returns the location of a member of an enum attribute.
+    #[must_use]
+    pub fn enum_member(
+        &self,
+        group_id: &str,
+        attribute_id: &str,
+        member_id: &str,
+    ) -> Option<SourceLocation> {
+        self.enum_members
+            .get(&(
+                group_id.to_owned(),
+                attribute_id.to_owned(),
+                member_id.to_owned(),
+            ))
+            .copied()
+    }
+
+    /// Returns the location of the element a finding is about: its attribute
+    /// if known and located, its group otherwise.
+    #[must_use]
+    pub fn locate(&self, report: &ErrorReport) -> Option<SourceLocation> {
+        let group_id = report.group_id.as_deref()?;
+        report
+            .attribute_id
+            .as_deref()
+            .and_then(|attribute_id| self.attribute(group_id, attribute_id))
+            .or_else(|| self.group(group_id))
+    }
+}
+
+/// Splits a `key: value` entry, returns the unquoted key and value (without
+/// trailing comment), the value being empty for a block key.
+fn split_key_value(entry: &str) -> Option<(&str, &str)> {
+    let (key, value) = match entry.split_once(": ") {
+        Some((key, value)) => (key, value),
+        None => (entry.trim_end().strip_suffix(':')?, ""),
+    };
+    let value = match value.find(" #") {
+        Some(comment) => &value[..comment],
+        None if value.starts_with('#') => "",
+        None => value,
+    };
+    Some((unquote(key.trim()), unquote(value.trim())))
+}
+
+/// Removes the quotes around a scalar.
+fn unquote(scalar: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| {
+            scalar
+                .strip_prefix(quote)
+                .and_then(|scalar| scalar.strip_suffix(quote))
+        })
+        .unwrap_or(scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provenance::Provenance;
+    use crate::semconv::SemConvSpecWithProvenance;
+    use weaver_common::result::WResult;
+
+    const SPEC: &str = r#"# A test registry.
+groups:
+  - id: registry.session
+    type: attribute_group
+    brief: >
+      Session attributes.
+      id: not.an.attribute
+    attributes:
+      - id: session.id
+        type: string
+        brief: The session id.
+        stability: stable
+        examples: [abc]
+      - id: "session.state"  # The state.
+        type:
+          members:
+            - id: active
+              value: active
+              stability: stable
+            - id: idle
+              value: idle
+              stability: stable
+        brief: The session state.
+        stability: stable
+  - type: span
+    id: span.session
+    brief: A session span.
+    stability: stable
+    span_kind: internal
+    attributes:
+    - ref: session.id
+      requirement_level: required
+"#;
+
+    #[test]
+    fn test_source_map() {
+        let source_map = SourceMap::from_yaml(SPEC);
+        let location = |line, column| Some(SourceLocation { line, column });
+
+        assert_eq!(source_map.group("registry.session"), location(3, 5));
+        assert_eq!(source_map.group("span.session"), location(26, 5));
+        assert_eq!(
+            source_map.attribute("registry.session", "session.id"),
+            location(9, 9)
+        );
+        assert_eq!(
+            source_map.attribute("registry.session", "session.state"),
+            location(14, 9)
+        );
+        assert_eq!(
+            source_map.attribute("registry.session", "not.an.attribute"),
+            None
+        );
+        assert_eq!(
+            source_map.enum_member("registry.session", "session.state", "idle"),
+            location(20, 15)
+        );
+        assert_eq!(
+            source_map.attribute("span.session", "session.id"),
+            location(31, 7)
+        );
+        assert_eq!(location(9, 9).unwrap().to_string(), "9:9");
+    }
+
+    #[test]
+    fn test_locate_finding() {
+        let spec = SPEC.replace("examples: [abc]", "examples: [1]");
+        let WResult::OkWithNFEs(_, errors) =
+            SemConvSpecWithProvenance::from_string(Provenance::new("test", "test.yaml"), &spec)
+        else {
+            panic!("The invalid example should be reported");
+        };
+        let reports: Vec<_> = errors.iter().flat_map(Error::reports).collect();
+        assert_eq!(reports.len(), 1, "{reports:#?}");
+        assert_eq!(
+            SourceMap::from_yaml(&spec).locate(&reports[0]),
+            Some(SourceLocation { line: 9, column: 9 })
+        );
+    }
+}
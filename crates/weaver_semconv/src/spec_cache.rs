@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! An on-disk cache of parsed semantic convention specs.
+//!
+//! The entries are keyed by the SHA-256 hash of the content of the semantic
+//! convention files, so a modified file is always parsed again. Each entry is
+//! stamped with the version of the cache format, entries written with another
+//! version are ignored.
+
+use crate::provenance::Provenance;
+use crate::semconv::{spec_from_str, SemConvSpec, SemConvSpecWithProvenance};
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use weaver_common::result::WResult;
+
+/// The default version stamp of the cache entries.
+///
+/// Entries are invalidated on every release of this crate as the format of the
+/// semantic convention specs may change.
+pub const SPEC_CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// An entry of the cache.
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// The version stamp of the cache that wrote this entry.
+    version: String,
+    /// The parsed (but not yet validated) semantic convention spec.
+    spec: SemConvSpec,
+}
+
+/// A cache of parsed semantic convention specs stored in a directory.
+#[derive(Debug, Clone)]
+pub struct SpecCache {
+    /// The directory containing the cache entries.
+    dir: PathBuf,
+    /// The version stamp of the cache entries.
+    version: String,
+}
+
+impl SpecCache {
+    /// Creates a cache storing its entries in the given directory.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self::with_version(dir, SPEC_CACHE_VERSION)
+    }
+
+    /// Creates a cache storing its entries in the given directory with a custom
+    /// version stamp.
+    pub fn with_version<P: Into<PathBuf>, S: Into<String>>(dir: P, version: S) -> Self {
+        SpecCache {
+            dir: dir.into(),
+            version: version.into(),
+        }
+    }
+
+    /// Loads the semantic convention spec from a file, using the cached parsed
+    /// spec when the content of the file has already been parsed.
+    ///
+    /// The spec is validated on every call, so the non-fatal errors are reported
+    /// the same way on a cache hit and on a cache miss. A failure to write the
+    /// cache entry is only logged.
+    pub fn load<P: AsRef<Path>>(
+        &self,
+        registry_id: &str,
+        path: P,
+    ) -> WResult<SemConvSpecWithProvenance, Error> {
+        let path = path.as_ref().display().to_string();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) => {
+                return WResult::FatalErr(Error::RegistryNotFound {
+                    path_or_url: path,
+                    error: e.to_string(),
+                })
+            }
+        };
+
+        let entry_path = self.entry_path(&content);
+        let spec = if let Some(spec) = self.read_entry(&entry_path) {
+            spec
+        } else {
+            let spec = match spec_from_str(&content) {
+                Ok(spec) => spec,
+                Err(e) => return WResult::FatalErr(Error::deserialization(&path, e)),
+            };
+            if let Err(e) = self.write_entry(&entry_path, &spec) {
+                log::warn!(
+                    "Failed to write the spec cache entry `{}`: {e}",
+                    entry_path.display()
+                );
+            }
+            spec
+        };
+
+        let provenance = Provenance::new(registry_id, &path);
+        spec.validate(&path)
+            .map(|spec| SemConvSpecWithProvenance { spec, provenance })
+    }
+
+    /// Returns the path of the cache entry for the given file content.
+    fn entry_path(&self, content: &str) -> PathBuf {
+        let mut file_name = to_hex(&Sha256::digest(content.as_bytes()));
+        file_name.push_str(".json");
+        self.dir.join(file_name)
+    }
+
+    /// Reads a cache entry, returning `None` if the entry doesn't exist, can't
+    /// be read or has been written with another version stamp.
+    fn read_entry(&self, entry_path: &Path) -> Option<SemConvSpec> {
+        let content = fs::read_to_string(entry_path).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        (entry.version == self.version).then_some(entry.spec)
+    }
+
+    /// Writes a cache entry.
+    fn write_entry(&self, entry_path: &Path, spec: &SemConvSpec) -> std::io::Result<()> {
+        #[derive(Serialize)]
+        struct CacheEntryRef<'a> {
+            version: &'a str,
+            spec: &'a SemConvSpec,
+        }
+
+        fs::create_dir_all(&self.dir)?;
+        let content = serde_json::to_string(&CacheEntryRef {
+            version: &self.version,
+            spec,
+        })?;
+        fs::write(entry_path, content)
+    }
+}
+
+/// Returns the lowercase hexadecimal representation of a hash.
+pub(crate) fn to_hex(hash: &[u8]) -> String {
+    let mut hex = String::with_capacity(hash.len() * 2);
+    for byte in hash {
+        _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"
+groups:
+  - id: registry.test
+    type: attribute_group
+    brief: A test group.
+    attributes:
+      - id: test.name
+        type: string
+        brief: The name.
+        stability: stable
+        examples: [foo]
+"#;
+
+    fn briefs(spec: SemConvSpecWithProvenance) -> Vec<String> {
+        spec.into_v1()
+            .spec
+            .groups()
+            .iter()
+            .map(|g| g.brief.clone())
+            .collect()
+    }
+
+    /// Overwrites the brief of the cached spec to detect cache hits.
+    fn tamper_entry(entry_path: &Path, brief: &str) {
+        let content = fs::read_to_string(entry_path).expect("Failed to read the entry");
+        let tampered = content.replace("A test group.", brief);
+        fs::write(entry_path, tampered).expect("Failed to write the entry");
+    }
+
+    #[test]
+    fn test_spec_cache() {
+        let cache_dir = tempfile::tempdir().expect("Failed to create a temp dir");
+        let spec_dir = tempfile::tempdir().expect("Failed to create a temp dir");
+        let spec_path = spec_dir.path().join("test.yaml");
+        fs::write(&spec_path, SPEC).expect("Failed to write the spec");
+        let cache = SpecCache::new(cache_dir.path());
+
+        // Cache miss, the file is parsed and the entry is written.
+        let entry_path = cache.entry_path(SPEC);
+        assert!(!entry_path.exists());
+        let spec = cache
+            .load("test", &spec_path)
+            .into_result_failing_non_fatal()
+            .expect("Failed to load the spec");
+        assert_eq!(briefs(spec), vec!["A test group."]);
+        assert!(entry_path.exists());
+
+        // Cache hit, the cached spec is returned.
+        tamper_entry(&entry_path, "A cached group.");
+        let spec = cache
+            .load("test", &spec_path)
+            .into_result_failing_non_fatal()
+            .expect("Failed to load the spec");
+        assert_eq!(briefs(spec), vec!["A cached group."]);
+
+        // The content changed, the file is parsed again.
+        let updated_spec = SPEC.replace("A test group.", "An updated group.");
+        fs::write(&spec_path, &updated_spec).expect("Failed to write the spec");
+        let spec = cache
+            .load("test", &spec_path)
+            .into_result_failing_non_fatal()
+            .expect("Failed to load the spec");
+        assert_eq!(briefs(spec), vec!["An updated group."]);
+        assert!(cache.entry_path(&updated_spec).exists());
+
+        // Entries written with another version stamp are ignored.
+        fs::write(&spec_path, SPEC).expect("Failed to write the spec");
+        let cache = SpecCache::with_version(cache_dir.path(), "other");
+        let spec = cache
+            .load("test", &spec_path)
+            .into_result_failing_non_fatal()
+            .expect("Failed to load the spec");
+        assert_eq!(briefs(spec), vec!["A test group."]);
+    }
+}
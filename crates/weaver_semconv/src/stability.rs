@@ -36,6 +36,7 @@ impl Display for Stability {
             Stability::Alpha => write!(f, "alpha"),
             Stability::Beta => write!(f, "beta"),
             Stability::ReleaseCandidate => write!(f, "release_candidate"),
+            #[allow(deprecated)]
             Stability::Deprecated => write!(f, "deprecated"),
         }
     }
@@ -47,6 +48,7 @@ mod tests {
     use serde_json;
 
     #[test]
+    #[allow(deprecated)]
     fn test_deserialize_stability() {
         let deprecated: Stability = serde_json::from_str("\"deprecated\"").unwrap();
         assert_eq!(deprecated, Stability::Deprecated);
@@ -3,9 +3,12 @@
 //! Statistics about the semantic convention registry.
 
 use crate::group::GroupType;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
 
 /// Statistics about the semantic convention registry.
+#[derive(Debug, Serialize)]
 #[must_use]
 pub struct Stats {
     /// Number of semconv files.
@@ -14,8 +17,39 @@ pub struct Stats {
     pub group_count: usize,
     /// Breakdown of group statistics by type.
     pub group_breakdown: HashMap<GroupType, usize>,
+    /// Breakdown of the groups by stability. Deprecated groups are counted as
+    /// `deprecated` and groups without stability as `unspecified`.
+    pub stability_breakdown: BTreeMap<String, usize>,
     /// Number of attributes.
     pub attribute_count: usize,
     /// Number of metrics.
     pub metric_count: usize,
+    /// Number of validation warnings reported on the loaded groups. Findings
+    /// fixed while loading (e.g. trailing whitespace) are not counted.
+    pub warning_count: usize,
+}
+
+impl Display for Stats {
+    /// Formats the stats as a compact two-column table.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut group_breakdown: Vec<_> = self
+            .group_breakdown
+            .iter()
+            .map(|(group_type, count)| (group_type.to_string(), count))
+            .collect();
+        group_breakdown.sort();
+
+        writeln!(f, "{:<24}{}", "files", self.file_count)?;
+        writeln!(f, "{:<24}{}", "groups", self.group_count)?;
+        for (group_type, count) in group_breakdown {
+            writeln!(f, "  {group_type:<22}{count}")?;
+        }
+        writeln!(f, "stability")?;
+        for (stability, count) in &self.stability_breakdown {
+            writeln!(f, "  {stability:<22}{count}")?;
+        }
+        writeln!(f, "{:<24}{}", "attributes", self.attribute_count)?;
+        writeln!(f, "{:<24}{}", "metrics", self.metric_count)?;
+        writeln!(f, "{:<24}{}", "warnings", self.warning_count)
+    }
 }
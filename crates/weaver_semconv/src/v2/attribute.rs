@@ -70,6 +70,7 @@ impl AttributeRef {
             requirement_level: self.requirement_level,
             sampling_relevant: None,
             note: self.note,
+            examples_note: None,
             stability: self.stability,
             deprecated: self.deprecated,
             prefix: false,
@@ -79,6 +80,7 @@ impl AttributeRef {
                 Some(self.annotations)
             },
             role: None,
+            scope: None,
         }
     }
     /// Converts a v2 refinement into a v1 AttributeSpec.
@@ -92,6 +94,7 @@ impl AttributeRef {
             requirement_level: self.requirement_level,
             sampling_relevant: None,
             note: self.note,
+            examples_note: None,
             stability: self.stability,
             deprecated: self.deprecated,
             prefix: false,
@@ -101,6 +104,7 @@ impl AttributeRef {
                 Some(self.annotations)
             },
             role: Some(role),
+            scope: None,
         }
     }
 }
@@ -134,6 +138,7 @@ impl AttributeDef {
     pub fn into_v1_attribute(self) -> AttributeSpec {
         AttributeSpec::Id {
             id: self.key,
+            renamed_from: None,
             r#type: self.r#type,
             brief: Some(self.common.brief),
             examples: self.examples,
@@ -141,6 +146,7 @@ impl AttributeDef {
             requirement_level: Default::default(),
             sampling_relevant: None,
             note: self.common.note,
+            examples_note: None,
             stability: Some(self.common.stability),
             deprecated: self.common.deprecated,
             annotations: if self.common.annotations.is_empty() {
@@ -149,6 +155,7 @@ impl AttributeDef {
                 Some(self.common.annotations)
             },
             role: None,
+            scope: None,
         }
     }
 }
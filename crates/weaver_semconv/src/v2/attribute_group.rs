@@ -115,6 +115,7 @@ impl AttributeGroup {
 
                 GroupSpec {
                     id: format!("{}", &internal.id),
+                    renamed_from: None,
                     r#type: GroupType::AttributeGroup,
                     brief: format!("{}", &internal.id),
                     note: "".to_owned(),
@@ -142,6 +143,7 @@ impl AttributeGroup {
 
                 GroupSpec {
                     id: format!("{}", public.id),
+                    renamed_from: None,
                     r#type: GroupType::AttributeGroup,
                     brief: public.common.brief,
                     note: public.common.note,
@@ -42,6 +42,7 @@ impl Entity {
 
         GroupSpec {
             id: format!("entity.{}", &self.r#type),
+            renamed_from: None,
             r#type: crate::group::GroupType::Entity,
             brief: self.common.brief,
             note: self.common.note,
@@ -40,6 +40,7 @@ impl Event {
         let (attribute_refs, include_groups) = split_attributes_and_groups(self.attributes);
         GroupSpec {
             id: format!("event.{}", &self.name),
+            renamed_from: None,
             r#type: crate::group::GroupType::Event,
             brief: self.common.brief,
             note: self.common.note,
@@ -50,6 +50,7 @@ impl Metric {
         let (attribute_refs, include_groups) = split_attributes_and_groups(self.attributes);
         GroupSpec {
             id: format!("metric.{}", &self.name),
+            renamed_from: None,
             r#type: crate::group::GroupType::Metric,
             brief: self.common.brief,
             note: self.common.note,
@@ -84,6 +84,7 @@ impl Span {
         let (attribute_refs, include_groups) = split_span_attributes_and_groups(self.attributes);
         GroupSpec {
             id: format!("span.{}", &self.r#type),
+            renamed_from: None,
             r#type: GroupType::Span,
             brief: self.common.brief,
             note: self.common.note,
@@ -149,6 +150,7 @@ impl SpanAttributeRef {
             requirement_level: self.base.requirement_level,
             sampling_relevant: self.sampling_relevant,
             note: self.base.note,
+            examples_note: None,
             stability: self.base.stability,
             deprecated: self.base.deprecated,
             prefix: false,
@@ -158,6 +160,7 @@ impl SpanAttributeRef {
                 Some(self.base.annotations)
             },
             role: None,
+            scope: None,
         }
     }
 }
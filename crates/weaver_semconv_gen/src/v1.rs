@@ -203,6 +203,7 @@ mod tests {
         };
         let mut diag_msgs = DiagnosticMessages::empty();
         let registry_repo = RegistryRepo::try_new("main", &registry_path)?;
+        #[allow(deprecated)]
         let generator = SnippetGenerator::try_from_registry_repo(
             &registry_repo,
             template,
@@ -4,7 +4,7 @@
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Changes to apply to the attributes of resource attributes, span attributes,
 /// event attributes, log attributes, and metric attributes.
@@ -28,5 +28,5 @@ pub struct AllChange {
 #[serde(deny_unknown_fields)]
 pub struct RenameAttributes {
     /// A collection of rename operations to apply to the resource attributes.
-    pub attribute_map: HashMap<String, String>,
+    pub attribute_map: BTreeMap<String, String>,
 }
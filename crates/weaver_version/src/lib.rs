@@ -25,6 +25,7 @@ pub mod metrics_changes;
 pub mod resource_changes;
 pub mod schema_changes;
 pub mod spans_changes;
+pub mod telemetry_schema_changes;
 pub mod v2;
 
 /// An error that can occur while loading or resolving version changes.
@@ -71,14 +72,19 @@ pub struct Versions {
 pub struct VersionSpec {
     /// The changes to apply to the following telemetry data: resource attributes,
     /// span attributes, span event attributes, log attributes, metric attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<AllChanges>,
     /// The changes to apply to the metrics specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<MetricsChanges>,
     /// The changes to apply to the logs specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub logs: Option<LogsChanges>,
     /// The changes to apply to the spans specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub spans: Option<SpansChanges>,
     /// The changes to apply to the resource specification for a specific version.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<ResourceChanges>,
 }
 
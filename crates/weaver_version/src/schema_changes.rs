@@ -49,7 +49,7 @@ pub struct RegistryManifest {
 /// Represents the different types of changes that can occur between
 /// two versions of a schema. This covers changes such as adding, removing,
 /// renaming, and deprecating telemetry objects (attributes, metrics, etc.).
-#[derive(Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
 pub enum SchemaItemChange {
@@ -68,8 +68,12 @@ pub enum SchemaItemChange {
         note: String,
     },
     /// One or more fields in a top-level telemetry object have been updated in the head registry.
-    /// Note: This is a placeholder for future use.
-    Updated {},
+    Updated {
+        /// The name of the updated telemetry object.
+        name: String,
+        /// The fields that have been updated.
+        fields: Vec<FieldChange>,
+    },
     /// A top-level telemetry object that is now discontinued without a valid replacement in the
     /// head registry.
     Obsoleted {
@@ -94,6 +98,19 @@ pub enum SchemaItemChange {
     },
 }
 
+/// A field of a top-level telemetry object whose value differs between the
+/// baseline and the head registries.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FieldChange {
+    /// The name of the field (e.g. `type`, `requirement_level`, `stability`).
+    pub field: String,
+    /// The value of the field in the baseline registry, `None` if not set.
+    pub baseline: Option<String>,
+    /// The value of the field in the head registry, `None` if not set.
+    pub head: Option<String>,
+}
+
 impl SchemaChanges {
     /// Create a new instance of `SchemaChanges`.
     #[must_use]
@@ -196,6 +213,19 @@ impl SchemaChanges {
             .unwrap_or(0)
     }
 
+    /// Counts the number of updated registry attributes in the schema.
+    #[must_use]
+    pub fn count_updated_registry_attributes(&self) -> usize {
+        self.changes
+            .get(&SchemaItemType::RegistryAttributes)
+            .map(|v| {
+                v.iter()
+                    .filter(|c| matches!(c, SchemaItemChange::Updated { .. }))
+                    .count()
+            })
+            .unwrap_or(0)
+    }
+
     /// Counts the number of removed registry attributes in the schema.
     #[must_use]
     pub fn count_removed_registry_attributes(&self) -> usize {
@@ -4,7 +4,7 @@
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 /// Changes to apply to the spans specification for a specific version.
 #[derive(Serialize, Deserialize, Debug, Default, Clone, JsonSchema)]
@@ -27,5 +27,5 @@ pub struct SpansChange {
 #[serde(deny_unknown_fields)]
 pub struct RenameAttributes {
     /// A collection of rename operations to apply to the span attributes.
-    pub attribute_map: HashMap<String, String>,
+    pub attribute_map: BTreeMap<String, String>,
 }
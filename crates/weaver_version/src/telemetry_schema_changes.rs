@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversion of the changes between two versions of a registry into the
+//! `versions` section of an OpenTelemetry telemetry schema.
+
+use crate::all_changes::{AllChange, AllChanges};
+use crate::metrics_changes::{MetricsChange, MetricsChanges};
+use crate::schema_changes::{SchemaItemChange, SchemaItemType};
+use crate::{VersionSpec, Versions};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// The changes between two versions of a registry expressed in the
+/// OpenTelemetry telemetry schema format.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SchemaChanges {
+    /// The `versions` section containing the changes of the new version.
+    pub versions: Versions,
+    /// The changes that cannot be expressed in the telemetry schema format,
+    /// e.g. attributes removed without a rename target.
+    #[serde(skip)]
+    pub non_representable: Vec<NonRepresentableChange>,
+}
+
+/// A change between two versions of a registry that cannot be expressed in
+/// the telemetry schema format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonRepresentableChange {
+    /// The type of the changed schema item.
+    pub item_type: SchemaItemType,
+    /// The change.
+    pub change: SchemaItemChange,
+}
+
+/// The version of the OpenTelemetry telemetry schema file format.
+pub const SCHEMA_FILE_FORMAT: &str = "1.1.0";
+
+/// An OpenTelemetry telemetry schema file, i.e. the document published at a
+/// schema URL.
+#[derive(Serialize, Debug, Clone)]
+pub struct SchemaFile {
+    /// The version of the schema file format.
+    pub file_format: String,
+    /// The schema URL of the most recent version.
+    pub schema_url: String,
+    /// The changes of all the versions of the schema.
+    pub versions: Versions,
+}
+
+/// The order in which the non-representable changes are reported.
+const ITEM_TYPES: [SchemaItemType; 5] = [
+    SchemaItemType::RegistryAttributes,
+    SchemaItemType::Metrics,
+    SchemaItemType::Events,
+    SchemaItemType::Spans,
+    SchemaItemType::Entities,
+];
+
+impl SchemaChanges {
+    /// Converts the changes of a registry diff into the telemetry schema
+    /// changes of the given version.
+    ///
+    /// Attribute renames become `rename_attributes` entries of the `all`
+    /// section and metric renames become `rename_metrics` entries of the
+    /// `metrics` section. Removals, deprecations without a rename target and
+    /// renames of other signals cannot be expressed and are reported in
+    /// `non_representable`. Additions don't need any transformation.
+    #[must_use]
+    pub fn from_registry_changes(
+        changes: &crate::schema_changes::SchemaChanges,
+        version: semver::Version,
+    ) -> Self {
+        let mut attribute_map = BTreeMap::new();
+        let mut rename_metrics = BTreeMap::new();
+        let mut non_representable = Vec::new();
+
+        for item_type in ITEM_TYPES {
+            for change in changes.changes_by_type(item_type).into_iter().flatten() {
+                match (item_type, change) {
+                    (_, SchemaItemChange::Added { .. } | SchemaItemChange::Updated { .. }) => {}
+                    (
+                        SchemaItemType::RegistryAttributes,
+                        SchemaItemChange::Renamed {
+                            old_name, new_name, ..
+                        },
+                    ) => {
+                        _ = attribute_map.insert(old_name.clone(), new_name.clone());
+                    }
+                    (
+                        SchemaItemType::Metrics,
+                        SchemaItemChange::Renamed {
+                            old_name, new_name, ..
+                        },
+                    ) => {
+                        _ = rename_metrics.insert(old_name.clone(), new_name.clone());
+                    }
+                    _ => non_representable.push(NonRepresentableChange {
+                        item_type,
+                        change: change.clone(),
+                    }),
+                }
+            }
+        }
+
+        let version_spec = VersionSpec {
+            all: (!attribute_map.is_empty()).then(|| AllChanges {
+                changes: vec![AllChange {
+                    rename_attributes: crate::all_changes::RenameAttributes { attribute_map },
+                }],
+            }),
+            metrics: (!rename_metrics.is_empty()).then(|| MetricsChanges {
+                changes: vec![MetricsChange {
+                    rename_attributes: Default::default(),
+                    rename_metrics,
+                }],
+            }),
+            logs: None,
+            spans: None,
+            resources: None,
+        };
+
+        SchemaChanges {
+            versions: Versions {
+                versions: BTreeMap::from([(version, version_spec)]),
+            },
+            non_representable,
+        }
+    }
+
+    /// Builds the schema file published at the given schema URL. The versions
+    /// of the previous schema file, if any, are kept so the schema file
+    /// contains the whole history of the schema.
+    #[must_use]
+    pub fn into_schema_file(
+        self,
+        schema_url: String,
+        previous_versions: Option<Versions>,
+    ) -> SchemaFile {
+        let mut versions = self.versions;
+        if let Some(previous_versions) = previous_versions {
+            versions.extend(previous_versions);
+        }
+        SchemaFile {
+            file_format: SCHEMA_FILE_FORMAT.to_owned(),
+            schema_url,
+            versions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_registry_changes() {
+        let mut changes = crate::schema_changes::SchemaChanges::new();
+        changes.add_change(
+            SchemaItemType::RegistryAttributes,
+            SchemaItemChange::Renamed {
+                old_name: "http.method".to_owned(),
+                new_name: "http.request.method".to_owned(),
+                note: "".to_owned(),
+            },
+        );
+        changes.add_change(
+            SchemaItemType::RegistryAttributes,
+            SchemaItemChange::Removed {
+                name: "http.flavor".to_owned(),
+            },
+        );
+        changes.add_change(
+            SchemaItemType::RegistryAttributes,
+            SchemaItemChange::Added {
+                name: "http.request.method".to_owned(),
+            },
+        );
+
+        let schema_changes =
+            SchemaChanges::from_registry_changes(&changes, semver::Version::new(1, 2, 0));
+
+        assert_eq!(
+            serde_yaml::to_string(&schema_changes).unwrap(),
+            r#"versions:
+  1.2.0:
+    all:
+      changes:
+      - rename_attributes:
+          attribute_map:
+            http.method: http.request.method
+"#
+        );
+        assert_eq!(
+            schema_changes.non_representable,
+            vec![NonRepresentableChange {
+                item_type: SchemaItemType::RegistryAttributes,
+                change: SchemaItemChange::Removed {
+                    name: "http.flavor".to_owned(),
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn test_into_schema_file() {
+        let mut changes = crate::schema_changes::SchemaChanges::new();
+        changes.add_change(
+            SchemaItemType::Metrics,
+            SchemaItemChange::Renamed {
+                old_name: "cpu.time".to_owned(),
+                new_name: "system.cpu.time".to_owned(),
+                note: "".to_owned(),
+            },
+        );
+        let previous_versions: Versions = serde_yaml::from_str(
+            r#"
+1.0.0:
+1.1.0:
+  all:
+    changes:
+      - rename_attributes:
+          attribute_map:
+            http.method: http.request.method
+"#,
+        )
+        .unwrap();
+
+        let schema_file =
+            SchemaChanges::from_registry_changes(&changes, semver::Version::new(1, 2, 0))
+                .into_schema_file(
+                    "https://example.com/schemas/1.2.0".to_owned(),
+                    Some(previous_versions),
+                );
+
+        assert_eq!(
+            serde_yaml::to_string(&schema_file).unwrap(),
+            r#"file_format: 1.1.0
+schema_url: https://example.com/schemas/1.2.0
+versions:
+  1.0.0: {}
+  1.1.0:
+    all:
+      changes:
+      - rename_attributes:
+          attribute_map:
+            http.method: http.request.method
+  1.2.0:
+    metrics:
+      changes:
+      - rename_metrics:
+          cpu.time: system.cpu.time
+"#
+        );
+    }
+}
@@ -80,6 +80,7 @@ pub fn run(start_version: Option<String>) -> anyhow::Result<()> {
     println!("Checking versions: {versions:?}");
     let mut failed = false;
     for version in versions {
+        #[allow(deprecated)]
         let mut cmd =
             Command::cargo_bin("weaver").context("Failed to create the cargo command.")?;
         let output = cmd
@@ -0,0 +1,27 @@
+/*
+ * Copyright The OpenTelemetry Authors
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! DO NOT EDIT, THIS FILE HAS BEEN GENERATED BY WEAVER
+
+/// Server domain name if available without reverse DNS lookup; otherwise, IP address or Unix domain socket name.
+///
+/// When observed from the client side, and when communicating through an intermediary, `server.address` SHOULD represent the server address behind any intermediaries, for example proxies, if it's available
+///
+/// ## Examples:
+/// - example.com
+/// - 10.1.2.80
+/// - /tmp/my.sock
+pub const SERVER_ADDRESS: crate::attributes::AttributeKey<opentelemetry::StringValue> = crate::attributes::AttributeKey::new("server.address");
+
+/// Server port number.
+///
+/// When observed from the client side, and when communicating through an intermediary, `server.port` SHOULD represent the server port behind any intermediaries, for example proxies, if it's available
+///
+/// ## Examples:
+/// - 80
+/// - 8080
+/// - 443
+pub const SERVER_PORT: crate::attributes::AttributeKey<i64> = crate::attributes::AttributeKey::new("server.port");
+
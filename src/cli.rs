@@ -3,6 +3,8 @@
 //! Manage command line arguments
 
 use crate::diagnostic::DiagnosticCommand;
+use crate::project::{GenerateCommand, UpdateCommand};
+use crate::registry::live_check::RegistryLiveCheckArgs;
 use crate::registry::RegistryCommand;
 use crate::serve::ServeCommand;
 use clap::{Args, Parser, Subcommand};
@@ -43,6 +45,12 @@ pub struct Cli {
 pub enum Commands {
     /// Manage Semantic Convention Registry
     Registry(RegistryCommand),
+    /// Generate the template sets declared in the project manifest (`weaver.toml`)
+    Generate(GenerateCommand),
+    /// Fetch again the remote sources of the project manifest and refresh its lockfile (`weaver.lock`)
+    Update(UpdateCommand),
+    /// Check the telemetry of an instrumented application, received over OTLP/gRPC or OTLP/HTTP, against a semantic convention registry (alias of `registry live-check`)
+    LiveCheck(RegistryLiveCheckArgs),
     /// Manage Diagnostic Messages
     Diagnostic(DiagnosticCommand),
     /// Generate shell completions
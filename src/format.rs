@@ -12,6 +12,9 @@ pub(crate) enum Format {
     Yaml,
     /// JSON format
     Json,
+    /// Protobuf format (`weaver.schema.v1.ResolvedTelemetrySchema` message of
+    /// the resolved telemetry schema, binary)
+    Protobuf,
 }
 
 pub(crate) fn apply_format<T: Serialize>(format: &Format, object: &T) -> Result<String, String> {
@@ -20,5 +23,8 @@ pub(crate) fn apply_format<T: Serialize>(format: &Format, object: &T) -> Result<
             .map_err(|e| format!("Failed to serialize in Yaml the resolved registry: {e:?}")),
         Format::Json => serde_json::to_string_pretty(object)
             .map_err(|e| format!("Failed to serialize in Json the resolved registry: {e:?}")),
+        Format::Protobuf => Err(
+            "The protobuf format is only supported for the resolved telemetry schema".to_owned(),
+        ),
     }
 }
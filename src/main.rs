@@ -11,7 +11,7 @@ use log::info;
 use std::io;
 use std::io::Write;
 
-use registry::semconv_registry;
+use registry::{live_check, semconv_registry};
 use weaver_common::diagnostic::{enable_future_mode, DiagnosticMessages};
 use weaver_common::log_error;
 use weaver_forge::config::{Params, WeaverConfig};
@@ -24,6 +24,7 @@ use crate::diagnostic::DEFAULT_DIAGNOSTIC_TEMPLATES;
 mod cli;
 mod diagnostic;
 mod format;
+mod project;
 mod registry;
 mod serve;
 mod weaver;
@@ -134,6 +135,11 @@ fn run_command(cli: &Cli) -> ExitDirectives {
     }
     let cmd_result = match &cli.command {
         Some(Commands::Registry(params)) => semconv_registry(params),
+        Some(Commands::Generate(params)) => project::command(params),
+        Some(Commands::Update(params)) => project::update(params),
+        Some(Commands::LiveCheck(params)) => {
+            CmdResult::new(live_check::command(params), Some(params.diagnostic.clone()))
+        }
         Some(Commands::Diagnostic(params)) => diagnostic::diagnostic(params),
         Some(Commands::Serve(params)) => serve::command(params),
         Some(Commands::Completion(completions)) => {
@@ -160,7 +166,7 @@ fn run_command(cli: &Cli) -> ExitDirectives {
     process_diagnostics(cmd_result)
 }
 
-fn print_diagnostics(
+pub(crate) fn print_diagnostics(
     diagnostic_args: &DiagnosticArgs,
     diagnostic_messages: &DiagnosticMessages,
 ) -> Result<(), weaver_forge::error::Error> {
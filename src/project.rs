@@ -0,0 +1,784 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! The project manifest (`weaver.toml`) declaring the registry, the overlay
+//! registries, the policies and the template sets of a project, so that
+//! `weaver generate` can run without flags.
+//!
+//! The commits of the Git sources and the digests of the remote archives of
+//! the manifest are recorded in a lockfile (`weaver.lock`) written next to the
+//! manifest. `weaver generate` fails if the fetched sources don't match the
+//! lockfile, and `weaver update` fetches the sources again to refresh it.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use log::info;
+use miette::Diagnostic;
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
+use weaver_common::log_success;
+use weaver_common::vdir::{LoadOptions, VirtualDirectory, VirtualDirectoryPath};
+
+use crate::registry::generate::RegistryGenerateArgs;
+use crate::registry::{generate, PolicyArgs, RegistryArgs};
+use crate::{CmdResult, DiagnosticArgs, ExitDirectives};
+
+/// The file name of the lockfile, written next to the project manifest.
+const LOCKFILE_NAME: &str = "weaver.lock";
+
+/// The version of the format of the lockfile.
+const LOCKFILE_VERSION: u32 = 1;
+
+/// Parameters for the `weaver generate` command.
+#[derive(Debug, Args)]
+pub struct GenerateCommand {
+    /// Path to the project manifest declaring the registry, the overlay
+    /// registries, the policies and the template sets to generate.
+    #[arg(long, default_value = "weaver.toml")]
+    pub manifest: PathBuf,
+
+    /// Parameters to specify the diagnostic format.
+    #[command(flatten)]
+    pub diagnostic: DiagnosticArgs,
+}
+
+/// Parameters for the `weaver update` command.
+#[derive(Debug, Args)]
+pub struct UpdateCommand {
+    /// Path to the project manifest whose lockfile is refreshed.
+    #[arg(long, default_value = "weaver.toml")]
+    pub manifest: PathBuf,
+
+    /// Parameters to specify the diagnostic format.
+    #[command(flatten)]
+    pub diagnostic: DiagnosticArgs,
+}
+
+/// An error that can occur while loading a project manifest or writing its
+/// lockfile.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Serialize, Diagnostic)]
+#[non_exhaustive]
+pub enum Error {
+    /// The project manifest could not be read or parsed.
+    #[error("The project manifest `{path}` is invalid: {error}")]
+    InvalidManifest {
+        /// The path to the manifest.
+        path: PathBuf,
+        /// The error that occurred.
+        error: String,
+    },
+
+    /// The project manifest doesn't declare any template set.
+    #[error("The project manifest `{path}` doesn't declare any `[[templates]]` set.")]
+    NoTemplateSet {
+        /// The path to the manifest.
+        path: PathBuf,
+    },
+
+    /// A template set of the project manifest doesn't have a target.
+    #[error("The template set #{index} of the project manifest `{path}` has an empty `target`.")]
+    EmptyTarget {
+        /// The path to the manifest.
+        path: PathBuf,
+        /// The index of the template set, starting at 1.
+        index: usize,
+    },
+
+    /// Several template sets of the project manifest write to the same output.
+    #[error("Several template sets of the project manifest `{path}` write to `{output}`.")]
+    DuplicateOutput {
+        /// The path to the manifest.
+        path: PathBuf,
+        /// The output shared by the template sets.
+        output: PathBuf,
+    },
+
+    /// A remote source of the project manifest could not be fetched to lock it.
+    #[error("The source `{source_path}` could not be locked: {error}")]
+    LockFailed {
+        /// The path of the source.
+        source_path: String,
+        /// The error that occurred.
+        error: String,
+    },
+
+    /// The lockfile could not be read or parsed.
+    #[error("The lockfile `{path}` is invalid: {error}")]
+    InvalidLockfile {
+        /// The path to the lockfile.
+        path: PathBuf,
+        /// The error that occurred.
+        error: String,
+    },
+
+    /// A remote source of the project manifest doesn't match the lockfile.
+    #[error("The source `{source_path}` doesn't match the lockfile `{path}`, run `weaver update` to refresh it.")]
+    OutdatedLockfile {
+        /// The path to the lockfile.
+        path: PathBuf,
+        /// The path of the source.
+        source_path: String,
+    },
+
+    /// Writing the lockfile failed.
+    #[error("Writing the lockfile `{path}` failed: {error}")]
+    LockfileWriteFailed {
+        /// The path to the lockfile.
+        path: PathBuf,
+        /// The error that occurred.
+        error: String,
+    },
+}
+
+impl From<Error> for DiagnosticMessages {
+    fn from(error: Error) -> Self {
+        DiagnosticMessages::new(vec![DiagnosticMessage::new(error)])
+    }
+}
+
+/// The project manifest, usually named `weaver.toml`.
+///
+/// ```toml
+/// policies = ["policies"]
+///
+/// [registry]
+/// path = "https://github.com/open-telemetry/semantic-conventions.git[model]@v1.26.0"
+/// overlays = ["model"]
+///
+/// [[templates]]
+/// path = "templates"
+/// target = "markdown"
+/// output = "docs"
+/// ```
+///
+/// The local paths are relative to the directory of the manifest.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ProjectManifest {
+    /// The registry of the project.
+    pub(crate) registry: RegistrySpec,
+    /// The policy files or directories checked against the registry.
+    #[serde(default)]
+    pub(crate) policies: Vec<VirtualDirectoryPath>,
+    /// The template sets to generate, in order.
+    #[serde(default)]
+    pub(crate) templates: Vec<TemplateSet>,
+}
+
+/// The registry declared in a project manifest.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RegistrySpec {
+    /// The base registry.
+    pub(crate) path: VirtualDirectoryPath,
+    /// The overlay registries applied on top of the base registry, the last
+    /// one having the highest precedence.
+    #[serde(default)]
+    pub(crate) overlays: Vec<VirtualDirectoryPath>,
+    /// Whether to follow symlinks when loading the registry.
+    #[serde(default)]
+    pub(crate) follow_symlinks: bool,
+    /// Whether to include the signals and attributes of the dependency
+    /// registries that are not referenced by the registry.
+    #[serde(default)]
+    pub(crate) include_unreferenced: bool,
+}
+
+/// A template set declared in a project manifest.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct TemplateSet {
+    /// The directory where the templates are located.
+    #[serde(default = "default_templates_path")]
+    pub(crate) path: VirtualDirectoryPath,
+    /// The target to generate.
+    pub(crate) target: String,
+    /// The directory where the generated artifacts are saved.
+    #[serde(default = "default_output")]
+    pub(crate) output: PathBuf,
+    /// The parameters passed to the templates.
+    #[serde(default)]
+    pub(crate) params: BTreeMap<String, Value>,
+}
+
+fn default_templates_path() -> VirtualDirectoryPath {
+    VirtualDirectoryPath::LocalFolder {
+        path: "templates".to_owned(),
+    }
+}
+
+fn default_output() -> PathBuf {
+    PathBuf::from("output")
+}
+
+/// The lockfile of a project manifest, recording the commits of its Git
+/// sources and the digests of its remote archives.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Lockfile {
+    /// The version of the format of the lockfile.
+    pub(crate) version: u32,
+    /// The remote sources of the manifest.
+    #[serde(default, rename = "source")]
+    pub(crate) sources: Vec<LockedSource>,
+}
+
+/// A remote source of a project manifest, locked to a commit or a digest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct LockedSource {
+    /// What the source is used for.
+    pub(crate) kind: SourceKind,
+    /// The path of the source, as declared in the manifest.
+    pub(crate) path: String,
+    /// The commit checked out, unknown for a cached clone that is not a Git
+    /// repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) commit: Option<String>,
+    /// The SHA-256 digest of the archive, for a remote archive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) digest: Option<String>,
+}
+
+/// What a source of a project manifest is used for.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum SourceKind {
+    /// The base registry.
+    Registry,
+    /// An overlay registry.
+    Overlay,
+    /// Policies.
+    Policies,
+    /// A template set.
+    Templates,
+}
+
+impl ProjectManifest {
+    /// Loads and validates a project manifest. The local paths of the manifest
+    /// are made relative to the current directory.
+    pub(crate) fn try_from_file(path: &Path) -> Result<Self, DiagnosticMessages> {
+        let content = std::fs::read_to_string(path).map_err(|e| Error::InvalidManifest {
+            path: path.to_path_buf(),
+            error: e.to_string(),
+        })?;
+        let mut manifest: Self = toml::from_str(&content).map_err(|e| Error::InvalidManifest {
+            path: path.to_path_buf(),
+            error: e.to_string(),
+        })?;
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+        manifest.rebase(base_dir);
+
+        let errors = manifest.validate(path);
+        if !errors.is_empty() {
+            return Err(DiagnosticMessages::new(
+                errors.into_iter().map(DiagnosticMessage::new).collect(),
+            ));
+        }
+        Ok(manifest)
+    }
+
+    /// Makes the local paths of the manifest relative to `base_dir`.
+    fn rebase(&mut self, base_dir: &Path) {
+        let rebase_vdir = |vdir_path: &mut VirtualDirectoryPath| match vdir_path {
+            VirtualDirectoryPath::LocalFolder { path }
+            | VirtualDirectoryPath::LocalArchive { path, .. } => {
+                *path = base_dir.join(&*path).to_string_lossy().into_owned();
+            }
+            VirtualDirectoryPath::RemoteArchive { .. } | VirtualDirectoryPath::GitRepo { .. } => {}
+        };
+        rebase_vdir(&mut self.registry.path);
+        self.registry.overlays.iter_mut().for_each(rebase_vdir);
+        self.policies.iter_mut().for_each(rebase_vdir);
+        for template_set in &mut self.templates {
+            rebase_vdir(&mut template_set.path);
+            template_set.output = base_dir.join(&template_set.output);
+        }
+    }
+
+    /// Returns the validation errors of the manifest.
+    fn validate(&self, path: &Path) -> Vec<Error> {
+        let mut errors = vec![];
+        if self.templates.is_empty() {
+            errors.push(Error::NoTemplateSet {
+                path: path.to_path_buf(),
+            });
+        }
+        let mut outputs = HashSet::new();
+        for (index, template_set) in self.templates.iter().enumerate() {
+            if template_set.target.trim().is_empty() {
+                errors.push(Error::EmptyTarget {
+                    path: path.to_path_buf(),
+                    index: index + 1,
+                });
+            }
+            if !outputs.insert(&template_set.output) {
+                errors.push(Error::DuplicateOutput {
+                    path: path.to_path_buf(),
+                    output: template_set.output.clone(),
+                });
+            }
+        }
+        errors
+    }
+
+    /// Returns the `registry generate` parameters of each template set.
+    fn generate_args(&self, diagnostic: &DiagnosticArgs) -> Vec<RegistryGenerateArgs> {
+        self.templates
+            .iter()
+            .map(|template_set| RegistryGenerateArgs {
+                target: template_set.target.clone(),
+                output: template_set.output.clone(),
+                templates: template_set.path.clone(),
+                config: None,
+                param: Some(
+                    template_set
+                        .params
+                        .iter()
+                        .map(|(name, value)| (name.clone(), value.clone()))
+                        .collect(),
+                ),
+                params: None,
+                registry: RegistryArgs {
+                    registry: std::iter::once(self.registry.path.clone())
+                        .chain(self.registry.overlays.iter().cloned())
+                        .collect(),
+                    follow_symlinks: self.registry.follow_symlinks,
+                    include_unreferenced: self.registry.include_unreferenced,
+                    incremental: false,
+                    refresh: false,
+                    v2: false,
+                },
+                policy: PolicyArgs {
+                    policies: self.policies.clone(),
+                    skip_policies: false,
+                    display_policy_coverage: false,
+                },
+                future: false,
+                watch: false,
+                diagnostic: diagnostic.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the remote sources of the manifest, i.e. the Git repos and the
+    /// remote archives, in order.
+    fn remote_sources(&self) -> Vec<(SourceKind, &VirtualDirectoryPath)> {
+        std::iter::once((SourceKind::Registry, &self.registry.path))
+            .chain(
+                self.registry
+                    .overlays
+                    .iter()
+                    .map(|path| (SourceKind::Overlay, path)),
+            )
+            .chain(
+                self.policies
+                    .iter()
+                    .map(|path| (SourceKind::Policies, path)),
+            )
+            .chain(
+                self.templates
+                    .iter()
+                    .map(|template_set| (SourceKind::Templates, &template_set.path)),
+            )
+            .filter(|(_, path)| {
+                matches!(
+                    path,
+                    VirtualDirectoryPath::GitRepo { .. }
+                        | VirtualDirectoryPath::RemoteArchive { .. }
+                )
+            })
+            .collect()
+    }
+
+    /// Fetches the remote sources of the manifest and returns the lockfile
+    /// recording their commits and digests.
+    fn lock(&self, options: &LoadOptions) -> Result<Lockfile, Error> {
+        let sources = self
+            .remote_sources()
+            .into_iter()
+            .map(|(kind, path)| {
+                let vdir = VirtualDirectory::try_new_with_options(path, options).map_err(|e| {
+                    Error::LockFailed {
+                        source_path: path.to_string(),
+                        error: e.to_string(),
+                    }
+                })?;
+                Ok(LockedSource {
+                    kind,
+                    path: path.to_string(),
+                    commit: vdir.git_commit().map(ToOwned::to_owned),
+                    digest: vdir.archive_digest().map(ToOwned::to_owned),
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok(Lockfile {
+            version: LOCKFILE_VERSION,
+            sources,
+        })
+    }
+}
+
+impl Lockfile {
+    /// Reads a lockfile, returns `None` if it doesn't exist.
+    fn read(path: &Path) -> Result<Option<Self>, Error> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| Error::InvalidLockfile {
+            path: path.to_path_buf(),
+            error: e.to_string(),
+        })?;
+        toml::from_str(&content)
+            .map(Some)
+            .map_err(|e| Error::InvalidLockfile {
+                path: path.to_path_buf(),
+                error: e.to_string(),
+            })
+    }
+
+    /// Returns an error for each source of this lockfile that is missing or
+    /// different in the `locked` lockfile, and for each source of the `locked`
+    /// lockfile that is no longer in this lockfile.
+    fn check(&self, locked: &Lockfile, path: &Path) -> Vec<Error> {
+        let outdated = |source: &LockedSource| Error::OutdatedLockfile {
+            path: path.to_path_buf(),
+            source_path: source.path.clone(),
+        };
+        self.sources
+            .iter()
+            .filter(|source| !locked.sources.contains(source))
+            .chain(
+                locked
+                    .sources
+                    .iter()
+                    .filter(|source| !self.sources.iter().any(|s| s.path == source.path)),
+            )
+            .map(outdated)
+            .collect()
+    }
+
+    /// Writes the lockfile.
+    fn write(&self, path: &Path) -> Result<(), Error> {
+        let content = toml::to_string(self).map_err(|e| Error::LockfileWriteFailed {
+            path: path.to_path_buf(),
+            error: e.to_string(),
+        })?;
+        std::fs::write(
+            path,
+            format!("# Generated by weaver, run `weaver update` to refresh it.\n\n{content}"),
+        )
+        .map_err(|e| Error::LockfileWriteFailed {
+            path: path.to_path_buf(),
+            error: e.to_string(),
+        })
+    }
+}
+
+/// Execute the `weaver generate` command.
+pub(crate) fn command(args: &GenerateCommand) -> CmdResult {
+    CmdResult::new(generate_project(args), Some(args.diagnostic.clone()))
+}
+
+/// Execute the `weaver update` command.
+pub(crate) fn update(args: &UpdateCommand) -> CmdResult {
+    CmdResult::new(update_lockfile(args), Some(args.diagnostic.clone()))
+}
+
+/// Returns the path to the lockfile of a project manifest.
+fn lockfile_path(manifest_path: &Path) -> PathBuf {
+    manifest_path
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .join(LOCKFILE_NAME)
+}
+
+/// Check the remote sources of the project manifest against its lockfile, or
+/// write the lockfile if there is none, then generate the template sets.
+fn generate_project(args: &GenerateCommand) -> Result<ExitDirectives, DiagnosticMessages> {
+    info!("Generating the project `{}`", args.manifest.display());
+    let manifest = ProjectManifest::try_from_file(&args.manifest)?;
+
+    let lockfile_path = lockfile_path(&args.manifest);
+    let lockfile = manifest.lock(&LoadOptions::default())?;
+    if let Some(locked) = Lockfile::read(&lockfile_path)? {
+        let errors = lockfile.check(&locked, &lockfile_path);
+        if !errors.is_empty() {
+            return Err(DiagnosticMessages::new(
+                errors.into_iter().map(DiagnosticMessage::new).collect(),
+            ));
+        }
+    } else {
+        lockfile.write(&lockfile_path)?;
+        log_success(format!("Lockfile `{}` created", lockfile_path.display()));
+    }
+
+    for generate_args in manifest.generate_args(&args.diagnostic) {
+        info!(
+            "Generating the `{}` target into `{}`",
+            generate_args.target,
+            generate_args.output.display()
+        );
+        _ = generate::command(&generate_args)?;
+    }
+
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+/// Fetch again the remote sources of the project manifest and rewrite its
+/// lockfile.
+fn update_lockfile(args: &UpdateCommand) -> Result<ExitDirectives, DiagnosticMessages> {
+    info!("Updating the lockfile of `{}`", args.manifest.display());
+    let manifest = ProjectManifest::try_from_file(&args.manifest)?;
+
+    let options = LoadOptions {
+        refresh: true,
+        ..LoadOptions::default()
+    };
+    let lockfile_path = lockfile_path(&args.manifest);
+    manifest.lock(&options)?.write(&lockfile_path)?;
+    log_success(format!("Lockfile `{}` updated", lockfile_path.display()));
+
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use weaver_common::vdir::VirtualDirectoryPath;
+
+    use crate::cli::{Cli, Commands};
+    use crate::project::{
+        GenerateCommand, LockedSource, Lockfile, ProjectManifest, SourceKind, UpdateCommand,
+        LOCKFILE_NAME,
+    };
+    use crate::run_command;
+
+    #[test]
+    fn test_load_manifest() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let manifest_path = temp_dir.path().join("weaver.toml");
+        std::fs::write(
+            &manifest_path,
+            r#"
+policies = ["policies"]
+
+[registry]
+path = "https://github.com/open-telemetry/semantic-conventions.git[model]@v1.26.0"
+overlays = ["model", "https://example.com/overlay.zip"]
+
+[[templates]]
+target = "markdown"
+output = "docs"
+params = { registry_name = "Vendor" }
+"#,
+        )
+        .unwrap();
+
+        let manifest = ProjectManifest::try_from_file(&manifest_path).unwrap();
+        assert!(matches!(
+            manifest.registry.path,
+            VirtualDirectoryPath::GitRepo { ref refspec, .. } if refspec.as_deref() == Some("v1.26.0")
+        ));
+        // The local paths are relative to the directory of the manifest.
+        assert_eq!(
+            manifest.registry.overlays[0].to_string(),
+            temp_dir.path().join("model").to_string_lossy()
+        );
+        assert_eq!(
+            manifest.policies[0].to_string(),
+            temp_dir.path().join("policies").to_string_lossy()
+        );
+        let template_set = &manifest.templates[0];
+        assert_eq!(
+            template_set.path.to_string(),
+            temp_dir.path().join("templates").to_string_lossy()
+        );
+        assert_eq!(template_set.output, temp_dir.path().join("docs"));
+        assert_eq!(template_set.params["registry_name"], "Vendor");
+
+        let remote_sources = manifest.remote_sources();
+        assert_eq!(remote_sources.len(), 2);
+        assert_eq!(remote_sources[0].0, SourceKind::Registry);
+        assert_eq!(remote_sources[1].0, SourceKind::Overlay);
+    }
+
+    #[test]
+    fn test_invalid_manifest() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let manifest_path = temp_dir.path().join("weaver.toml");
+
+        std::fs::write(&manifest_path, "[registry]\npath = \"model\"\n").unwrap();
+        let diag_msgs = ProjectManifest::try_from_file(&manifest_path).unwrap_err();
+        assert_eq!(diag_msgs.len(), 1);
+
+        std::fs::write(
+            &manifest_path,
+            r#"
+[registry]
+path = "model"
+
+[[templates]]
+target = ""
+
+[[templates]]
+target = "markdown"
+"#,
+        )
+        .unwrap();
+        let diag_msgs = ProjectManifest::try_from_file(&manifest_path).unwrap_err();
+        // An empty target and two template sets writing to `output`.
+        assert_eq!(diag_msgs.len(), 2);
+
+        std::fs::write(&manifest_path, "[registry]\nlocation = \"model\"\n").unwrap();
+        assert!(ProjectManifest::try_from_file(&manifest_path).is_err());
+        assert!(ProjectManifest::try_from_file(Path::new("missing/weaver.toml")).is_err());
+    }
+
+    #[test]
+    fn test_lockfile_format() {
+        let lockfile = Lockfile {
+            version: 1,
+            sources: vec![
+                LockedSource {
+                    kind: SourceKind::Registry,
+                    path:
+                        "https://github.com/open-telemetry/semantic-conventions.git@v1.26.0[model]"
+                            .to_owned(),
+                    commit: Some("2f1b8f8a5e0b4d1f4c9c6b3c9d6a1e2b3c4d5e6f".to_owned()),
+                    digest: None,
+                },
+                LockedSource {
+                    kind: SourceKind::Templates,
+                    path: "https://example.com/templates.zip".to_owned(),
+                    commit: None,
+                    digest: Some(
+                        "sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+                            .to_owned(),
+                    ),
+                },
+            ],
+        };
+        let content = toml::to_string(&lockfile).unwrap();
+        assert_eq!(
+            content,
+            r#"version = 1
+
+[[source]]
+kind = "registry"
+path = "https://github.com/open-telemetry/semantic-conventions.git@v1.26.0[model]"
+commit = "2f1b8f8a5e0b4d1f4c9c6b3c9d6a1e2b3c4d5e6f"
+
+[[source]]
+kind = "templates"
+path = "https://example.com/templates.zip"
+digest = "sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+"#
+        );
+        assert_eq!(toml::from_str::<Lockfile>(&content).unwrap(), lockfile);
+    }
+
+    #[test]
+    fn test_check_lockfile() {
+        let source = |path: &str, digest: &str| LockedSource {
+            kind: SourceKind::Templates,
+            path: path.to_owned(),
+            commit: None,
+            digest: Some(digest.to_owned()),
+        };
+        let lockfile = Lockfile {
+            version: 1,
+            sources: vec![source("a.zip", "sha256:1"), source("b.zip", "sha256:2")],
+        };
+        let path = Path::new(LOCKFILE_NAME);
+        assert!(lockfile.check(&lockfile, path).is_empty());
+
+        // A new source, a changed digest, and a removed source.
+        let locked = Lockfile {
+            version: 1,
+            sources: vec![source("b.zip", "sha256:3"), source("c.zip", "sha256:4")],
+        };
+        assert_eq!(lockfile.check(&locked, path).len(), 3);
+    }
+
+    #[test]
+    fn test_generate_project() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let manifest_path = temp_dir.path().join("weaver.toml");
+        let registry_path = std::fs::canonicalize("tests/policy_levels/model").unwrap();
+        std::fs::write(
+            &manifest_path,
+            format!(
+                r#"
+[registry]
+path = "{}"
+
+[[templates]]
+target = "markdown"
+output = "docs"
+"#,
+                registry_path.display()
+            ),
+        )
+        .unwrap();
+
+        let cli = Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            command: Some(Commands::Generate(GenerateCommand {
+                manifest: manifest_path.clone(),
+                diagnostic: Default::default(),
+            })),
+        };
+
+        assert_eq!(run_command(&cli).exit_code, 0);
+        assert!(temp_dir.path().join("docs/README.md").exists());
+        // Without remote source, the lockfile is empty.
+        let lockfile_path = temp_dir.path().join(LOCKFILE_NAME);
+        let lockfile: Lockfile =
+            toml::from_str(&std::fs::read_to_string(&lockfile_path).unwrap()).unwrap();
+        assert_eq!(lockfile.sources, vec![]);
+
+        // A lockfile with a source no longer declared by the manifest is
+        // outdated, and `weaver update` refreshes it.
+        std::fs::write(
+            &lockfile_path,
+            "version = 1\n\n[[source]]\nkind = \"templates\"\npath = \"https://example.com/templates.zip\"\n",
+        )
+        .unwrap();
+        assert_eq!(run_command(&cli).exit_code, 1);
+        let update_cli = Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            command: Some(Commands::Update(UpdateCommand {
+                manifest: manifest_path,
+                diagnostic: Default::default(),
+            })),
+        };
+        assert_eq!(run_command(&update_cli).exit_code, 0);
+        assert_eq!(run_command(&cli).exit_code, 0);
+
+        // A missing manifest is an error.
+        let cli = Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            command: Some(Commands::Generate(GenerateCommand {
+                manifest: PathBuf::from("missing/weaver.toml"),
+                diagnostic: Default::default(),
+            })),
+        };
+        assert_eq!(run_command(&cli).exit_code, 1);
+    }
+}
@@ -35,7 +35,7 @@ pub struct RegistryCheckArgs {
 pub(crate) fn command(args: &RegistryCheckArgs) -> Result<ExitDirectives, DiagnosticMessages> {
     let mut diag_msgs = DiagnosticMessages::empty();
     info!("Weaver Registry Check");
-    info!("Checking registry `{}`", args.registry.registry);
+    info!("Checking registry `{}`", args.registry.base_registry());
     let weaver = WeaverEngine::new(&args.registry, &args.policy);
 
     // Initialize the main registry.
@@ -43,12 +43,15 @@ pub(crate) fn command(args: &RegistryCheckArgs) -> Result<ExitDirectives, Diagno
 
     // Initialize the baseline registry if provided.
     let baseline = if let Some(br) = args.baseline_registry.as_ref() {
-        let registry_repo = RegistryRepo::try_new("baseline", br)?;
+        let registry_repo =
+            RegistryRepo::try_new_with_options("baseline", br, &args.registry.load_options())?;
         // ignore warnings.
         let mut ignored = DiagnosticMessages::empty();
         let loaded = weaver.load_definitions(registry_repo, &mut ignored)?;
         // TODO - do we need to keep any loading diagnostic messages?
-        Some(weaver.resolve(loaded, &mut diag_msgs)?)
+        let baseline = weaver.resolve(loaded, &mut diag_msgs)?;
+        main_resolved.check_renamed_from(&baseline, &mut diag_msgs)?;
+        Some(baseline)
     } else {
         None
     };
@@ -99,11 +102,13 @@ mod tests {
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Check(RegistryCheckArgs {
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     baseline_registry: None,
@@ -129,11 +134,13 @@ mod tests {
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Check(RegistryCheckArgs {
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     baseline_registry: None,
@@ -157,11 +164,13 @@ mod tests {
         let registry_cmd = RegistryCommand {
             command: RegistrySubCommand::Check(RegistryCheckArgs {
                 registry: RegistryArgs {
-                    registry: VirtualDirectoryPath::LocalFolder {
+                    registry: vec![VirtualDirectoryPath::LocalFolder {
                         path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
-                    },
+                    }],
                     follow_symlinks: false,
                     include_unreferenced: false,
+                    incremental: false,
+                    refresh: false,
                     v2: false,
                 },
                 baseline_registry: None,
@@ -191,16 +200,115 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_group_policies() {
+        let registry_cmd = RegistryCommand {
+            command: RegistrySubCommand::Check(RegistryCheckArgs {
+                registry: RegistryArgs {
+                    registry: vec![VirtualDirectoryPath::LocalFolder {
+                        path: "tests/group_policies/".to_owned(),
+                    }],
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    incremental: false,
+                    refresh: false,
+                    v2: false,
+                },
+                baseline_registry: None,
+                policy: PolicyArgs {
+                    policies: vec![],
+                    skip_policies: false,
+                    display_policy_coverage: false,
+                },
+                diagnostic: Default::default(),
+            }),
+        };
+        let cmd_result = semconv_registry(&registry_cmd);
+        // Only the metric with an invalid name and without the (inherited)
+        // `http.request.method` attribute violates the metric policies.
+        let Err(diag_msgs) = cmd_result.command_result else {
+            panic!("Group policy violations should be observed");
+        };
+        let messages: Vec<_> = diag_msgs
+            .into_inner()
+            .iter()
+            .map(|msg| format!("{msg:?}"))
+            .collect();
+        assert_eq!(messages.len(), 2);
+        assert!(messages
+            .iter()
+            .all(|msg| msg.contains("metric.http.server.active_requests")));
+        assert!(messages
+            .iter()
+            .any(|msg| msg.contains("invalid_metric_name")));
+        assert!(messages
+            .iter()
+            .any(|msg| msg.contains("missing_http_request_method")));
+    }
+
+    #[test]
+    fn test_warn_and_info_policies() {
+        let registry_cmd = RegistryCommand {
+            command: RegistrySubCommand::Check(RegistryCheckArgs {
+                registry: RegistryArgs {
+                    registry: vec![VirtualDirectoryPath::LocalFolder {
+                        path: "tests/policy_levels/".to_owned(),
+                    }],
+                    follow_symlinks: false,
+                    include_unreferenced: false,
+                    incremental: false,
+                    refresh: false,
+                    v2: false,
+                },
+                baseline_registry: None,
+                policy: PolicyArgs {
+                    policies: vec![],
+                    skip_policies: false,
+                    display_policy_coverage: false,
+                },
+                diagnostic: Default::default(),
+            }),
+        };
+        let cmd_result = semconv_registry(&registry_cmd);
+        // The findings are reported but none of them is an error.
+        let Err(diag_msgs) = cmd_result.command_result else {
+            panic!("Policy warnings should be reported");
+        };
+        assert!(!diag_msgs.has_error());
+        let messages: Vec<_> = diag_msgs
+            .into_inner()
+            .iter()
+            .map(|msg| format!("{msg:?}"))
+            .collect();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            messages
+                .iter()
+                .filter(|msg| msg.contains("attr_not_stable"))
+                .count(),
+            1
+        );
+        assert_eq!(
+            messages
+                .iter()
+                .filter(|msg| msg.contains("attr_without_note"))
+                .count(),
+            2
+        );
+    }
+
     #[test]
     fn test_v2_policies() {
         let registry_cmd = RegistryCommand {
             command: RegistrySubCommand::Check(RegistryCheckArgs {
                 registry: RegistryArgs {
-                    registry: VirtualDirectoryPath::LocalFolder {
+                    registry: vec![VirtualDirectoryPath::LocalFolder {
                         path: "tests/v2_check/".to_owned(),
-                    },
+                    }],
                     follow_symlinks: false,
                     include_unreferenced: false,
+                    incremental: false,
+                    refresh: false,
                     v2: true,
                 },
                 baseline_registry: None,
@@ -235,11 +343,13 @@ mod tests {
         let registry_cmd = RegistryCommand {
             command: RegistrySubCommand::Check(RegistryCheckArgs {
                 registry: RegistryArgs {
-                    registry: VirtualDirectoryPath::LocalFolder {
+                    registry: vec![VirtualDirectoryPath::LocalFolder {
                         path: "tests/v2_check_baseline/next/".to_owned(),
-                    },
+                    }],
                     follow_symlinks: false,
                     include_unreferenced: false,
+                    incremental: false,
+                    refresh: false,
                     v2: true,
                 },
                 baseline_registry: Some(VirtualDirectoryPath::LocalFolder {
@@ -277,11 +387,13 @@ mod tests {
         let registry_cmd = RegistryCommand {
             command: RegistrySubCommand::Check(RegistryCheckArgs {
                 registry: RegistryArgs {
-                    registry: VirtualDirectoryPath::LocalFolder {
+                    registry: vec![VirtualDirectoryPath::LocalFolder {
                         path: "tests/v2_check_before_resolution/".to_owned(),
-                    },
+                    }],
                     follow_symlinks: false,
                     include_unreferenced: false,
+                    incremental: false,
+                    refresh: false,
                     v2: true,
                 },
                 baseline_registry: None,
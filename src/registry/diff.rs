@@ -98,16 +98,19 @@ pub(crate) fn command(args: &RegistryDiffArgs) -> Result<ExitDirectives, Diagnos
     let weaver = WeaverEngine::new(&args.registry, &policy_config);
 
     info!("Weaver Registry Diff");
-    info!("Checking registry `{}`", args.registry.registry);
+    info!("Checking registry `{}`", args.registry.base_registry());
 
-    let registry_path = args.registry.registry.clone();
-    let main_registry_repo = RegistryRepo::try_new("main", &registry_path)?;
-    let baseline_registry_repo = RegistryRepo::try_new("baseline", &args.baseline_registry)?;
+    let baseline_registry_repo = RegistryRepo::try_new_with_options(
+        "baseline",
+        &args.baseline_registry,
+        &args.registry.load_options(),
+    )?;
 
-    let main = weaver.load_definitions(main_registry_repo, &mut diag_msgs)?;
+    let main = weaver.load_main_definitions(&mut diag_msgs)?;
     let baseline = weaver.load_definitions(baseline_registry_repo, &mut diag_msgs)?;
     let main_resolved = weaver.resolve(main, &mut diag_msgs)?;
     let baseline_resolved = weaver.resolve(baseline, &mut diag_msgs)?;
+    main_resolved.check_renamed_from(&baseline_resolved, &mut diag_msgs)?;
     // Generate diff.
     let diff = if args.registry.v2 {
         VersionedDiff::V2(
@@ -176,11 +179,13 @@ mod tests {
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Diff(RegistryDiffArgs {
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "tests/diff/registry_head/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     baseline_registry: VirtualDirectoryPath::LocalFolder {
@@ -209,11 +214,13 @@ mod tests {
         let registry_cmd = RegistryCommand {
             command: RegistrySubCommand::Diff(RegistryDiffArgs {
                 registry: RegistryArgs {
-                    registry: VirtualDirectoryPath::LocalFolder {
+                    registry: vec![VirtualDirectoryPath::LocalFolder {
                         path: "tests/diff/registry_head/".to_owned(),
-                    },
+                    }],
                     follow_symlinks: false,
                     include_unreferenced: false,
+                    incremental: false,
+                    refresh: false,
                     v2: false,
                 },
                 baseline_registry: VirtualDirectoryPath::LocalFolder {
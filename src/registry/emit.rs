@@ -40,7 +40,7 @@ pub struct RegistryEmitArgs {
 /// Emit all spans in the resolved registry.
 pub(crate) fn command(args: &RegistryEmitArgs) -> Result<ExitDirectives, DiagnosticMessages> {
     info!("Weaver Registry Emit");
-    info!("Resolving registry `{}`", args.registry.registry);
+    info!("Resolving registry `{}`", args.registry.base_registry());
 
     let mut diag_msgs = DiagnosticMessages::empty();
 
@@ -55,23 +55,26 @@ pub(crate) fn command(args: &RegistryEmitArgs) -> Result<ExitDirectives, Diagnos
     let resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
     if args.registry.v2 {
         let resolved_v2: ResolvedV2 = resolved.try_into()?;
-        info!("Emitting v2 registry `{}`", args.registry.registry);
+        info!("Emitting v2 registry `{}`", args.registry.base_registry());
         emit(
             RegistryVersion::V2(resolved_v2.template_schema()),
-            &args.registry.registry.to_string(),
+            &args.registry.base_registry().to_string(),
             &exporter_config,
         )
         .combine_diag_msgs_with(&diag_msgs)?;
     } else {
-        info!("Emitting v1 registry `{}`", args.registry.registry);
+        info!("Emitting v1 registry `{}`", args.registry.base_registry());
         emit(
             RegistryVersion::V1(resolved.template_schema()),
-            &args.registry.registry.to_string(),
+            &args.registry.base_registry().to_string(),
             &exporter_config,
         )
         .combine_diag_msgs_with(&diag_msgs)?;
     }
-    log_success(format!("Emitted registry `{}`", args.registry.registry));
+    log_success(format!(
+        "Emitted registry `{}`",
+        args.registry.base_registry()
+    ));
 
     if diag_msgs.has_error() {
         return Err(diag_msgs);
@@ -100,11 +103,13 @@ mod tests {
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Emit(RegistryEmitArgs {
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_emit/data/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     policy: PolicyArgs {
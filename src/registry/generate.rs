@@ -3,20 +3,22 @@
 //! Generate artifacts for a semantic convention registry.
 
 use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::Args;
 use log::info;
 use serde_yaml::Value;
 
 use weaver_common::diagnostic::DiagnosticMessages;
-use weaver_common::log_success;
+use weaver_common::watch::FileWatcher;
+use weaver_common::{log_error, log_success};
 use weaver_forge::config::{Params, WeaverConfig};
-use weaver_forge::file_loader::{FileLoader, FileSystemFileLoader};
-use weaver_forge::{OutputDirective, TemplateEngine};
+use weaver_forge::file_loader::{EmbeddedFileLoader, FileLoader, FileSystemFileLoader};
+use weaver_forge::{OutputDirective, TemplateEngine, DOCS_TEMPLATES};
 
 use crate::registry::{Error, PolicyArgs, RegistryArgs};
 use crate::weaver::{ResolvedV2, WeaverEngine};
-use crate::{DiagnosticArgs, ExitDirectives};
+use crate::{print_diagnostics, DiagnosticArgs, ExitDirectives};
 use weaver_common::vdir::VirtualDirectory;
 use weaver_common::vdir::VirtualDirectoryPath;
 
@@ -24,6 +26,8 @@ use weaver_common::vdir::VirtualDirectoryPath;
 #[derive(Debug, Args)]
 pub struct RegistryGenerateArgs {
     /// Target to generate the artifacts for.
+    /// The official `markdown` docs target is embedded in Weaver and used
+    /// when the templates directory doesn't define it.
     #[arg(default_value = "")]
     pub target: String,
 
@@ -53,22 +57,31 @@ pub struct RegistryGenerateArgs {
 
     /// Parameters to specify the semantic convention registry
     #[command(flatten)]
-    registry: RegistryArgs,
+    pub(crate) registry: RegistryArgs,
 
     /// Policy parameters
     #[command(flatten)]
-    policy: PolicyArgs,
+    pub(crate) policy: PolicyArgs,
 
     /// Enable the most recent validation rules for the semconv registry. It is recommended
     /// to enable this flag when checking a new registry.
     #[arg(long, default_value = "false")]
     pub future: bool,
 
+    /// Watch the registry, the templates and the configuration and parameter files, and
+    /// generate the artifacts again whenever one of them changes. Only the local folders
+    /// and files are watched.
+    #[arg(long)]
+    pub watch: bool,
+
     /// Parameters to specify the diagnostic format.
     #[command(flatten)]
     pub diagnostic: DiagnosticArgs,
 }
 
+/// The polling interval of the watched files.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Utility function to parse key-value pairs from the command line.
 pub(crate) fn parse_key_val(s: &str) -> Result<(String, Value), Error> {
     let pos = s.find('=').ok_or_else(|| Error::InvalidParam {
@@ -86,26 +99,111 @@ pub(crate) fn parse_key_val(s: &str) -> Result<(String, Value), Error> {
 pub(crate) fn command(args: &RegistryGenerateArgs) -> Result<ExitDirectives, DiagnosticMessages> {
     info!(
         "Generating artifacts for the registry `{}`",
-        args.registry.registry
+        args.registry.base_registry()
     );
 
+    if args.watch {
+        return watch(args);
+    }
+    generate(args)?;
+    log_success("Artifacts generated successfully");
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+/// Generate the artifacts, then generate them again on every change of the inputs, until
+/// the process is interrupted. The diagnostics of every generation are printed as they come.
+fn watch(args: &RegistryGenerateArgs) -> Result<ExitDirectives, DiagnosticMessages> {
+    let watcher = FileWatcher::new(watched_paths(args));
+    if watcher.roots().is_empty() {
+        return Err(Error::NothingToWatch.into());
+    }
+    regenerate_on_changes(args, watcher)
+}
+
+/// Generate the artifacts and wait for a change of the watched inputs, forever.
+fn regenerate_on_changes(args: &RegistryGenerateArgs, mut watcher: FileWatcher) -> ! {
+    loop {
+        match generate(args) {
+            Ok(()) => log_success("Artifacts generated successfully"),
+            Err(diag_msgs) => {
+                if let Err(e) = print_diagnostics(&args.diagnostic, &diag_msgs) {
+                    log_error(format!(
+                        "Failed to render the diagnostic messages. Error: {e}"
+                    ));
+                }
+            }
+        }
+        info!(
+            "Watching {} for changes (press Ctrl+C to stop)",
+            watcher
+                .roots()
+                .iter()
+                .map(|path| format!("`{}`", path.display()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let changed = watcher.wait_for_changes(WATCH_INTERVAL);
+        for path in &changed {
+            info!("Changed: {}", path.display());
+        }
+    }
+}
+
+/// Returns the local inputs of the generation: the registry and templates folders, and
+/// the configuration and parameter files.
+fn watched_paths(args: &RegistryGenerateArgs) -> Vec<PathBuf> {
+    let local_folder = |vdir_path: &VirtualDirectoryPath| {
+        if let VirtualDirectoryPath::LocalFolder { path } = vdir_path {
+            Some(PathBuf::from(path))
+        } else {
+            log::warn!("`{vdir_path}` is not a local folder and won't be watched");
+            None
+        }
+    };
+    local_folder(args.registry.base_registry())
+        .into_iter()
+        .chain(local_folder(&args.templates))
+        .chain(args.config.iter().flatten().cloned())
+        .chain(args.params.clone())
+        .collect()
+}
+
+/// Generate the artifacts once.
+fn generate(args: &RegistryGenerateArgs) -> Result<(), DiagnosticMessages> {
     let mut diag_msgs = DiagnosticMessages::empty();
     let weaver = WeaverEngine::new(&args.registry, &args.policy);
     let resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
     let params = generate_params(args)?;
     let templates_dir =
-        VirtualDirectory::try_new(&args.templates).map_err(|e| Error::InvalidParams {
-            params_file: PathBuf::from(args.templates.to_string()),
-            error: e.to_string(),
-        })?;
-    let loader =
-        FileSystemFileLoader::try_new(resolve_templates_root(&templates_dir), &args.target)?;
-    let config = if let Some(paths) = &args.config {
-        WeaverConfig::try_from_config_files(paths)
+        VirtualDirectory::try_new_with_options(&args.templates, &args.registry.load_options())
+            .map_err(|e| Error::InvalidParams {
+                params_file: PathBuf::from(args.templates.to_string()),
+                error: e.to_string(),
+            })?;
+    let templates_root = resolve_templates_root(&templates_dir);
+    let engine = if !templates_root.join(&args.target).exists()
+        && DOCS_TEMPLATES.get_dir(&args.target).is_some()
+    {
+        // The target is one of the official docs targets embedded in Weaver.
+        let loader = EmbeddedFileLoader::try_new(&DOCS_TEMPLATES, templates_root, &args.target)?;
+        let config = if let Some(paths) = &args.config {
+            WeaverConfig::try_from_config_files(paths)
+        } else {
+            WeaverConfig::try_from_loader(&loader)
+        }?;
+        TemplateEngine::try_new(config, loader, params)?
     } else {
-        WeaverConfig::try_from_path(loader.root())
-    }?;
-    let engine = TemplateEngine::try_new(config, loader, params)?;
+        let loader = FileSystemFileLoader::try_new(templates_root, &args.target)?;
+        let config = if let Some(paths) = &args.config {
+            WeaverConfig::try_from_config_files(paths)
+        } else {
+            WeaverConfig::try_from_path(loader.root())
+        }?;
+        TemplateEngine::try_new(config, loader, params)?
+    };
     // Resolve v1 and v2 schema, based on user request.
     if args.registry.v2 {
         let resolved_v2: ResolvedV2 = resolved.try_into()?;
@@ -127,12 +225,7 @@ pub(crate) fn command(args: &RegistryGenerateArgs) -> Result<ExitDirectives, Dia
     if !diag_msgs.is_empty() {
         return Err(diag_msgs);
     }
-
-    log_success("Artifacts generated successfully");
-    Ok(ExitDirectives {
-        exit_code: 0,
-        warnings: None,
-    })
+    Ok(())
 }
 
 /// Resolve the effective templates root.
@@ -196,6 +289,75 @@ mod tests {
     use crate::run_command;
     use weaver_common::vdir::VirtualDirectoryPath;
 
+    #[test]
+    fn test_registry_generate_markdown_docs() {
+        let temp_output = tempfile::tempdir().expect("Failed to create temporary directory");
+        // The templates directory doesn't define the `markdown` target, the
+        // official docs target embedded in Weaver is used.
+        let temp_templates = tempfile::tempdir().expect("Failed to create temporary directory");
+        let cli = Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            command: Some(Commands::Registry(RegistryCommand {
+                command: RegistrySubCommand::Generate(RegistryGenerateArgs {
+                    target: "markdown".to_owned(),
+                    output: temp_output.path().to_path_buf(),
+                    templates: VirtualDirectoryPath::LocalFolder {
+                        path: temp_templates.path().to_string_lossy().to_string(),
+                    },
+                    config: None,
+                    param: None,
+                    params: None,
+                    registry: RegistryArgs {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
+                            path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
+                        }],
+                        follow_symlinks: false,
+                        include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
+                        v2: false,
+                    },
+                    policy: PolicyArgs {
+                        policies: vec![],
+                        skip_policies: true,
+                        display_policy_coverage: false,
+                    },
+                    future: false,
+                    watch: false,
+                    diagnostic: Default::default(),
+                }),
+            })),
+        };
+
+        let exit_directive = run_command(&cli);
+        assert_eq!(exit_directive.exit_code, 0);
+
+        let index = std::fs::read_to_string(temp_output.path().join("README.md")).unwrap();
+        assert!(index.contains("| [`registry.http`](registry.http.md) | `attribute_group` |"));
+
+        // The attributes defined by the group are anchored in its page.
+        let registry_http =
+            std::fs::read_to_string(temp_output.path().join("registry.http.md")).unwrap();
+        assert!(registry_http.contains("<a id=\"http-request-method\"></a>`http.request.method`"));
+
+        // The referenced attributes link to the page of the group defining them,
+        // and the attribute table is wrapped in the semconv snippet markers.
+        let metric = std::fs::read_to_string(
+            temp_output
+                .path()
+                .join("metric.http.client.request.duration.md"),
+        )
+        .unwrap();
+        assert!(metric.contains("| `http.client.request.duration` | histogram | `s` |"));
+        assert!(metric.contains(
+            "| [`http.request.method`](registry.http.md#http-request-method) | string | HTTP request method. | `\"GET\"`; `\"POST\"`; `\"HEAD\"` | Required | stable |"
+        ));
+        assert!(metric.contains("<!-- semconv metric.http.client.request.duration -->"));
+        assert!(metric.contains("<!-- endsemconv -->"));
+    }
+
     #[test]
     fn test_registry_generate() {
         let temp_output = tempfile::Builder::new()
@@ -218,11 +380,13 @@ mod tests {
                     param: None,
                     params: None,
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     policy: PolicyArgs {
@@ -231,6 +395,7 @@ mod tests {
                         display_policy_coverage: false,
                     },
                     future: false,
+                    watch: false,
                     diagnostic: Default::default(),
                 }),
             })),
@@ -297,11 +462,13 @@ mod tests {
                     param: None,
                     params: None,
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     policy: PolicyArgs {
@@ -310,6 +477,7 @@ mod tests {
                         display_policy_coverage: false,
                     },
                     future: false,
+                    watch: false,
                     diagnostic: Default::default(),
                 }),
             })),
@@ -347,11 +515,13 @@ mod tests {
                     param: None,
                     params: None,
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     policy: PolicyArgs {
@@ -360,6 +530,7 @@ mod tests {
                         display_policy_coverage: false,
                     },
                     future: false,
+                    watch: false,
                     diagnostic: Default::default(),
                 }),
             })),
@@ -456,11 +627,13 @@ mod tests {
                         param: None,
                         params: None,
                         registry: RegistryArgs {
-                            registry: VirtualDirectoryPath::LocalFolder {
+                            registry: vec![VirtualDirectoryPath::LocalFolder {
                                 path: "data/symbolic_test/".to_owned(),
-                            },
+                            }],
                             follow_symlinks,
                             include_unreferenced: false,
+                            incremental: false,
+                            refresh: false,
                             v2: false,
                         },
                         policy: PolicyArgs {
@@ -469,6 +642,7 @@ mod tests {
                             display_policy_coverage: false,
                         },
                         future: false,
+                        watch: false,
                         diagnostic: Default::default(),
                     }),
                 })),
@@ -533,11 +707,13 @@ mod tests {
                     param: None,
                     params: None,
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "tests/v2_forge/model/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: true,
                     },
                     policy: PolicyArgs {
@@ -546,6 +722,7 @@ mod tests {
                         display_policy_coverage: false,
                     },
                     future: false,
+                    watch: false,
                     diagnostic: Default::default(),
                 }),
             })),
@@ -58,7 +58,7 @@ pub enum Error {
 pub enum JsonSchemaType {
     /// The JSON schema of a resolved registry.
     ResolvedRegistry,
-    /// The JSON schema of a semantic convention group.
+    /// The JSON schema of a semantic convention file (e.g. for editor validation).
     SemconvGroup,
     /// The JSON schema of the V2 definition.
     SemconvDefinitionV2,
@@ -84,10 +84,10 @@ pub(crate) fn command(args: &RegistryJsonSchemaArgs) -> Result<ExitDirectives, D
     let json_schema = match args.json_schema {
         JsonSchemaType::ResolvedRegistry => schema_for!(ResolvedRegistry),
         JsonSchemaType::SemconvGroup => schema_for!(SemConvSpec),
-        JsonSchemaType::SemconvDefinitionV2 => {
+        JsonSchemaType::SemconvDefinitionV2 => schema_for!(weaver_semconv::v2::SemConvSpecV2),
+        JsonSchemaType::ResolvedRegistryV2 => {
             schema_for!(weaver_resolved_schema::v2::ResolvedTelemetrySchema)
         }
-        JsonSchemaType::ResolvedRegistryV2 => schema_for!(weaver_semconv::v2::SemConvSpecV2),
         JsonSchemaType::ForgeRegistryV2 => {
             schema_for!(weaver_forge::v2::registry::ForgeResolvedRegistry)
         }
@@ -173,6 +173,17 @@ mod tests {
                 definitions.is_some(),
                 "Expected a 'definitions' key in the JSON schema"
             );
+
+            // The schema describes the expected document.
+            let expected_title = match json_schema_type {
+                JsonSchemaType::ResolvedRegistry => "ResolvedRegistry",
+                JsonSchemaType::SemconvGroup => "SemConvSpec",
+                JsonSchemaType::SemconvDefinitionV2 => "SemConvSpecV2",
+                JsonSchemaType::ResolvedRegistryV2 => "ResolvedTelemetrySchema",
+                JsonSchemaType::ForgeRegistryV2 => "ForgeResolvedRegistry",
+                JsonSchemaType::Diff | JsonSchemaType::DiffV2 => "SchemaChanges",
+            };
+            assert_eq!(value["title"], expected_title, "{json_schema_type:?}");
         }
     }
 }
@@ -121,7 +121,7 @@ pub struct RegistryLiveCheckArgs {
     #[arg(short, long)]
     output: Option<PathBuf>,
 
-    /// Address used by the gRPC OTLP listener.
+    /// Address used by the gRPC and HTTP OTLP listeners.
     #[clap(long, default_value = "0.0.0.0")]
     otlp_grpc_address: String,
 
@@ -129,6 +129,10 @@ pub struct RegistryLiveCheckArgs {
     #[clap(long, default_value = "4317")]
     otlp_grpc_port: u16,
 
+    /// Port used by the HTTP OTLP listener (protobuf encoding), 0 to disable it.
+    #[clap(long, default_value = "4318")]
+    otlp_http_port: u16,
+
     /// Enable OTLP log emission for live check policy findings
     #[arg(long, default_value = "false")]
     emit_otlp_logs: bool,
@@ -197,7 +201,7 @@ pub(crate) fn command(args: &RegistryLiveCheckArgs) -> Result<ExitDirectives, Di
     info!("Weaver Registry Live Check");
 
     // Prepare the registry
-    info!("Resolving registry `{}`", args.registry.registry);
+    info!("Resolving registry `{}`", args.registry.base_registry());
 
     let mut diag_msgs = DiagnosticMessages::empty();
     let weaver = WeaverEngine::new(&args.registry, &args.policy);
@@ -257,6 +261,7 @@ pub(crate) fn command(args: &RegistryLiveCheckArgs) -> Result<ExitDirectives, Di
         (InputSource::Otlp, _) => (OtlpIngester {
             otlp_grpc_address: args.otlp_grpc_address.clone(),
             otlp_grpc_port: args.otlp_grpc_port,
+            otlp_http_port: args.otlp_http_port,
             admin_port: args.admin_port,
             inactivity_timeout: args.inactivity_timeout,
         })
@@ -373,7 +378,7 @@ pub(crate) fn command(args: &RegistryLiveCheckArgs) -> Result<ExitDirectives, Di
 
     log_success(format!(
         "Performed live check for registry `{}`",
-        args.registry.registry
+        args.registry.base_registry()
     ));
 
     if diag_msgs.has_error() {
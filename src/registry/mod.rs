@@ -14,22 +14,24 @@ use crate::registry::generate::RegistryGenerateArgs;
 use crate::registry::json_schema::RegistryJsonSchemaArgs;
 use crate::registry::live_check::RegistryLiveCheckArgs;
 use crate::registry::resolve::RegistryResolveArgs;
+use crate::registry::schema_file::RegistrySchemaFileArgs;
 use crate::registry::search::RegistrySearchArgs;
 use crate::registry::stats::RegistryStatsArgs;
 use crate::registry::update_markdown::RegistryUpdateMarkdownArgs;
 use crate::CmdResult;
 use check::RegistryCheckArgs;
 use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
-use weaver_common::vdir::VirtualDirectoryPath;
+use weaver_common::vdir::{LoadOptions, VirtualDirectoryPath};
 
 mod check;
 mod diff;
 mod emit;
-mod generate;
+pub(crate) mod generate;
 mod json_schema;
-mod live_check;
+pub(crate) mod live_check;
 mod otlp;
 mod resolve;
+mod schema_file;
 mod search;
 mod stats;
 mod update_markdown;
@@ -50,6 +52,18 @@ pub enum Error {
     #[error("Failed to render the registry diff: {error}")]
     DiffRender { error: String },
 
+    /// Failed to render the registry stats
+    #[error("Failed to render the registry stats: {error}")]
+    StatsRender { error: String },
+
+    /// None of the inputs of a watched command is a local path
+    #[error("Nothing to watch, the registry and the templates are not local folders")]
+    NothingToWatch,
+
+    /// The protobuf output is not supported for the V2 schema
+    #[error("The protobuf format is not supported with `--v2`, use the yaml or json format")]
+    ProtobufV2Unsupported,
+
     #[error(transparent)]
     Schema(#[from] weaver_resolved_schema::error::Error),
 }
@@ -123,6 +137,13 @@ pub enum RegistrySubCommand {
     /// - ...
     #[clap(verbatim_doc_comment)]
     Diff(RegistryDiffArgs),
+    /// Generate the OpenTelemetry telemetry schema file of a semantic convention registry from
+    /// the diff with the previous version of the registry.
+    ///
+    /// Attribute and metric renames are expressed as schema file transformations. The other
+    /// changes (e.g. removals or span renames) can't be expressed and are reported as warnings.
+    #[clap(verbatim_doc_comment)]
+    SchemaFile(RegistrySchemaFileArgs),
 
     /// Emits a semantic convention registry as example signals to your OTLP receiver.
     ///
@@ -141,13 +162,22 @@ pub enum RegistrySubCommand {
 pub struct RegistryArgs {
     /// Local folder, Git repo URL, or Git archive URL of the semantic
     /// convention registry. For Git URLs, a sub-folder can be specified
-    /// using the `[sub-folder]` syntax after the URL.
+    /// using the `[sub-folder]` syntax after the URL, and a tag or branch
+    /// using the `@ref` syntax, e.g.
+    /// `https://github.com/open-telemetry/semantic-conventions.git[model]@v1.26.0`.
+    /// Git repos pinned to a ref are cached in `~/.weaver/git_cache`.
+    ///
+    /// Repeat the flag to apply overlay registries (e.g. vendor extensions)
+    /// on top of the first one, the last registry having the highest
+    /// precedence. An overlay group with the id of an existing group overrides
+    /// its brief, note, stability and deprecation, adds attributes and
+    /// overrides the fields of its attributes with `ref`s.
     #[arg(
         short = 'r',
         long,
         default_value = "https://github.com/open-telemetry/semantic-conventions.git[model]"
     )]
-    pub registry: VirtualDirectoryPath,
+    pub registry: Vec<VirtualDirectoryPath>,
 
     /// Boolean flag to specify whether to follow symlinks when loading the registry.
     /// Default is false.
@@ -159,12 +189,43 @@ pub struct RegistryArgs {
     #[arg(long)]
     pub(crate) include_unreferenced: bool,
 
+    /// Reuse the resolution of an unchanged registry from the on-disk cache
+    /// (`~/.weaver/resolution_cache`), keyed by the content of the registry files.
+    #[arg(long)]
+    pub(crate) incremental: bool,
+
+    /// Fetch again the Git repos pinned to a ref instead of reusing their
+    /// cached clone, e.g. after the ref has moved.
+    #[arg(long)]
+    pub(crate) refresh: bool,
+
     /// Whether or not to output version 2 of the schema.
     /// Note: this will impact both output to templates *and* policies.
     #[arg(long, default_value = "false")]
     pub v2: bool,
 }
 
+impl RegistryArgs {
+    /// Returns the base registry, i.e. the first `--registry`.
+    pub(crate) fn base_registry(&self) -> &VirtualDirectoryPath {
+        &self.registry[0]
+    }
+
+    /// Returns the options to load the registries from a URL.
+    pub(crate) fn load_options(&self) -> LoadOptions {
+        LoadOptions {
+            refresh: self.refresh,
+            ..LoadOptions::default()
+        }
+    }
+
+    /// Returns the overlay registries, i.e. the other `--registry`, in order
+    /// of increasing precedence.
+    pub(crate) fn overlay_registries(&self) -> &[VirtualDirectoryPath] {
+        &self.registry[1..]
+    }
+}
+
 /// Set of common parameters used for policy checks.
 #[derive(Args, Debug)]
 pub struct PolicyArgs {
@@ -211,6 +272,9 @@ pub fn semconv_registry(command: &RegistryCommand) -> CmdResult {
         RegistrySubCommand::Diff(args) => {
             CmdResult::new(diff::command(args), Some(args.diagnostic.clone()))
         }
+        RegistrySubCommand::SchemaFile(args) => {
+            CmdResult::new(schema_file::command(args), Some(args.diagnostic.clone()))
+        }
         RegistrySubCommand::LiveCheck(args) => {
             CmdResult::new(live_check::command(args), Some(args.diagnostic.clone()))
         }
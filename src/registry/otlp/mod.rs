@@ -1,10 +1,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
-//! A basic OTLP receiver integrated into Weaver.
+//! A basic OTLP receiver integrated into Weaver, accepting OTLP/gRPC and
+//! OTLP/HTTP (protobuf encoding) requests.
 
 pub mod conversion;
 pub mod otlp_ingester;
 
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response as HttpResponse};
+use axum::routing::post;
+use axum::Router;
 use grpc_stubs::proto::collector::logs::v1::logs_service_server::{LogsService, LogsServiceServer};
 use grpc_stubs::proto::collector::logs::v1::{ExportLogsServiceRequest, ExportLogsServiceResponse};
 use grpc_stubs::proto::collector::metrics::v1::metrics_service_server::{
@@ -20,6 +27,7 @@ use grpc_stubs::proto::collector::trace::v1::{
     ExportTraceServiceRequest, ExportTraceServiceResponse,
 };
 use miette::Diagnostic;
+use prost::Message;
 use serde::Serialize;
 use std::fmt::{Display, Formatter};
 use std::net::{AddrParseError, SocketAddr};
@@ -166,32 +174,30 @@ impl Display for StopSignal {
     }
 }
 
+/// The content type of the OTLP/HTTP requests and responses encoded in protobuf.
+const PROTOBUF_CONTENT_TYPE: &str = "application/x-protobuf";
+
 /// Start an OTLP receiver listening to a specific port on all IPv4 interfaces
 /// and return an iterator of received OTLP requests.
 ///
+/// The OTLP/HTTP listener is only started if `http_port` is greater than zero,
+/// it shares the address of the gRPC listener.
+///
 /// This function guarantees that the OTLP server is started and ready when the
 /// result is Ok(iterator).
 pub fn listen_otlp_requests(
     grpc_addr: &str,
     grpc_port: u16,
+    http_port: u16,
     admin_port: u16,
     inactivity_timeout: Duration,
 ) -> Result<impl Iterator<Item = OtlpRequest>, Error> {
-    let addr: SocketAddr =
-        format!("{grpc_addr}:{grpc_port}")
-            .parse()
-            .map_err(|e: AddrParseError| Error::OtlpError {
-                error: e.to_string(),
-            })?;
-
-    let listener = std::net::TcpListener::bind(addr).map_err(|e| Error::OtlpError {
-        error: e.to_string(),
-    })?;
-    listener
-        .set_nonblocking(true)
-        .map_err(|e| Error::OtlpError {
-            error: e.to_string(),
-        })?;
+    let listener = bind_listener(grpc_addr, grpc_port)?;
+    let http_listener = if http_port > 0 {
+        Some(bind_listener(grpc_addr, http_port)?)
+    } else {
+        None
+    };
 
     let (tx, rx) = mpsc::channel(100);
     let stop_tx = tx.clone();
@@ -209,6 +215,10 @@ pub fn listen_otlp_requests(
         tx: tx.clone(),
         activity_tx: activity_tx.clone(),
     };
+    let http_state = HttpState {
+        tx: tx.clone(),
+        activity_tx: activity_tx.clone(),
+    };
 
     let (ready_tx, ready_rx) = oneshot::channel();
 
@@ -236,6 +246,10 @@ pub fn listen_otlp_requests(
                     );
                 }
 
+                if let Some(http_listener) = http_listener {
+                    spawn_http_receiver(http_listener, http_state, &mut tasks);
+                }
+
                 let tokio_listener = TcpListener::from_std(listener)
                     .expect("Failed to convert std listener to tokio listener");
                 let inbound = TcpListenerStream::new(tokio_listener);
@@ -272,6 +286,159 @@ pub fn listen_otlp_requests(
     Ok(SyncReceiver { receiver: rx })
 }
 
+/// Bind a non-blocking TCP listener to the given address and port.
+fn bind_listener(addr: &str, port: u16) -> Result<std::net::TcpListener, Error> {
+    let addr: SocketAddr = format!("{addr}:{port}")
+        .parse()
+        .map_err(|e: AddrParseError| Error::OtlpError {
+            error: e.to_string(),
+        })?;
+
+    let listener = std::net::TcpListener::bind(addr).map_err(|e| Error::OtlpError {
+        error: format!("Failed to bind {addr}: {e}"),
+    })?;
+    listener
+        .set_nonblocking(true)
+        .map_err(|e| Error::OtlpError {
+            error: e.to_string(),
+        })?;
+    Ok(listener)
+}
+
+/// The state shared by the OTLP/HTTP handlers.
+#[derive(Clone)]
+struct HttpState {
+    tx: mpsc::Sender<OtlpRequest>,
+    activity_tx: watch::Sender<Instant>,
+}
+
+impl HttpState {
+    /// Decode a protobuf OTLP/HTTP request, forward it to the channel, and
+    /// return the encoded response.
+    async fn export<T: Message + Default, R: Message>(
+        &self,
+        headers: &HeaderMap,
+        body: Bytes,
+        wrapper: fn(T) -> OtlpRequest,
+        response: R,
+    ) -> HttpResponse {
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        if !content_type.starts_with(PROTOBUF_CONTENT_TYPE) {
+            return (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                format!("Only the `{PROTOBUF_CONTENT_TYPE}` content type is supported"),
+            )
+                .into_response();
+        }
+        if headers.contains_key(header::CONTENT_ENCODING) {
+            return (
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "Compressed requests are not supported",
+            )
+                .into_response();
+        }
+        let request = match T::decode(body) {
+            Ok(request) => request,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    format!("Invalid OTLP request: {e}"),
+                )
+                    .into_response()
+            }
+        };
+
+        // Update last activity time
+        let _ = self.activity_tx.send(Instant::now());
+        if let Err(status) = forward_to_channel(&self.tx, request, wrapper).await {
+            return (StatusCode::SERVICE_UNAVAILABLE, status.message().to_owned()).into_response();
+        }
+        (
+            [(header::CONTENT_TYPE, PROTOBUF_CONTENT_TYPE)],
+            response.encode_to_vec(),
+        )
+            .into_response()
+    }
+}
+
+/// Spawn the OTLP/HTTP receiver handling the `/v1/logs`, `/v1/metrics`
+/// and `/v1/traces` endpoints.
+///
+/// Note: All the tasks created in this function are recorded into a
+/// JoinSet. `JoinSet::spawn` returns a `AbortHandle` that we can
+/// ignore as we don't need to abort these tasks.
+fn spawn_http_receiver(listener: std::net::TcpListener, state: HttpState, tasks: &mut JoinSet<()>) {
+    let router = Router::new()
+        .route(
+            "/v1/logs",
+            post(
+                |State(state): State<HttpState>, headers: HeaderMap, body: Bytes| async move {
+                    state
+                        .export(
+                            &headers,
+                            body,
+                            OtlpRequest::Logs,
+                            ExportLogsServiceResponse {
+                                partial_success: None,
+                            },
+                        )
+                        .await
+                },
+            ),
+        )
+        .route(
+            "/v1/metrics",
+            post(
+                |State(state): State<HttpState>, headers: HeaderMap, body: Bytes| async move {
+                    state
+                        .export(
+                            &headers,
+                            body,
+                            OtlpRequest::Metrics,
+                            ExportMetricsServiceResponse {
+                                partial_success: None,
+                            },
+                        )
+                        .await
+                },
+            ),
+        )
+        .route(
+            "/v1/traces",
+            post(
+                |State(state): State<HttpState>, headers: HeaderMap, body: Bytes| async move {
+                    state
+                        .export(
+                            &headers,
+                            body,
+                            OtlpRequest::Traces,
+                            ExportTraceServiceResponse {
+                                partial_success: None,
+                            },
+                        )
+                        .await
+                },
+            ),
+        )
+        .with_state(state.clone());
+
+    let _ = tasks.spawn(async move {
+        let listener = TcpListener::from_std(listener)
+            .expect("Failed to convert std listener to tokio listener");
+        if let Err(e) = axum::serve(listener, router).await {
+            let _ = state
+                .tx
+                .send(OtlpRequest::Error(Error::OtlpError {
+                    error: format!("The OTLP/HTTP listener encountered an error: {e}"),
+                }))
+                .await;
+        }
+    });
+}
+
 /// Spawn tasks to handle CTRL+C and SIGHUP signals.
 ///
 /// Note: All the tasks created in this function are recorded into a
@@ -518,7 +685,8 @@ mod tests {
         let inactivity_timeout = Duration::from_secs(1);
 
         let mut receiver =
-            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout).unwrap();
+            listen_otlp_requests("127.0.0.1", grpc_port, 0, admin_port, inactivity_timeout)
+                .unwrap();
         let grpc_endpoint = format!("http://127.0.0.1:{grpc_port}");
         let expected_metrics_count = 3;
         let expected_logs_count = 4;
@@ -604,6 +772,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_otlp_http_receiver() {
+        let grpc_port = portpicker::pick_unused_port().expect("No free ports");
+        let http_port = portpicker::pick_unused_port().expect("No free ports");
+        let admin_port = portpicker::pick_unused_port().expect("No free ports");
+        let inactivity_timeout = Duration::from_secs(1);
+
+        let mut receiver = listen_otlp_requests(
+            "127.0.0.1",
+            grpc_port,
+            http_port,
+            admin_port,
+            inactivity_timeout,
+        )
+        .unwrap();
+        // Give the server a little time to finish binding the port.
+        thread::sleep(Duration::from_millis(200));
+
+        let export = |path: &str, body: Vec<u8>| {
+            ureq::post(format!("http://127.0.0.1:{http_port}{path}"))
+                .header("Content-Type", PROTOBUF_CONTENT_TYPE)
+                .send(&body[..])
+        };
+        let response = export(
+            "/v1/traces",
+            ExportTraceServiceRequest::default().encode_to_vec(),
+        )
+        .expect("OTLP/HTTP export of traces failed");
+        assert_eq!(response.status(), 200);
+        assert!(export(
+            "/v1/metrics",
+            ExportMetricsServiceRequest::default().encode_to_vec()
+        )
+        .is_ok());
+        assert!(export(
+            "/v1/logs",
+            ExportLogsServiceRequest::default().encode_to_vec()
+        )
+        .is_ok());
+
+        // The JSON encoding is not supported.
+        let result = ureq::post(format!("http://127.0.0.1:{http_port}/v1/logs"))
+            .header("Content-Type", "application/json")
+            .send("{}");
+        assert!(matches!(result, Err(ureq::Error::StatusCode(415))));
+        // An invalid protobuf payload is rejected.
+        assert!(matches!(
+            export("/v1/logs", vec![0xff, 0xff]),
+            Err(ureq::Error::StatusCode(400))
+        ));
+
+        assert!(matches!(receiver.next(), Some(OtlpRequest::Traces(_))));
+        assert!(matches!(receiver.next(), Some(OtlpRequest::Metrics(_))));
+        assert!(matches!(receiver.next(), Some(OtlpRequest::Logs(_))));
+        assert!(matches!(
+            receiver.next(),
+            Some(OtlpRequest::Stop(StopSignal::Inactivity))
+        ));
+    }
+
     #[test]
     fn test_http_stop_endpoint() {
         let grpc_port = portpicker::pick_unused_port().expect("No free ports");
@@ -611,7 +839,8 @@ mod tests {
         let inactivity_timeout = Duration::from_secs(5);
 
         let mut receiver =
-            listen_otlp_requests("127.0.0.1", grpc_port, admin_port, inactivity_timeout).unwrap();
+            listen_otlp_requests("127.0.0.1", grpc_port, 0, admin_port, inactivity_timeout)
+                .unwrap();
 
         // Give the server a little time to finish binding the port.
         thread::sleep(Duration::from_millis(200));
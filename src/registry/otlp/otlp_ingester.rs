@@ -25,6 +25,8 @@ pub struct OtlpIngester {
     pub otlp_grpc_address: String,
     /// The port of the OTLP gRPC server
     pub otlp_grpc_port: u16,
+    /// The port of the OTLP/HTTP server, 0 to disable it
+    pub otlp_http_port: u16,
     /// The port of the admin server
     pub admin_port: u16,
     /// The inactivity timeout
@@ -212,6 +214,7 @@ impl Ingester for OtlpIngester {
         let otlp_requests = listen_otlp_requests(
             self.otlp_grpc_address.as_str(),
             self.otlp_grpc_port,
+            self.otlp_http_port,
             self.admin_port,
             Duration::from_secs(self.inactivity_timeout),
         )
@@ -219,6 +222,16 @@ impl Ingester for OtlpIngester {
             error: format!("Failed to listen to OTLP requests: {e}"),
         })?;
 
+        info!(
+            "OTLP/gRPC receiver listening on {}:{}",
+            self.otlp_grpc_address, self.otlp_grpc_port
+        );
+        if self.otlp_http_port > 0 {
+            info!(
+                "OTLP/HTTP receiver listening on {}:{} (endpoints: /v1/logs, /v1/metrics, /v1/traces)",
+                self.otlp_grpc_address, self.otlp_http_port
+            );
+        }
         log_info("To stop the OTLP receiver:");
         info!("  - press CTRL+C,");
         info!(
@@ -2,16 +2,16 @@
 
 //! Resolve a semantic convention registry.
 
+use std::io::Write;
 use std::path::PathBuf;
 
 use clap::Args;
 
 use log::info;
 use weaver_common::diagnostic::DiagnosticMessages;
-use weaver_semconv::registry_repo::RegistryRepo;
 
 use crate::format::{apply_format, Format};
-use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::registry::{Error, PolicyArgs, RegistryArgs};
 use crate::weaver::{PolicyError, ResolvedV2, WeaverEngine};
 use crate::{DiagnosticArgs, ExitDirectives};
 
@@ -34,7 +34,7 @@ pub struct RegistryResolveArgs {
 
     /// Output format for the resolved schema
     /// If not specified, the resolved schema is printed in YAML format
-    /// Supported formats: yaml, json
+    /// Supported formats: yaml, json, protobuf (not supported with `--v2`)
     /// Default format: yaml
     /// Example: `--format json`
     #[arg(short, long, default_value = "yaml")]
@@ -52,14 +52,15 @@ pub struct RegistryResolveArgs {
 /// Resolve a semantic convention registry and write the resolved schema to a
 /// file or print it to stdout.
 pub(crate) fn command(args: &RegistryResolveArgs) -> Result<ExitDirectives, DiagnosticMessages> {
-    info!("Resolving registry `{}`", args.registry.registry);
+    info!("Resolving registry `{}`", args.registry.base_registry());
+
+    if args.registry.v2 && matches!(args.format, Format::Protobuf) {
+        return Err(Error::ProtobufV2Unsupported.into());
+    }
 
     let mut diag_msgs = DiagnosticMessages::empty();
     let weaver = WeaverEngine::new(&args.registry, &args.policy);
-    let registry_path = &args.registry.registry;
-    let main_registry_repo = RegistryRepo::try_new("main", registry_path)?;
-
-    let loaded = weaver.load_definitions(main_registry_repo, &mut diag_msgs)?;
+    let loaded = weaver.load_main_definitions(&mut diag_msgs)?;
     // TODO - only do this in weaver check?
     if args.registry.v2 {
         // Issue a warning so we fail --future.
@@ -75,42 +76,25 @@ pub(crate) fn command(args: &RegistryResolveArgs) -> Result<ExitDirectives, Diag
         resolved_v2.check_after_resolution_policy(&mut diag_msgs)?;
         apply_format(&args.format, &resolved_v2.template_schema())
             .map_err(|e| format!("Failed to serialize the registry: {e:?}"))
-            .and_then(|s| {
-                if let Some(ref path) = args.output {
-                    // Write the resolved registry to a file.
-                    std::fs::write(path, s).map_err(|e| {
-                        format!("Failed to write the resolved registry to file: {e:?}")
-                    })
-                } else {
-                    // Print the resolved registry to stdout.
-                    println!("{s}");
-                    Ok(())
-                }
-            })
+            .and_then(|s| write_output(args.output.as_ref(), &s))
             .unwrap_or_else(|e| {
                 // Capture all the errors
                 panic!("{}", e);
             });
     } else {
         resolved.check_after_resolution_policy(&mut diag_msgs)?;
-        apply_format(&args.format, &resolved.template_schema())
-            .map_err(|e| format!("Failed to serialize the registry: {e:?}"))
-            .and_then(|s| {
-                if let Some(ref path) = args.output {
-                    // Write the resolved registry to a file.
-                    std::fs::write(path, s).map_err(|e| {
-                        format!("Failed to write the resolved registry to file: {e:?}")
-                    })
-                } else {
-                    // Print the resolved registry to stdout.
-                    println!("{s}");
-                    Ok(())
-                }
-            })
-            .unwrap_or_else(|e| {
-                // Capture all the errors
-                panic!("{}", e);
-            });
+        if matches!(args.format, Format::Protobuf) {
+            let encoded = weaver_otlp_schema::encode_resolved_schema(resolved.resolved_schema());
+            write_binary_output(args.output.as_ref(), &encoded)
+        } else {
+            apply_format(&args.format, &resolved.template_schema())
+                .map_err(|e| format!("Failed to serialize the registry: {e:?}"))
+                .and_then(|s| write_output(args.output.as_ref(), &s))
+        }
+        .unwrap_or_else(|e| {
+            // Capture all the errors
+            panic!("{}", e);
+        });
     }
 
     if !diag_msgs.is_empty() {
@@ -123,6 +107,33 @@ pub(crate) fn command(args: &RegistryResolveArgs) -> Result<ExitDirectives, Diag
     })
 }
 
+/// Writes the serialized resolved registry to a file or prints it to stdout.
+fn write_output(path: Option<&PathBuf>, s: &str) -> Result<(), String> {
+    if let Some(path) = path {
+        // Write the resolved registry to a file.
+        std::fs::write(path, s)
+            .map_err(|e| format!("Failed to write the resolved registry to file: {e:?}"))
+    } else {
+        // Print the resolved registry to stdout.
+        println!("{s}");
+        Ok(())
+    }
+}
+
+/// Writes the binary encoded resolved registry to a file or to stdout.
+fn write_binary_output(path: Option<&PathBuf>, bytes: &[u8]) -> Result<(), String> {
+    if let Some(path) = path {
+        std::fs::write(path, bytes)
+            .map_err(|e| format!("Failed to write the resolved registry to file: {e:?}"))
+    } else {
+        let mut stdout = std::io::stdout().lock();
+        stdout
+            .write_all(bytes)
+            .and_then(|()| stdout.flush())
+            .map_err(|e| format!("Failed to write the resolved registry to stdout: {e:?}"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::cli::{Cli, Commands};
@@ -130,7 +141,9 @@ mod tests {
     use crate::registry::resolve::RegistryResolveArgs;
     use crate::registry::{PolicyArgs, RegistryArgs, RegistryCommand, RegistrySubCommand};
     use crate::run_command;
+    use prost::Message;
     use weaver_common::vdir::VirtualDirectoryPath;
+    use weaver_otlp_schema::v1::ResolvedTelemetrySchema;
 
     #[test]
     fn test_registry_resolve() {
@@ -141,11 +154,13 @@ mod tests {
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Resolve(RegistryResolveArgs {
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     lineage: true,
@@ -173,11 +188,13 @@ mod tests {
             command: Some(Commands::Registry(RegistryCommand {
                 command: RegistrySubCommand::Resolve(RegistryResolveArgs {
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     lineage: true,
@@ -197,4 +214,173 @@ mod tests {
         // The command should exit with an error code.
         assert_eq!(exit_directive.exit_code, 1);
     }
+
+    #[test]
+    fn test_registry_resolve_protobuf() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = temp_dir.path().join("resolved.pb");
+        let cli = |v2: bool| Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            command: Some(Commands::Registry(RegistryCommand {
+                command: RegistrySubCommand::Resolve(RegistryResolveArgs {
+                    registry: RegistryArgs {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
+                            path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
+                        }],
+                        follow_symlinks: false,
+                        include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
+                        v2,
+                    },
+                    lineage: false,
+                    output: Some(output.clone()),
+                    format: Format::Protobuf,
+                    policy: PolicyArgs {
+                        policies: vec![],
+                        skip_policies: true,
+                        display_policy_coverage: false,
+                    },
+                    diagnostic: Default::default(),
+                }),
+            })),
+        };
+
+        assert_eq!(run_command(&cli(false)).exit_code, 0);
+        let encoded = std::fs::read(&output).unwrap();
+        let schema = ResolvedTelemetrySchema::decode(encoded.as_slice())
+            .expect("The output should be a resolved schema message");
+        assert!(schema
+            .groups
+            .iter()
+            .any(|group| group.metric_name.as_deref() == Some("system.cpu.time")));
+        assert!(schema.groups.iter().all(|group| group
+            .attributes
+            .iter()
+            .all(|index| (*index as usize) < schema.attributes.len())));
+
+        // Not supported for the V2 schema.
+        assert_eq!(run_command(&cli(true)).exit_code, 1);
+    }
+
+    #[test]
+    fn test_registry_resolve_overlay() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let overlay_dir = temp_dir.path().join("overlay");
+        std::fs::create_dir_all(&overlay_dir).unwrap();
+        std::fs::write(
+            overlay_dir.join("overlay.yaml"),
+            r#"
+groups:
+  - id: attributes.http.common
+    type: attribute_group
+    brief: "Vendor HTTP attributes."
+    attributes:
+      - ref: http.request.method
+        requirement_level: opt_in
+"#,
+        )
+        .unwrap();
+        let output = temp_dir.path().join("resolved.json");
+        let cli = Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            command: Some(Commands::Registry(RegistryCommand {
+                command: RegistrySubCommand::Resolve(RegistryResolveArgs {
+                    registry: RegistryArgs {
+                        registry: vec![
+                            VirtualDirectoryPath::LocalFolder {
+                                path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
+                            },
+                            VirtualDirectoryPath::LocalFolder {
+                                path: overlay_dir.to_string_lossy().into_owned(),
+                            },
+                        ],
+                        follow_symlinks: false,
+                        include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
+                        v2: false,
+                    },
+                    lineage: false,
+                    output: Some(output.clone()),
+                    format: Format::Json,
+                    policy: PolicyArgs {
+                        policies: vec![],
+                        skip_policies: true,
+                        display_policy_coverage: false,
+                    },
+                    diagnostic: Default::default(),
+                }),
+            })),
+        };
+
+        assert_eq!(run_command(&cli).exit_code, 0);
+        let resolved: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+        let group = resolved["groups"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|group| group["id"] == "attributes.http.common")
+            .expect("The overlaid group should be resolved");
+        assert_eq!(group["brief"], "Vendor HTTP attributes.");
+        // The overlay takes precedence over the base registry.
+        let method = group["attributes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|attribute| attribute["name"] == "http.request.method")
+            .expect("The group should still have the method");
+        assert_eq!(method["requirement_level"], "opt_in");
+    }
+
+    #[test]
+    fn test_registry_resolve_golden() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for (format, extension) in [(Format::Json, "json"), (Format::Yaml, "yaml")] {
+            let output = temp_dir.path().join(format!("resolved.{extension}"));
+            let cli = Cli {
+                debug: 0,
+                quiet: false,
+                future: false,
+                command: Some(Commands::Registry(RegistryCommand {
+                    command: RegistrySubCommand::Resolve(RegistryResolveArgs {
+                        registry: RegistryArgs {
+                            registry: vec![VirtualDirectoryPath::LocalFolder {
+                                path: "tests/resolve_golden/model".to_owned(),
+                            }],
+                            follow_symlinks: false,
+                            include_unreferenced: false,
+                            incremental: false,
+                            refresh: false,
+                            v2: false,
+                        },
+                        lineage: false,
+                        output: Some(output.clone()),
+                        format,
+                        policy: PolicyArgs {
+                            policies: vec![],
+                            skip_policies: true,
+                            display_policy_coverage: false,
+                        },
+                        diagnostic: Default::default(),
+                    }),
+                })),
+            };
+
+            assert_eq!(run_command(&cli).exit_code, 0);
+            // The groups are sorted by id and the attributes by name, so the
+            // output must match the golden file byte-for-byte.
+            assert_eq!(
+                std::fs::read_to_string(&output).unwrap(),
+                std::fs::read_to_string(format!("tests/resolve_golden/resolved.{extension}"))
+                    .unwrap(),
+                "The resolved registry in {extension} doesn't match the golden file"
+            );
+        }
+    }
 }
@@ -0,0 +1,276 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Generate the OpenTelemetry telemetry schema file of a semantic convention
+//! registry from the diff with a previous version of the registry.
+
+use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::weaver::WeaverEngine;
+use crate::{DiagnosticArgs, ExitDirectives};
+use clap::Args;
+use log::info;
+use miette::Diagnostic;
+use serde::Serialize;
+use std::path::PathBuf;
+use weaver_common::diagnostic::{DiagnosticMessage, DiagnosticMessages};
+use weaver_common::log_warn;
+use weaver_common::vdir::VirtualDirectoryPath;
+use weaver_semconv::registry_repo::RegistryRepo;
+use weaver_version::telemetry_schema_changes::SchemaChanges;
+use weaver_version::Versions;
+
+/// Parameters for the `registry schema-file` sub-command
+#[derive(Debug, Args)]
+pub struct RegistrySchemaFileArgs {
+    /// Parameters to specify the semantic convention registry
+    #[command(flatten)]
+    registry: RegistryArgs,
+
+    /// Parameters to specify the baseline semantic convention registry, i.e.
+    /// the previous version of the registry.
+    #[arg(long)]
+    baseline_registry: VirtualDirectoryPath,
+
+    /// The version of the registry, e.g. `1.27.0`. Defaults to the
+    /// `semconv_version` of the registry manifest.
+    #[arg(long)]
+    version: Option<String>,
+
+    /// The schema URL of the registry. Defaults to the `schema_base_url` of
+    /// the registry manifest followed by the version.
+    #[arg(long)]
+    schema_url: Option<String>,
+
+    /// The schema file of the baseline registry. Its versions are kept in the
+    /// generated schema file.
+    #[arg(long)]
+    baseline_schema_file: Option<PathBuf>,
+
+    /// Path to the file where the schema file will be saved.
+    /// If not specified, the schema file is printed to stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Parameters to specify the diagnostic format.
+    #[command(flatten)]
+    pub(crate) diagnostic: DiagnosticArgs,
+}
+
+/// An error that can occur while generating the schema file of a semantic
+/// convention registry.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Serialize, Diagnostic)]
+#[non_exhaustive]
+pub enum Error {
+    /// The version of the registry is unknown.
+    #[error("The version of the registry is unknown, use `--version` or set the `semconv_version` of the registry manifest.")]
+    MissingVersion,
+
+    /// The version of the registry is not a valid semantic version.
+    #[error("The version `{version}` of the registry is invalid: {error}")]
+    InvalidVersion {
+        /// The version.
+        version: String,
+        /// The error that occurred.
+        error: String,
+    },
+
+    /// The schema URL of the registry is unknown.
+    #[error("The schema URL of the registry is unknown, use `--schema-url` or set the `schema_base_url` of the registry manifest.")]
+    MissingSchemaUrl,
+
+    /// The schema file of the baseline registry could not be loaded.
+    #[error("The schema file of the baseline registry could not be loaded: {error}")]
+    InvalidBaselineSchemaFile {
+        /// The error that occurred.
+        error: String,
+    },
+
+    /// The schema file can only be generated from V1 resolved schemas.
+    #[error("The schema file generation is not supported with the V2 schema.")]
+    V2Unsupported,
+
+    /// Writing the schema file failed.
+    #[error("Writing the schema file failed for the following reason: {error}")]
+    WriteFailed {
+        /// The error that occurred.
+        error: String,
+    },
+}
+
+impl From<Error> for DiagnosticMessages {
+    fn from(error: Error) -> Self {
+        DiagnosticMessages::new(vec![DiagnosticMessage::new(error)])
+    }
+}
+
+/// Generate the telemetry schema file of a semantic convention registry.
+pub(crate) fn command(args: &RegistrySchemaFileArgs) -> Result<ExitDirectives, DiagnosticMessages> {
+    if args.registry.v2 {
+        return Err(Error::V2Unsupported.into());
+    }
+
+    let mut diag_msgs = DiagnosticMessages::empty();
+    let policy_config = PolicyArgs {
+        policies: vec![],
+        skip_policies: true,
+        display_policy_coverage: false,
+    };
+    let weaver = WeaverEngine::new(&args.registry, &policy_config);
+
+    info!("Weaver Registry Schema File");
+    info!("Checking registry `{}`", args.registry.base_registry());
+
+    let baseline_registry_repo = RegistryRepo::try_new_with_options(
+        "baseline",
+        &args.baseline_registry,
+        &args.registry.load_options(),
+    )?;
+    let main = weaver.load_main_definitions(&mut diag_msgs)?;
+    let baseline = weaver.load_definitions(baseline_registry_repo, &mut diag_msgs)?;
+    let main_resolved = weaver.resolve(main, &mut diag_msgs)?;
+    let baseline_resolved = weaver.resolve(baseline, &mut diag_msgs)?;
+    main_resolved.check_renamed_from(&baseline_resolved, &mut diag_msgs)?;
+    if diag_msgs.has_error() {
+        return Err(diag_msgs);
+    }
+
+    let manifest = main_resolved.resolved_schema().registry_manifest.as_ref();
+    let version = args
+        .version
+        .clone()
+        .or_else(|| manifest.map(|manifest| manifest.semconv_version.clone()))
+        .filter(|version| !version.is_empty())
+        .ok_or(Error::MissingVersion)?;
+    let version = parse_version(&version)?;
+    let schema_url = args
+        .schema_url
+        .clone()
+        .or_else(|| {
+            manifest
+                .filter(|manifest| !manifest.schema_base_url.is_empty())
+                .map(|manifest| {
+                    format!(
+                        "{}/{version}",
+                        manifest.schema_base_url.trim_end_matches('/')
+                    )
+                })
+        })
+        .ok_or(Error::MissingSchemaUrl)?;
+    let previous_versions = args
+        .baseline_schema_file
+        .as_ref()
+        .map(Versions::load_from_file)
+        .transpose()
+        .map_err(|e| Error::InvalidBaselineSchemaFile {
+            error: e.to_string(),
+        })?;
+
+    let changes = SchemaChanges::from_registry_changes(
+        main_resolved.diff(&baseline_resolved).as_template_context(),
+        version,
+    );
+    for change in &changes.non_representable {
+        log_warn(format!(
+            "The following change to the {:?} can't be expressed in a schema file: {:?}",
+            change.item_type, change.change
+        ));
+    }
+    let schema_file = changes.into_schema_file(schema_url, previous_versions);
+    let yaml = serde_yaml::to_string(&schema_file).map_err(|e| Error::WriteFailed {
+        error: e.to_string(),
+    })?;
+    match &args.output {
+        Some(output) => std::fs::write(output, yaml).map_err(|e| Error::WriteFailed {
+            error: format!("{}: {e}", output.display()),
+        })?,
+        None => print!("{yaml}"),
+    }
+
+    Ok(ExitDirectives {
+        exit_code: 0,
+        warnings: None,
+    })
+}
+
+/// Parses a semantic version, the missing minor and patch numbers of a
+/// version such as `1.1` default to 0.
+fn parse_version(version: &str) -> Result<semver::Version, Error> {
+    let mut full_version = version.to_owned();
+    for _ in version.split('.').count()..3 {
+        full_version.push_str(".0");
+    }
+    semver::Version::parse(&full_version).map_err(|e| Error::InvalidVersion {
+        version: version.to_owned(),
+        error: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Cli, Commands};
+    use crate::registry::schema_file::{parse_version, RegistrySchemaFileArgs};
+    use crate::registry::{RegistryArgs, RegistryCommand, RegistrySubCommand};
+    use crate::run_command;
+    use weaver_common::vdir::VirtualDirectoryPath;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("1.1").unwrap(), semver::Version::new(1, 1, 0));
+        assert_eq!(
+            parse_version("1.27.3").unwrap(),
+            semver::Version::new(1, 27, 3)
+        );
+        assert!(parse_version("latest").is_err());
+    }
+
+    #[test]
+    fn test_registry_schema_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output = temp_dir.path().join("1.1.0");
+        let cli = Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            command: Some(Commands::Registry(RegistryCommand {
+                command: RegistrySubCommand::SchemaFile(RegistrySchemaFileArgs {
+                    registry: RegistryArgs {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
+                            path: "tests/diff/registry_head/".to_owned(),
+                        }],
+                        follow_symlinks: false,
+                        include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
+                        v2: false,
+                    },
+                    baseline_registry: VirtualDirectoryPath::LocalFolder {
+                        path: "tests/diff/registry_baseline/".to_owned(),
+                    },
+                    version: None,
+                    schema_url: None,
+                    baseline_schema_file: None,
+                    output: Some(output.clone()),
+                    diagnostic: Default::default(),
+                }),
+            })),
+        };
+
+        assert_eq!(run_command(&cli).exit_code, 0);
+        let schema_file: serde_yaml::Value =
+            serde_yaml::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+        assert_eq!(schema_file["file_format"], "1.1.0");
+        // The version and the schema URL come from the registry manifest.
+        assert_eq!(
+            schema_file["schema_url"],
+            "https://opentelemetry.io/schemas/1.1.0"
+        );
+        let version = &schema_file["versions"]["1.1.0"];
+        assert_eq!(
+            version["all"]["changes"][0]["rename_attributes"]["attribute_map"]["attr1"],
+            "attr1_bis"
+        );
+        assert_eq!(
+            version["metrics"]["changes"][0]["rename_metrics"]["metric4"],
+            "metric4_bis"
+        );
+    }
+}
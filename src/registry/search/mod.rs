@@ -2,10 +2,15 @@
 
 //! Search a semantic convention registry.
 
-use clap::Args;
+mod query;
+
+use clap::{Args, ValueEnum};
 use itertools::Itertools;
 use log::info;
 use miette::Diagnostic;
+use query::{Query, Target};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
 use weaver_common::diagnostic::DiagnosticMessages;
 use weaver_resolved_schema::{attribute::Attribute, ResolvedTelemetrySchema};
 
@@ -30,6 +35,16 @@ use ratatui::{
 use std::io::{stdout, IsTerminal};
 use tui_textarea::TextArea;
 
+/// The output format of the command line search.
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub(crate) enum SearchFormat {
+    /// Human-readable table (the attribute names for a plain search string).
+    #[default]
+    Table,
+    /// JSON array of the matching attributes or groups.
+    Json,
+}
+
 /// Parameters for the `registry search` sub-command
 #[derive(Debug, Args)]
 pub struct RegistrySearchArgs {
@@ -46,17 +61,27 @@ pub struct RegistrySearchArgs {
     #[command(flatten)]
     pub diagnostic: DiagnosticArgs,
 
+    /// Output format of the command line search.
+    #[arg(long, default_value = "table")]
+    format: SearchFormat,
+
     /// An (optional) search string to use.  If specified, will return matching values on the command line.
     /// Otherwise, runs an interactive terminal UI.
+    ///
+    /// The search string is either a part of the attribute names, or a query such as
+    /// `attributes where stability=experimental and type=string` (see `docs/usage.md`).
     pub search_string: Option<String>,
 }
 
-#[derive(thiserror::Error, Debug, serde::Serialize, Diagnostic)]
+#[derive(thiserror::Error, Debug, Serialize, Diagnostic)]
+#[allow(clippy::enum_variant_names)]
 enum Error {
     #[error("{0}")]
     StdIoError(String),
     #[error("The search command is deprecated and not compatible with V2 schema. Please search the generated documentation instead.")]
     V2SchemaIncompatible,
+    #[error("Invalid search query `{query}`: {error}")]
+    InvalidQuery { query: String, error: String },
 }
 
 impl From<std::io::Error> for Error {
@@ -362,19 +387,129 @@ fn run_ui(schema: &ResolvedTelemetrySchema) -> Result<(), Error> {
 
 // If the user specified a search string on the command line, we operate as if we're a command-line tool, allowing
 // awk/bash/etc type utilities on the result.
-// TODO - the behavior of this method needs to be sorted out.
-fn run_command_line_search(schema: &ResolvedTelemetrySchema, pattern: &str) {
-    let results = schema
-        .catalog()
-        .iter()
-        .filter(|a| a.name.contains(pattern))
-        .map(|a| a.name.to_owned())
-        .join("\n");
-    println!("{results}");
+fn run_command_line_search(
+    schema: &ResolvedTelemetrySchema,
+    search: &str,
+    format: &SearchFormat,
+) -> Result<(), Error> {
+    if !Query::is_query(search) {
+        let attributes: Vec<&Attribute> = schema
+            .catalog()
+            .iter()
+            .filter(|a| a.name.contains(search))
+            .collect();
+        return match format {
+            SearchFormat::Table => {
+                println!("{}", attributes.iter().map(|a| &a.name).join("\n"));
+                Ok(())
+            }
+            SearchFormat::Json => print_json(&attributes),
+        };
+    }
+
+    let query = Query::parse(search).map_err(|e| Error::InvalidQuery {
+        query: search.to_owned(),
+        error: e.to_string(),
+    })?;
+    match query.target {
+        Target::Attributes => {
+            // The types of the groups referencing each attribute.
+            let mut group_types: BTreeMap<u32, BTreeSet<String>> = BTreeMap::new();
+            for group in &schema.registry.groups {
+                for attribute_ref in &group.attributes {
+                    _ = group_types
+                        .entry(attribute_ref.0)
+                        .or_default()
+                        .insert(group.r#type.to_string());
+                }
+            }
+            let no_group_types = BTreeSet::new();
+            let attributes: Vec<&Attribute> = schema
+                .catalog()
+                .iter()
+                .zip(0..)
+                .filter(|(attribute, index)| {
+                    query.matches_attribute(
+                        attribute,
+                        group_types.get(index).unwrap_or(&no_group_types),
+                    )
+                })
+                .map(|(attribute, _)| attribute)
+                .collect();
+            match format {
+                SearchFormat::Table => print_table(
+                    [
+                        "NAME",
+                        "TYPE",
+                        "STABILITY",
+                        "REQUIREMENT LEVEL",
+                        "DEPRECATED",
+                    ],
+                    attributes.iter().map(|a| {
+                        [
+                            a.name.clone(),
+                            a.r#type.to_string(),
+                            query::stability(a.stability.as_ref()),
+                            query::requirement_level(&a.requirement_level).to_owned(),
+                            a.deprecated.is_some().to_string(),
+                        ]
+                    }),
+                ),
+                SearchFormat::Json => print_json(&attributes)?,
+            }
+        }
+        Target::Groups => {
+            let groups: Vec<_> = schema
+                .registry
+                .groups
+                .iter()
+                .filter(|group| query.matches_group(group))
+                .collect();
+            match format {
+                SearchFormat::Table => print_table(
+                    ["ID", "TYPE", "STABILITY", "DEPRECATED"],
+                    groups.iter().map(|g| {
+                        [
+                            g.id.clone(),
+                            g.r#type.to_string(),
+                            query::stability(g.stability.as_ref()),
+                            g.deprecated.is_some().to_string(),
+                        ]
+                    }),
+                ),
+                SearchFormat::Json => print_json(&groups)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+// Prints the rows of a table, the columns being aligned on their widest cell.
+fn print_table<const N: usize>(header: [&str; N], rows: impl Iterator<Item = [String; N]>) {
+    let rows: Vec<[String; N]> = std::iter::once(header.map(str::to_owned))
+        .chain(rows)
+        .collect();
+    let widths: Vec<usize> = (0..N)
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+}
+
+fn print_json<T: Serialize>(results: &T) -> Result<(), Error> {
+    let json = serde_json::to_string_pretty(results).map_err(std::io::Error::from)?;
+    println!("{json}");
+    Ok(())
 }
 
 pub(crate) fn command(args: &RegistrySearchArgs) -> Result<ExitDirectives, DiagnosticMessages> {
-    info!("Resolving registry `{}`", args.registry.registry);
+    info!("Resolving registry `{}`", args.registry.base_registry());
 
     // Check for V2 schema incompatibility
     if args.registry.v2 {
@@ -399,8 +534,9 @@ pub(crate) fn command(args: &RegistrySearchArgs) -> Result<ExitDirectives, Diagn
     // We should have two modes:
     // 1. a single input we take in and directly output some rendered result.
     // 2. An interactive UI
-    if let Some(pattern) = args.search_string.as_ref() {
-        run_command_line_search(resolved.resolved_schema(), pattern);
+    if let Some(search) = args.search_string.as_ref() {
+        run_command_line_search(resolved.resolved_schema(), search, &args.format)
+            .map_err(DiagnosticMessages::from_error)?;
     } else if stdout().is_terminal() {
         run_ui(resolved.resolved_schema()).map_err(DiagnosticMessages::from_error)?;
     } else {
@@ -0,0 +1,411 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! The query language of the `registry search` command, e.g.
+//! `attributes where stability=experimental and type=string`.
+//!
+//! A query selects the attributes or the groups of a resolved registry, the
+//! optional conditions are combined with `and`. A condition compares a field
+//! with a value using one of the operators:
+//! - `=`: equal to,
+//! - `!=`: not equal to,
+//! - `^=`: starts with,
+//! - `~=`: contains.
+
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use weaver_resolved_schema::attribute::Attribute;
+use weaver_resolved_schema::registry::Group;
+use weaver_semconv::attribute::{AttributeType, BasicRequirementLevelSpec, RequirementLevel};
+use weaver_semconv::stability::Stability;
+
+/// The fields of the attributes usable in a condition.
+const ATTRIBUTE_FIELDS: &[&str] = &[
+    "name",
+    "type",
+    "stability",
+    "deprecated",
+    "requirement_level",
+    "group_type",
+];
+
+/// The fields of the groups usable in a condition.
+const GROUP_FIELDS: &[&str] = &["id", "type", "stability", "deprecated"];
+
+/// The elements of the registry selected by a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Target {
+    /// The attributes of the catalog.
+    Attributes,
+    /// The groups of the registry.
+    Groups,
+}
+
+impl Target {
+    fn fields(self) -> &'static [&'static str] {
+        match self {
+            Target::Attributes => ATTRIBUTE_FIELDS,
+            Target::Groups => GROUP_FIELDS,
+        }
+    }
+}
+
+/// The comparison operator of a condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    /// `=`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `^=`
+    Prefix,
+    /// `~=`
+    Contains,
+}
+
+impl Operator {
+    /// The operators, the two characters ones first so `=` isn't matched in
+    /// `!=`.
+    const ALL: [(&'static str, Operator); 4] = [
+        ("!=", Operator::Ne),
+        ("^=", Operator::Prefix),
+        ("~=", Operator::Contains),
+        ("=", Operator::Eq),
+    ];
+
+    fn test(self, actual: &str, expected: &str) -> bool {
+        match self {
+            Operator::Eq => actual == expected,
+            Operator::Ne => actual != expected,
+            Operator::Prefix => actual.starts_with(expected),
+            Operator::Contains => actual.contains(expected),
+        }
+    }
+}
+
+/// A condition on a field of the selected elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Condition {
+    field: String,
+    operator: Operator,
+    value: String,
+}
+
+impl Condition {
+    /// Returns true if the values of the field satisfy the condition. A field
+    /// may have several values (e.g. the types of the groups referencing an
+    /// attribute), the condition must hold for at least one of them, or for
+    /// all of them with `!=`.
+    fn test(&self, values: &[String]) -> bool {
+        match self.operator {
+            Operator::Ne => values.iter().all(|value| value != &self.value),
+            operator => values.iter().any(|value| operator.test(value, &self.value)),
+        }
+    }
+}
+
+/// A parsed search query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Query {
+    /// The elements selected by the query.
+    pub(crate) target: Target,
+    conditions: Vec<Condition>,
+}
+
+/// The error returned for an invalid query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct QueryError(String);
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Query {
+    /// Returns true if the search string is a query, i.e. starts with
+    /// `attributes` or `groups`, and not a plain search pattern.
+    pub(crate) fn is_query(search: &str) -> bool {
+        parse_target(search.split_whitespace().next().unwrap_or_default()).is_some()
+    }
+
+    /// Parses a query.
+    pub(crate) fn parse(query: &str) -> Result<Query, QueryError> {
+        let mut words = query.split_whitespace();
+        let target = words.next().and_then(parse_target).ok_or_else(|| {
+            QueryError("A query must start with `attributes` or `groups`".to_owned())
+        })?;
+        let mut conditions = vec![];
+        match words.next() {
+            None => {}
+            Some(word) if word.eq_ignore_ascii_case("where") => {
+                let words: Vec<&str> = words.collect();
+                for condition in words.split(|word| word.eq_ignore_ascii_case("and")) {
+                    conditions.push(parse_condition(target, &condition.join(" "))?);
+                }
+            }
+            Some(word) => {
+                return Err(QueryError(format!(
+                    "Expected `where` after `{}`, found `{word}`",
+                    query.split_whitespace().next().unwrap_or_default()
+                )))
+            }
+        }
+        Ok(Query { target, conditions })
+    }
+
+    /// Returns true if the attribute satisfies all the conditions, the group
+    /// types being the types of the groups referencing the attribute.
+    pub(crate) fn matches_attribute(
+        &self,
+        attribute: &Attribute,
+        group_types: &BTreeSet<String>,
+    ) -> bool {
+        self.conditions.iter().all(|condition| {
+            let values = match condition.field.as_str() {
+                "name" => vec![attribute.name.clone()],
+                "type" => vec![attribute_type(&attribute.r#type)],
+                "stability" => vec![stability(attribute.stability.as_ref())],
+                "deprecated" => vec![attribute.deprecated.is_some().to_string()],
+                "requirement_level" => {
+                    vec![requirement_level(&attribute.requirement_level).to_owned()]
+                }
+                "group_type" => group_types.iter().cloned().collect(),
+                _ => vec![],
+            };
+            condition.test(&values)
+        })
+    }
+
+    /// Returns true if the group satisfies all the conditions.
+    pub(crate) fn matches_group(&self, group: &Group) -> bool {
+        self.conditions.iter().all(|condition| {
+            let values = match condition.field.as_str() {
+                "id" => vec![group.id.clone()],
+                "type" => vec![group.r#type.to_string()],
+                "stability" => vec![stability(group.stability.as_ref())],
+                "deprecated" => vec![group.deprecated.is_some().to_string()],
+                _ => vec![],
+            };
+            condition.test(&values)
+        })
+    }
+}
+
+fn parse_target(word: &str) -> Option<Target> {
+    match word.to_ascii_lowercase().as_str() {
+        "attributes" => Some(Target::Attributes),
+        "groups" => Some(Target::Groups),
+        _ => None,
+    }
+}
+
+fn parse_condition(target: Target, condition: &str) -> Result<Condition, QueryError> {
+    let (index, operator, symbol) = Operator::ALL
+        .iter()
+        .filter_map(|(symbol, operator)| condition.find(symbol).map(|index| (index, *operator, *symbol)))
+        // The first operator of the condition, the longest one at a given index.
+        .min_by_key(|(index, _, symbol)| (*index, usize::MAX - symbol.len()))
+        .ok_or_else(|| {
+            QueryError(format!(
+                "Invalid condition `{condition}`, expected `<field> <operator> <value>` with one of the operators `=`, `!=`, `^=`, `~=`"
+            ))
+        })?;
+    let field = condition[..index].trim().to_ascii_lowercase();
+    let field = match (target, field.as_str()) {
+        // `name` is an alias of the group id.
+        (Target::Groups, "name") => "id".to_owned(),
+        _ => field,
+    };
+    if !target.fields().contains(&field.as_str()) {
+        return Err(QueryError(format!(
+            "Unknown field `{field}`, expected one of: {}",
+            target.fields().join(", ")
+        )));
+    }
+    let value = condition[index + symbol.len()..].trim();
+    let value = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value);
+    if value.is_empty() {
+        return Err(QueryError(format!(
+            "Missing value in condition `{condition}`"
+        )));
+    }
+    let value = match (field.as_str(), value) {
+        // The former name of the development stability.
+        ("stability", "experimental") => "development".to_owned(),
+        _ => value.to_owned(),
+    };
+    Ok(Condition {
+        field,
+        operator,
+        value,
+    })
+}
+
+/// The type of an attribute as written in a query, `enum` for the enums.
+fn attribute_type(r#type: &AttributeType) -> String {
+    match r#type {
+        AttributeType::Enum { .. } => "enum".to_owned(),
+        r#type => r#type.to_string(),
+    }
+}
+
+/// The stability as written in a query, `none` if not specified.
+pub(crate) fn stability(stability: Option<&Stability>) -> String {
+    stability.map_or_else(|| "none".to_owned(), ToString::to_string)
+}
+
+/// The kind of a requirement level, without its description.
+pub(crate) fn requirement_level(requirement_level: &RequirementLevel) -> &'static str {
+    match requirement_level {
+        RequirementLevel::Basic(BasicRequirementLevelSpec::Required) => "required",
+        RequirementLevel::Basic(BasicRequirementLevelSpec::Recommended)
+        | RequirementLevel::Recommended { .. } => "recommended",
+        RequirementLevel::Basic(BasicRequirementLevelSpec::OptIn)
+        | RequirementLevel::OptIn { .. } => "opt_in",
+        RequirementLevel::ConditionallyRequired { .. } => "conditionally_required",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use weaver_semconv::attribute::PrimitiveOrArrayTypeSpec;
+    use weaver_semconv::deprecated::Deprecated;
+
+    fn attribute(name: &str, r#type: PrimitiveOrArrayTypeSpec, stability: Stability) -> Attribute {
+        Attribute {
+            name: name.to_owned(),
+            renamed_from: None,
+            r#type: AttributeType::PrimitiveOrArray(r#type),
+            brief: String::new(),
+            examples: None,
+            tag: None,
+            requirement_level: RequirementLevel::default(),
+            sampling_relevant: None,
+            note: String::new(),
+            stability: Some(stability),
+            deprecated: None,
+            prefix: false,
+            tags: None,
+            annotations: None,
+            role: None,
+            value: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_query() {
+        assert!(Query::is_query("attributes"));
+        assert!(Query::is_query("Groups where type=span"));
+        assert!(!Query::is_query("http.request"));
+
+        let query =
+            Query::parse("attributes where stability=experimental and name ^= http.").unwrap();
+        assert_eq!(query.target, Target::Attributes);
+        assert_eq!(
+            query.conditions,
+            vec![
+                Condition {
+                    field: "stability".to_owned(),
+                    operator: Operator::Eq,
+                    value: "development".to_owned(),
+                },
+                Condition {
+                    field: "name".to_owned(),
+                    operator: Operator::Prefix,
+                    value: "http.".to_owned(),
+                },
+            ]
+        );
+        let query = Query::parse("groups where name != \"registry.http\"").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![Condition {
+                field: "id".to_owned(),
+                operator: Operator::Ne,
+                value: "registry.http".to_owned(),
+            }]
+        );
+        assert!(Query::parse("groups").unwrap().conditions.is_empty());
+
+        assert!(Query::parse("metrics").is_err());
+        assert!(Query::parse("attributes with type=string").is_err());
+        assert!(Query::parse("attributes where type").is_err());
+        assert!(Query::parse("attributes where type=").is_err());
+        assert!(Query::parse("attributes where brief~=method").is_err());
+        assert!(Query::parse("groups where requirement_level=required").is_err());
+        assert!(Query::parse("attributes where type=string and").is_err());
+    }
+
+    #[test]
+    fn test_match_attribute() {
+        let method = attribute(
+            "http.request.method",
+            PrimitiveOrArrayTypeSpec::String,
+            Stability::Stable,
+        );
+        let mut body_size = attribute(
+            "http.request.body.size",
+            PrimitiveOrArrayTypeSpec::Int,
+            Stability::Development,
+        );
+        body_size.requirement_level = RequirementLevel::OptIn {
+            text: "If available.".to_owned(),
+        };
+        body_size.deprecated = Some(Deprecated::Obsoleted {
+            note: "Removed.".to_owned(),
+        });
+        let span = BTreeSet::from(["span".to_owned()]);
+        let none = BTreeSet::new();
+
+        let matches = |query: &str, attribute: &Attribute, group_types: &BTreeSet<String>| {
+            Query::parse(query)
+                .unwrap()
+                .matches_attribute(attribute, group_types)
+        };
+        assert!(matches("attributes", &method, &none));
+        assert!(matches(
+            "attributes where type=string and stability=stable",
+            &method,
+            &none
+        ));
+        assert!(!matches("attributes where type=string", &body_size, &none));
+        assert!(matches(
+            "attributes where stability=experimental",
+            &body_size,
+            &none
+        ));
+        assert!(matches("attributes where name~=body", &body_size, &none));
+        assert!(matches(
+            "attributes where deprecated=true",
+            &body_size,
+            &none
+        ));
+        assert!(matches("attributes where deprecated=false", &method, &none));
+        assert!(matches(
+            "attributes where requirement_level=opt_in",
+            &body_size,
+            &none
+        ));
+        assert!(matches(
+            "attributes where requirement_level=recommended",
+            &method,
+            &none
+        ));
+        assert!(matches("attributes where group_type=span", &method, &span));
+        assert!(!matches("attributes where group_type=span", &method, &none));
+        assert!(!matches(
+            "attributes where group_type!=span",
+            &method,
+            &span
+        ));
+        assert!(matches(
+            "attributes where group_type!=metric",
+            &method,
+            &span
+        ));
+    }
+}
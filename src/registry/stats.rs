@@ -2,17 +2,27 @@
 
 //! Compute stats on a semantic convention registry.
 
-use crate::registry::{PolicyArgs, RegistryArgs};
+use crate::registry::{Error, PolicyArgs, RegistryArgs};
 use crate::weaver::{ResolvedV2, WeaverEngine};
 use crate::{DiagnosticArgs, ExitDirectives};
-use clap::Args;
+use clap::{Args, ValueEnum};
 use itertools::Itertools;
 use log::info;
+use serde::Serialize;
 use weaver_common::diagnostic::DiagnosticMessages;
 use weaver_resolved_schema::registry::{CommonGroupStats, GroupStats};
 use weaver_resolved_schema::ResolvedTelemetrySchema;
 use weaver_semconv::group::GroupType;
-use weaver_semconv::registry::SemConvRegistry;
+
+/// The output format of the stats.
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub(crate) enum StatsFormat {
+    /// Human-readable breakdowns.
+    #[default]
+    Text,
+    /// JSON, e.g. to feed dashboards.
+    Json,
+}
 
 /// Parameters for the `registry stats` sub-command
 #[derive(Debug, Args)]
@@ -21,6 +31,10 @@ pub struct RegistryStatsArgs {
     #[command(flatten)]
     registry: RegistryArgs,
 
+    /// Output format of the stats.
+    #[arg(long, default_value = "text")]
+    format: StatsFormat,
+
     /// Parameters to specify the diagnostic format.
     #[command(flatten)]
     pub diagnostic: DiagnosticArgs,
@@ -30,7 +44,7 @@ pub struct RegistryStatsArgs {
 pub(crate) fn command(args: &RegistryStatsArgs) -> Result<ExitDirectives, DiagnosticMessages> {
     info!(
         "Compute statistics on the registry `{}`",
-        args.registry.registry
+        args.registry.base_registry()
     );
 
     if args.registry.v2 {
@@ -55,7 +69,10 @@ fn display_v2(args: &RegistryStatsArgs) -> Result<(), DiagnosticMessages> {
     let weaver = WeaverEngine::new(&args.registry, &policy_config);
     let resolved = weaver.load_and_resolve_main(&mut diag_msgs)?;
     let resolved_v2: ResolvedV2 = resolved.try_into()?;
-    display_schema_stats_v2(resolved_v2.resolved_schema());
+    match args.format {
+        StatsFormat::Text => display_schema_stats_v2(resolved_v2.resolved_schema()),
+        StatsFormat::Json => print_json(&resolved_v2.resolved_schema().stats())?,
+    }
     Ok(())
 }
 
@@ -68,21 +85,44 @@ fn display_v1(args: &RegistryStatsArgs) -> Result<(), DiagnosticMessages> {
     };
     let weaver = WeaverEngine::new(&args.registry, &policy_config);
     let loaded = weaver.load_main_definitions(&mut diag_msgs)?;
-    display_semconv_registry_stats(&loaded.semconv_registry()?);
+    let semconv_registry_stats = loaded.semconv_registry()?.stats();
 
     let resolved = weaver.resolve(loaded, &mut diag_msgs)?;
     if !diag_msgs.is_empty() {
         return Err(diag_msgs);
     }
 
-    display_schema_stats(resolved.resolved_schema());
+    match args.format {
+        StatsFormat::Text => {
+            display_semconv_registry_stats(&semconv_registry_stats);
+            display_schema_stats(resolved.resolved_schema());
+        }
+        StatsFormat::Json => print_json(&StatsReport {
+            semconv_registry: semconv_registry_stats,
+            resolved_schema: resolved.resolved_schema().stats(),
+        })?,
+    }
     Ok(())
 }
 
-fn display_semconv_registry_stats(semconv_registry: &SemConvRegistry) {
-    let stats = semconv_registry.stats();
+/// The stats of a V1 registry, before and after resolution.
+#[derive(Serialize)]
+struct StatsReport {
+    semconv_registry: weaver_semconv::stats::Stats,
+    resolved_schema: weaver_resolved_schema::Stats,
+}
+
+fn print_json<T: Serialize>(stats: &T) -> Result<(), DiagnosticMessages> {
+    let json = serde_json::to_string_pretty(stats).map_err(|e| Error::StatsRender {
+        error: e.to_string(),
+    })?;
+    println!("{json}");
+    Ok(())
+}
+
+fn display_semconv_registry_stats(stats: &weaver_semconv::stats::Stats) {
     println!("Semantic Convention Registry Stats:");
-    println!("  - Total number of files: {}", stats.file_count);
+    print!("{stats}");
 }
 
 fn display_schema_stats_v2(schema: &weaver_resolved_schema::v2::ResolvedTelemetrySchema) {
@@ -282,11 +322,12 @@ fn display_schema_stats(schema: &ResolvedTelemetrySchema) {
 
     let catalog_stats = &stats.catalog_stats;
     println!("Shared Catalog (after resolution and deduplication):");
-    if total_number_of_attributes > 0 {
+    if let Some(percent) =
+        (catalog_stats.attribute_count * 100).checked_div(total_number_of_attributes)
+    {
         println!(
             "  - Number of deduplicated attributes: {} ({}%)",
-            catalog_stats.attribute_count,
-            catalog_stats.attribute_count * 100 / total_number_of_attributes
+            catalog_stats.attribute_count, percent
         );
     }
     println!("    - Attribute types breakdown:");
@@ -314,6 +355,12 @@ fn display_schema_stats(schema: &ResolvedTelemetrySchema) {
             catalog_stats.deprecated_count * 100 / catalog_stats.attribute_count
         );
     }
+    if catalog_stats.enum_count > 0 {
+        println!(
+            "    - Total number of enum members: {} (in {} enums)",
+            catalog_stats.enum_member_count, catalog_stats.enum_count
+        );
+    }
 }
 
 fn display_common_group_stats(group_type: &GroupType, common_stats: &CommonGroupStats) {
@@ -331,16 +378,17 @@ fn display_common_group_stats(group_type: &GroupType, common_stats: &CommonGroup
             .collect::<Vec<_>>()
             .join(", ")
     );
-    if common_stats.count > 0 {
+    if let (Some(prefix_percent), Some(note_percent)) = (
+        (common_stats.total_with_prefix * 100).checked_div(common_stats.count),
+        (common_stats.total_with_note * 100).checked_div(common_stats.count),
+    ) {
         println!(
             "      - Number of group with a prefix: {} ({}%)",
-            common_stats.total_with_prefix,
-            common_stats.total_with_prefix * 100 / common_stats.count
+            common_stats.total_with_prefix, prefix_percent
         );
         println!(
             "      - Number of group with a note: {} ({}%)",
-            common_stats.total_with_note,
-            common_stats.total_with_note * 100 / common_stats.count
+            common_stats.total_with_note, note_percent
         );
     }
     if !common_stats.stability_breakdown.is_empty() {
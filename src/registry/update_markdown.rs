@@ -16,8 +16,8 @@ use weaver_common::vdir::VirtualDirectory;
 use weaver_common::vdir::VirtualDirectoryPath;
 use weaver_common::{log_error, log_info, log_success, Error};
 use weaver_forge::config::WeaverConfig;
-use weaver_forge::file_loader::FileSystemFileLoader;
-use weaver_forge::TemplateEngine;
+use weaver_forge::file_loader::{EmbeddedFileLoader, FileSystemFileLoader};
+use weaver_forge::{TemplateEngine, DOCS_TEMPLATES};
 use weaver_semconv_gen::{MarkdownSnippetGenerator, SnipperGeneratorV2, SnippetGenerator};
 
 #[derive(thiserror::Error, Debug, serde::Serialize, Diagnostic)]
@@ -69,7 +69,9 @@ pub struct RegistryUpdateMarkdownArgs {
     /// If provided, the target to generate snippets with.
     /// Note: `registry update-markdown` will look for a specific jinja template:
     ///   {templates}/{target}/snippet.md.j2.
-    #[arg(long)]
+    /// Default is the official `markdown` docs target embedded in Weaver, used
+    /// when the templates directory doesn't define it.
+    #[arg(long, default_value = "markdown")]
     pub target: String,
 
     /// Parameters to specify the diagnostic format.
@@ -98,10 +100,20 @@ pub(crate) fn command(
                 error: e.to_string(),
             }
         })?;
-        let loader =
-            FileSystemFileLoader::try_new(templates_dir.path().join("registry"), &args.target)?;
-        let config = WeaverConfig::try_from_loader(&loader)?;
-        TemplateEngine::try_new(config, loader, params)?
+        let templates_root = templates_dir.path().join("registry");
+        if !templates_root.join(&args.target).exists()
+            && DOCS_TEMPLATES.get_dir(&args.target).is_some()
+        {
+            // The target is one of the official docs targets embedded in Weaver.
+            let loader =
+                EmbeddedFileLoader::try_new(&DOCS_TEMPLATES, templates_root, &args.target)?;
+            let config = WeaverConfig::try_from_loader(&loader)?;
+            TemplateEngine::try_new(config, loader, params)?
+        } else {
+            let loader = FileSystemFileLoader::try_new(templates_root, &args.target)?;
+            let config = WeaverConfig::try_from_loader(&loader)?;
+            TemplateEngine::try_new(config, loader, params)?
+        }
     };
     let policy_config = PolicyArgs {
         policies: vec![],
@@ -200,11 +212,13 @@ mod tests {
                 command: RegistrySubCommand::UpdateMarkdown(RegistryUpdateMarkdownArgs {
                     markdown_dir: "data/update_markdown/markdown".to_owned(),
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: "data/update_markdown/registry".to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     dry_run: true,
@@ -225,6 +239,80 @@ mod tests {
         assert_eq!(exit_directive.exit_code, 0);
     }
 
+    #[test]
+    fn test_registry_update_markdown_official_target() {
+        let markdown_dir = tempfile::tempdir().expect("Failed to create temporary directory");
+        let markdown_file = markdown_dir.path().join("http.md");
+        std::fs::write(
+            &markdown_file,
+            "# HTTP client\n\
+             \n\
+             Some prose to preserve.\n\
+             \n\
+             <!-- semconv metric.http.client.request.duration(metric_table) -->\n\
+             stale metric table\n\
+             <!-- endsemconv -->\n\
+             \n\
+             <!-- semconv metric.http.client.request.duration -->\n\
+             stale attribute table\n\
+             <!-- endsemconv -->\n\
+             \n\
+             More prose to preserve.\n",
+        )
+        .unwrap();
+        // The templates directory doesn't define any target, the official
+        // `markdown` docs target embedded in Weaver is used.
+        let templates_dir = tempfile::tempdir().expect("Failed to create temporary directory");
+
+        let cli = Cli {
+            debug: 0,
+            quiet: false,
+            future: false,
+            command: Some(Commands::Registry(RegistryCommand {
+                command: RegistrySubCommand::UpdateMarkdown(RegistryUpdateMarkdownArgs {
+                    markdown_dir: markdown_dir.path().to_string_lossy().to_string(),
+                    registry: RegistryArgs {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
+                            path: "crates/weaver_codegen_test/semconv_registry/".to_owned(),
+                        }],
+                        follow_symlinks: false,
+                        include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
+                        v2: false,
+                    },
+                    dry_run: false,
+                    attribute_registry_base_url: None,
+                    templates: VirtualDirectoryPath::LocalFolder {
+                        path: templates_dir.path().to_string_lossy().to_string(),
+                    },
+                    diagnostic: Default::default(),
+                    target: "markdown".to_owned(),
+                    param: None,
+                    params: None,
+                }),
+            })),
+        };
+
+        let exit_directive = run_command(&cli);
+        assert_eq!(exit_directive.exit_code, 0);
+
+        let updated = std::fs::read_to_string(&markdown_file).unwrap();
+        assert!(!updated.contains("stale"));
+        assert!(updated.starts_with("# HTTP client\n\nSome prose to preserve.\n"));
+        assert!(updated.ends_with("<!-- endsemconv -->\n\nMore prose to preserve.\n"));
+        assert!(updated.contains(
+            "<!-- semconv metric.http.client.request.duration(metric_table) -->\n\
+             | Name | Instrument | Unit | Description | Stability |\n\
+             |---|---|---|---|---|\n\
+             | `http.client.request.duration` | histogram | `s` | Duration of HTTP client requests. | stable |\n\
+             <!-- endsemconv -->\n"
+        ));
+        assert!(updated.contains(
+            "| [`http.request.method`](registry.http.md#http-request-method) | string | HTTP request method. | `\"GET\"`; `\"POST\"`; `\"HEAD\"` | Required | stable |\n"
+        ));
+    }
+
     #[test]
     fn test_registry_update_markdown_dryrun() {
         let markdown_dir = "tests/markdown_update_dryrun/current_output";
@@ -239,11 +327,13 @@ mod tests {
                 command: RegistrySubCommand::UpdateMarkdown(RegistryUpdateMarkdownArgs {
                     markdown_dir: markdown_dir.to_owned(),
                     registry: RegistryArgs {
-                        registry: VirtualDirectoryPath::LocalFolder {
+                        registry: vec![VirtualDirectoryPath::LocalFolder {
                             path: schema_dir.to_owned(),
-                        },
+                        }],
                         follow_symlinks: false,
                         include_unreferenced: false,
+                        incremental: false,
+                        refresh: false,
                         v2: false,
                     },
                     dry_run: true,
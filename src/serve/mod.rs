@@ -54,7 +54,7 @@ fn run_serve(args: &ServeCommand) -> Result<ExitDirectives, DiagnosticMessages>
     // a new registry, and then the server would update its internal state to use the new registry.
     // A UI could be built to allow selecting a registry file, or specifying a git repo/branch.
 
-    info!("Loading registry from `{}`", args.registry.registry);
+    info!("Loading registry from `{}`", args.registry.base_registry());
 
     let mut diag_msgs = DiagnosticMessages::empty();
 
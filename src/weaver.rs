@@ -5,7 +5,7 @@ use std::path::PathBuf;
 use miette::Diagnostic;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use serde::Serialize;
-use weaver_checker::Error::{InvalidPolicyFile, PolicyViolation};
+use weaver_checker::Error::{InvalidPolicyFile, PolicyInformation, PolicyViolation, PolicyWarning};
 use weaver_checker::{Engine, PolicyStage, SEMCONV_REGO};
 use weaver_common::diagnostic::DiagnosticMessage;
 use weaver_common::log_success;
@@ -13,6 +13,7 @@ use weaver_common::vdir::VirtualDirectory;
 use weaver_common::{diagnostic::DiagnosticMessages, result::WResult};
 use weaver_forge::registry::ResolvedRegistry;
 use weaver_resolved_schema::ResolvedTelemetrySchema;
+use weaver_resolver::cache::ResolutionCache;
 use weaver_resolver::SchemaResolver;
 use weaver_semconv::registry::SemConvRegistry;
 use weaver_semconv::semconv::SemConvSpec;
@@ -53,14 +54,28 @@ impl<'a> WeaverEngine<'a> {
         self.resolve(loaded, diag_msgs)
     }
 
-    /// Loads "main" weaver definition files (from our config).
+    /// Loads "main" weaver definition files (from our config), along with the
+    /// definition files of the overlay registries.
     pub fn load_main_definitions(
         &self,
         diag_msgs: &mut DiagnosticMessages,
     ) -> Result<Loaded, Error> {
-        let registry_path = &self.registry_config.registry;
-        let main_registry_repo = RegistryRepo::try_new("main", registry_path)?;
-        self.load_definitions(main_registry_repo, diag_msgs)
+        let registry_path = self.registry_config.base_registry();
+        let load_options = self.registry_config.load_options();
+        let main_registry_repo =
+            RegistryRepo::try_new_with_options("main", registry_path, &load_options)?;
+        let mut loaded = self.load_definitions(main_registry_repo, diag_msgs)?;
+        for (index, overlay_path) in self.registry_config.overlay_registries().iter().enumerate() {
+            let overlay_repo = RegistryRepo::try_new_with_options(
+                &format!("overlay-{}", index + 1),
+                overlay_path,
+                &load_options,
+            )?;
+            let specs = load_semconv_specs(&overlay_repo, self.registry_config.follow_symlinks)
+                .capture_non_fatal_errors(diag_msgs)?;
+            loaded.overlays.push((overlay_repo, specs));
+        }
+        Ok(loaded)
     }
 
     /// Loads "raw" weaver definitions files from some external source.
@@ -78,6 +93,7 @@ impl<'a> WeaverEngine<'a> {
         Ok(Loaded {
             repo,
             specs,
+            overlays: vec![],
             policy_engine,
         })
     }
@@ -90,12 +106,35 @@ impl<'a> WeaverEngine<'a> {
         loaded: Loaded,
         diag_msgs: &mut DiagnosticMessages,
     ) -> Result<Resolved, Error> {
-        let mut registry = SemConvRegistry::from_semconv_specs(&loaded.repo, loaded.specs)?;
+        let Loaded {
+            repo,
+            specs,
+            overlays,
+            policy_engine,
+        } = loaded;
+        let mut registry = SemConvRegistry::from_semconv_specs(&repo, specs)?;
+        // Apply the overlays on top of the main registry, the last one taking precedence.
+        for (overlay_repo, overlay_specs) in overlays {
+            let overlay = SemConvRegistry::from_semconv_specs(&overlay_repo, overlay_specs)?;
+            registry
+                .apply_overlay(overlay)
+                .capture_non_fatal_errors(diag_msgs)?;
+        }
         // let registry_id = registry.id().to_owned();
-        let resolved = SchemaResolver::resolve_semantic_convention_registry(
-            &mut registry,
-            self.registry_config.include_unreferenced,
-        )
+        let include_unreferenced = self.registry_config.include_unreferenced;
+        let resolution_cache = self
+            .registry_config
+            .incremental
+            .then(dirs::home_dir)
+            .flatten()
+            .map(|home| ResolutionCache::new(home.join(".weaver/resolution_cache")));
+        let resolved = match resolution_cache {
+            Some(cache) => cache.resolve(&mut registry, include_unreferenced),
+            None => SchemaResolver::resolve_semantic_convention_registry(
+                &mut registry,
+                include_unreferenced,
+            ),
+        }
         .capture_non_fatal_errors(diag_msgs)?;
 
         // This creates the template/json friendly registry.
@@ -105,18 +144,20 @@ impl<'a> WeaverEngine<'a> {
         Ok(Resolved {
             resolved_schema: resolved,
             template_schema: template,
-            registry_path_repr: loaded.repo.registry_path_repr().to_owned(),
-            policy_engine: loaded.policy_engine,
+            registry_path_repr: repo.registry_path_repr().to_owned(),
+            policy_engine,
         })
     }
 }
 
 /// A loaded set of weaver definition files.
 ///
-/// Contains the repository definition and raw files and an optional policy engine with policies for this repo.
+/// Contains the repository definition and raw files, the raw files of the overlay registries (in
+/// order of increasing precedence) and an optional policy engine with policies for this repo.
 pub struct Loaded {
     repo: RegistryRepo,
     specs: Vec<SemConvSpecWithProvenance>,
+    overlays: Vec<(RegistryRepo, Vec<SemConvSpecWithProvenance>)>,
     policy_engine: Option<Engine>,
 }
 impl Loaded {
@@ -135,6 +176,9 @@ impl Loaded {
     ) -> Result<(), Error> {
         if let Some(policy_engine) = self.policy_engine.as_ref() {
             check_policy(policy_engine, &self.specs).capture_non_fatal_errors(diag_msgs)?;
+            for (_, specs) in &self.overlays {
+                check_policy(policy_engine, specs).capture_non_fatal_errors(diag_msgs)?;
+            }
         }
         Ok(())
     }
@@ -208,6 +252,15 @@ impl Resolved {
                 }
             })
             .capture_non_fatal_errors(diag_msgs)?;
+            check_group_policy_stages(
+                &mut e,
+                &self.registry_path_repr,
+                self.template_schema
+                    .groups
+                    .iter()
+                    .map(|group| (group.r#type.to_string(), group)),
+            )
+            .capture_non_fatal_errors(diag_msgs)?;
         }
         Ok(())
     }
@@ -242,6 +295,18 @@ impl Resolved {
         Ok(())
     }
 
+    /// Checks that the groups and attributes renamed from a previous version
+    /// (see `renamed_from`) reference an id of the baseline.
+    pub fn check_renamed_from(
+        &self,
+        baseline: &Resolved,
+        diag_msgs: &mut DiagnosticMessages,
+    ) -> Result<(), Error> {
+        SchemaResolver::check_renamed_from(&self.resolved_schema, &baseline.resolved_schema)
+            .capture_non_fatal_errors(diag_msgs)?;
+        Ok(())
+    }
+
     /// Differences two repositories.
     pub fn diff(&self, other: &Self) -> Diff {
         let changes = self.resolved_schema.diff(&other.resolved_schema);
@@ -302,6 +367,21 @@ impl ResolvedV2 {
                 }
             })
             .capture_non_fatal_errors(diag_msgs)?;
+            let registry = &self.template_schema.registry;
+            let repr = &self.registry_path_repr;
+            for result in [
+                check_group_policy_stages(
+                    &mut e,
+                    repr,
+                    typed("attribute_group", &registry.attribute_groups),
+                ),
+                check_group_policy_stages(&mut e, repr, typed("metric", &registry.metrics)),
+                check_group_policy_stages(&mut e, repr, typed("span", &registry.spans)),
+                check_group_policy_stages(&mut e, repr, typed("event", &registry.events)),
+                check_group_policy_stages(&mut e, repr, typed("entity", &registry.entities)),
+            ] {
+                result.capture_non_fatal_errors(diag_msgs)?;
+            }
         }
         Ok(())
     }
@@ -579,14 +659,26 @@ pub(crate) fn check_policy_stage<T: Serialize, U: Serialize>(
     }
 
     match policy_engine.set_input(input) {
-        Ok(_) => match policy_engine.check(policy_stage) {
-            Ok(violations) => {
-                for violation in violations {
+        Ok(_) => match policy_engine.check_report(policy_stage) {
+            Ok(report) => {
+                for violation in report.deny {
                     errors.push(PolicyViolation {
                         provenance: policy_file.to_owned(),
                         violation: Box::new(violation),
                     });
                 }
+                for violation in report.warn {
+                    errors.push(PolicyWarning {
+                        provenance: policy_file.to_owned(),
+                        violation: Box::new(violation),
+                    });
+                }
+                for violation in report.info {
+                    errors.push(PolicyInformation {
+                        provenance: policy_file.to_owned(),
+                        violation: Box::new(violation),
+                    });
+                }
             }
             Err(e) => errors.push(InvalidPolicyFile {
                 file: policy_file.to_owned(),
@@ -601,6 +693,36 @@ pub(crate) fn check_policy_stage<T: Serialize, U: Serialize>(
     WResult::with_non_fatal_errors((), errors)
 }
 
+/// Checks the `after_resolution.<group type>` policies (see
+/// [`PolicyStage::GroupAfterResolution`]) against each of the given resolved
+/// groups, along with their type. The group is the input of the policies.
+pub(crate) fn check_group_policy_stages<'a, T: Serialize + 'a>(
+    policy_engine: &mut Engine,
+    policy_file: &str,
+    groups: impl IntoIterator<Item = (String, &'a T)>,
+) -> WResult<(), weaver_checker::Error> {
+    let mut errors = vec![];
+    for (group_type, group) in groups {
+        let stage = PolicyStage::GroupAfterResolution(group_type);
+        if !policy_engine.has_stage(stage.clone()) {
+            continue;
+        }
+        match check_policy_stage::<T, ()>(policy_engine, stage, policy_file, group, &[]) {
+            WResult::Ok(()) => {}
+            WResult::OkWithNFEs((), nfes) => errors.extend(nfes),
+            WResult::FatalErr(e) => return WResult::FatalErr(e),
+        }
+    }
+    WResult::with_non_fatal_errors((), errors)
+}
+
+/// Pairs the V2 signals of a given type with the name of the type.
+fn typed<'a, T>(group_type: &'a str, groups: &'a [T]) -> impl Iterator<Item = (String, &'a T)> {
+    groups
+        .iter()
+        .map(move |group| (group_type.to_owned(), group))
+}
+
 /// Errors that could occur in these utilities.
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Serialize, Diagnostic)]
 #[non_exhaustive]
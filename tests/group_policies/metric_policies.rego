@@ -0,0 +1,27 @@
+package after_resolution.metric
+
+import rego.v1
+
+# Each resolved metric group is the input, including its inherited attributes.
+
+deny contains metric_violation("invalid_metric_name", input.id) if {
+	not regex.match(`^[a-z][a-z0-9._]*$`, input.metric_name)
+}
+
+deny contains metric_violation("missing_http_request_method", input.id) if {
+	not has_attribute("http.request.method")
+}
+
+has_attribute(name) if {
+	input.attributes[_].name == name
+}
+
+metric_violation(violation_id, group_id) := violation if {
+	violation := {
+		"id": violation_id,
+		"type": "semconv_attribute",
+		"category": "metric",
+		"group": group_id,
+		"attr": "",
+	}
+}
@@ -0,0 +1,27 @@
+package after_resolution
+
+import rego.v1
+
+# Findings of the `warn` and `info` rules are reported without failing the
+# check.
+
+warn contains attr_finding("attr_not_stable", "improvement", attr.name) if {
+	some group in input.groups
+	some attr in group.attributes
+	attr.stability != "stable"
+}
+
+info contains attr_finding("attr_without_note", "information", attr.name) if {
+	some group in input.groups
+	some attr in group.attributes
+	not attr.note
+}
+
+attr_finding(finding_id, level, attr_name) := finding if {
+	finding := {
+		"id": finding_id,
+		"context": {"attribute_name": attr_name},
+		"message": sprintf("Attribute '%s' reported by '%s'", [attr_name, finding_id]),
+		"level": level,
+	}
+}
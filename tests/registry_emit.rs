@@ -45,11 +45,15 @@ fn run_emit_with_live_check_test(use_v2: bool) {
         live_check_args.push("--v2");
         live_check_args.push("--otlp-grpc-port");
         live_check_args.push("5300");
+        live_check_args.push("--otlp-http-port");
+        live_check_args.push("5302");
         live_check_args.push("--admin-port");
         live_check_args.push("5301");
     } else {
         live_check_args.push("--otlp-grpc-port");
         live_check_args.push("5200");
+        live_check_args.push("--otlp-http-port");
+        live_check_args.push("5202");
         live_check_args.push("--admin-port");
         live_check_args.push("5201");
     }
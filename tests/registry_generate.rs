@@ -2,13 +2,13 @@
 
 //! Test the registry generate command.
 
-use assert_cmd::Command;
+use assert_cmd::cargo::cargo_bin_cmd;
 
 /// This test checks the CLI interface for the registry generate command.
 /// This test doesn't count for the coverage report as it runs a separate process.
 #[test]
 fn test_cli_interface() {
-    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let mut cmd = cargo_bin_cmd!("weaver");
     let output = cmd
         .arg("registry")
         .arg("generate")
@@ -22,7 +22,7 @@ fn test_cli_interface() {
     // Test a local semantic convention registry.
     // There are policy violations in this registry.
     // This test should fail with a non-zero exit code and display the policy violations.
-    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let mut cmd = cargo_bin_cmd!("weaver");
     let output = cmd
         .arg("--quiet")
         .arg("registry")
@@ -2,13 +2,13 @@
 
 //! Test the registry stats command.
 
-use assert_cmd::Command;
+use assert_cmd::cargo::cargo_bin_cmd;
 
 /// This test checks the CLI interface for the registry stats command.
 /// This test doesn't count for the coverage report as it runs a separate process.
 #[test]
 fn test_cli_interface() {
-    let mut cmd = Command::cargo_bin("weaver").unwrap();
+    let mut cmd = cargo_bin_cmd!("weaver");
     let output = cmd
         .arg("registry")
         .arg("stats")
@@ -20,3 +20,25 @@ fn test_cli_interface() {
 
     assert!(output.status.success());
 }
+
+/// This test checks the stats are printed as JSON with `--format json`.
+#[test]
+fn test_cli_json_format() {
+    let mut cmd = cargo_bin_cmd!("weaver");
+    let output = cmd
+        .arg("registry")
+        .arg("stats")
+        .arg("-r")
+        .arg("tests/custom_registry")
+        .arg("--format")
+        .arg("json")
+        .timeout(std::time::Duration::from_secs(60))
+        .output()
+        .expect("failed to execute process");
+
+    assert!(output.status.success());
+    let stats: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("the stats should be valid JSON");
+    assert!(stats["semconv_registry"]["group_breakdown"].is_object());
+    assert!(stats["resolved_schema"]["catalog_stats"]["enum_member_count"].is_number());
+}